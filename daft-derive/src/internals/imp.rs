@@ -1,11 +1,13 @@
 use super::error_store::{ErrorSink, ErrorStore};
-use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, quote, quote_spanned};
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
 use syn::{
-    Attribute, Data, DataStruct, DeriveInput, Expr, Field, Fields,
-    GenericParam, Generics, Index, Lifetime, LifetimeParam, Path, Token,
-    WhereClause, WherePredicate, parse_quote, parse_quote_spanned, parse_str,
-    spanned::Spanned, visit::Visit,
+    Attribute, Data, DataEnum, DataStruct, DeriveInput, Expr, Field, Fields,
+    GenericParam, Generics, Ident, Lifetime, LifetimeParam, LitStr, Member,
+    Meta, Path, Token, Type, Variant, Visibility, WhereClause, WherePredicate,
+    ext::IdentExt, meta::ParseNestedMeta, parenthesized, parse::Parse,
+    parse_quote, parse_quote_spanned, punctuated::Punctuated, spanned::Spanned,
+    visit::Visit, visit_mut::VisitMut,
 };
 
 pub struct DeriveDiffableOutput {
@@ -24,13 +26,10 @@ pub fn derive_diffable(input: syn::DeriveInput) -> DeriveDiffableOutput {
     let mut error_store = ErrorStore::new();
 
     match &input.data {
-        Data::Enum(_) => {
-            // Implement all Enums as `Leaf`s
-            let out = make_leaf(&input, AttrPosition::Enum, error_store.sink());
-            DeriveDiffableOutput {
-                out: Some(out),
-                errors: error_store.into_inner(),
-            }
+        Data::Enum(e) => {
+            // This might be None if there are errors.
+            let out = make_enum_impl(&input, e, error_store.sink());
+            DeriveDiffableOutput { out, errors: error_store.into_inner() }
         }
         Data::Struct(s) => {
             // This might be None if there are errors.
@@ -40,8 +39,12 @@ pub fn derive_diffable(input: syn::DeriveInput) -> DeriveDiffableOutput {
 
         Data::Union(_) => {
             // Implement all unions as `Leaf`s
-            let out =
-                make_leaf(&input, AttrPosition::Union, error_store.sink());
+            let out = make_leaf(
+                &input,
+                AttrPosition::Union,
+                &default_daft_crate(),
+                error_store.sink(),
+            );
             DeriveDiffableOutput {
                 out: Some(out),
                 errors: error_store.into_inner(),
@@ -50,11 +53,35 @@ pub fn derive_diffable(input: syn::DeriveInput) -> DeriveDiffableOutput {
     }
 }
 
-// TODO: allow the crate name to be passed in as a macro argument
-fn daft_crate() -> Path {
+/// The path to the daft crate, unless overridden with
+/// `#[daft(crate = "...")]`.
+fn default_daft_crate() -> Path {
     parse_quote! { ::daft }
 }
 
+/// Parses the value of `#[daft(crate = "...")]` into `daft_crate`.
+fn parse_crate_path(
+    meta: &ParseNestedMeta<'_>,
+    daft_crate: &mut Option<Path>,
+    errors: &ErrorSink<'_, syn::Error>,
+) -> syn::Result<()> {
+    let value: LitStr = meta.value()?.parse()?;
+    if daft_crate.is_some() {
+        errors.push_critical(
+            meta.error("#[daft(crate)] specified multiple times"),
+        );
+        return Ok(());
+    }
+    match value.parse::<Path>() {
+        Ok(path) => *daft_crate = Some(path),
+        Err(_) => errors.push_critical(syn::Error::new_spanned(
+            &value,
+            "expected a path to the daft crate, e.g. \"::daft\"",
+        )),
+    }
+    Ok(())
+}
+
 fn daft_lifetime() -> LifetimeParam {
     // Use an underscore to avoid clashing with a user-defined `'daft` lifetime.
     LifetimeParam::new(Lifetime::new("'__daft", Span::call_site()))
@@ -82,14 +109,23 @@ fn add_lifetime_to_generics(
 }
 
 // Implement `Diffable` as a `Leaf`.
+//
+// Unions don't have a separate configuration, so `#[daft(crate = "...")]` is
+// parsed here for them. For other types, `daft_crate` has already been parsed
+// from the attributes.
 fn make_leaf(
     input: &DeriveInput,
     position: AttrPosition,
+    daft_crate: &Path,
     errors: ErrorSink<'_, syn::Error>,
 ) -> TokenStream {
-    // The input should not have any daft attributes.
+    let mut union_crate = None;
+
+    // The input should not have any daft attributes other than the crate path.
+    // (Attributes on enums are checked by `EnumConfig`, since enums can opt
+    // into recursive diffs.)
     for attr in &input.attrs {
-        if attr.path().is_ident("daft") {
+        if position != AttrPosition::Enum && attr.path().is_ident("daft") {
             let res = attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("leaf") {
                     // Accept this for leaf structs, but not for anything else.
@@ -102,6 +138,20 @@ fn make_leaf(
                          implementation {} is always a leaf",
                         position.as_purpose_str(),
                     )));
+                } else if meta.path.is_ident("crate") {
+                    match position {
+                        AttrPosition::Union => {
+                            return parse_crate_path(
+                                &meta,
+                                &mut union_crate,
+                                &errors,
+                            );
+                        }
+                        // Already parsed by `StructConfig`.
+                        _ => {
+                            meta.value()?.parse::<LitStr>()?;
+                        }
+                    }
                 } else {
                     errors.push_critical(meta.error(format!(
                         "daft attributes are not allowed {}",
@@ -126,7 +176,7 @@ fn make_leaf(
     // `Diffable` impl exists.
 
     let ident = &input.ident;
-    let daft_crate = daft_crate();
+    let daft_crate = union_crate.as_ref().unwrap_or(daft_crate);
     let daft_lt = daft_lifetime();
 
     // The "where Self: #daft_lt" condition appears to be enough to satisfy
@@ -266,21 +316,56 @@ fn make_struct_impl(
         return None;
     };
 
+    if struct_config.owned {
+        // Owned diffs can't borrow from the original struct, so lifetime
+        // parameters aren't supported.
+        if let Some(lt) = input.generics.lifetimes().next() {
+            errors.push_critical(syn::Error::new_spanned(
+                lt,
+                "#[daft(owned)] is not supported for structs with lifetime \
+                 parameters",
+            ));
+            return None;
+        }
+    }
+
     match struct_config.mode {
-        StructMode::Default => make_diff_struct(input, s, errors.new_child())
-            .map(|(generated_struct, diff_fields)| {
-                let diff_impl = make_diff_impl(input, &diff_fields);
-                // Uncomment for some debugging
-                // eprintln!("{generated_struct}");
-                // eprintln!("{diff_impl}");
-                quote! {
-                    #generated_struct
-                    #diff_impl
-                }
-            }),
-        StructMode::Leaf => {
-            Some(make_leaf(input, AttrPosition::LeafStruct, errors.new_child()))
+        StructMode::Default => {
+            let (generated_struct, diff_fields) = make_diff_struct(
+                input,
+                s,
+                &struct_config.daft_crate,
+                &struct_config.diff_type,
+                errors.new_child(),
+            )?;
+            let diff_impl =
+                make_diff_impl(input, &struct_config.diff_type, &diff_fields);
+            let owned_struct = if struct_config.owned {
+                Some(make_owned_struct(
+                    input,
+                    s,
+                    &struct_config.diff_type,
+                    &diff_fields,
+                    errors.new_child(),
+                )?)
+            } else {
+                None
+            };
+            // Uncomment for some debugging
+            // eprintln!("{generated_struct}");
+            // eprintln!("{diff_impl}");
+            Some(quote! {
+                #generated_struct
+                #diff_impl
+                #owned_struct
+            })
         }
+        StructMode::Leaf => Some(make_leaf(
+            input,
+            AttrPosition::LeafStruct,
+            &struct_config.daft_crate,
+            errors.new_child(),
+        )),
     }
 }
 
@@ -288,13 +373,17 @@ fn make_struct_impl(
 fn make_diff_struct(
     input: &DeriveInput,
     s: &DataStruct,
+    daft_crate: &Path,
+    diff_type: &DiffTypeConfig,
     errors: ErrorSink<'_, syn::Error>,
 ) -> Option<(TokenStream, DiffFields)> {
-    // The name of the original type
-    let vis = &input.vis;
+    let vis = diff_type.vis(input);
 
     // The name of the generated type
-    let name = parse_str::<Path>(&format!("{}Diff", input.ident)).unwrap();
+    let name = diff_type.name(input);
+
+    // Derives and attributes specified for the generated type.
+    let diff_type_attrs = diff_type.attrs();
 
     // Copy over the non-exhaustive attribute from the original struct. (Do we
     // need to copy over other attributes?)
@@ -315,9 +404,12 @@ fn make_diff_struct(
     let new_generics = add_lifetime_to_generics(input, &daft_lt);
     let where_clause = &new_generics.where_clause;
 
-    let Some(diff_fields) =
-        DiffFields::new(&s.fields, where_clause.as_ref(), errors.new_child())
-    else {
+    let Some(diff_fields) = DiffFields::new(
+        &s.fields,
+        where_clause.as_ref(),
+        daft_crate,
+        errors.new_child(),
+    ) else {
         // An error occurred parsing fields -- don't generate the diff struct.
         return None;
     };
@@ -344,12 +436,14 @@ fn make_diff_struct(
     let struct_def = if diff_fields.fields.is_empty() {
         match &s.fields {
             Fields::Named(_) | Fields::Unit => quote! {
+                #diff_type_attrs
                 #non_exhaustive
                 #vis struct #name #new_generics #where_clause {
                     _phantom: #phantom_ty,
                 }
             },
             Fields::Unnamed(_) => quote! {
+                #diff_type_attrs
                 #non_exhaustive
                 #vis struct #name #new_generics (#phantom_ty) #where_clause;
             },
@@ -357,10 +451,12 @@ fn make_diff_struct(
     } else {
         match &s.fields {
             Fields::Named(_) => quote! {
+                #diff_type_attrs
                 #non_exhaustive
                 #vis struct #name #new_generics #where_clause #diff_fields
             },
             Fields::Unnamed(_) => quote! {
+                #diff_type_attrs
                 #non_exhaustive
                 #vis struct #name #new_generics #diff_fields #where_clause;
             },
@@ -370,86 +466,89 @@ fn make_diff_struct(
         }
     };
 
-    // Generate PartialEq, Eq, and Debug implementations for the diff struct. We
-    // can't rely on `#[derive] because we want to put bounds on the
-    // Diffable::Diff types, not on the original types.
+    let generated = GeneratedStruct {
+        name: &name,
+        generics: &new_generics,
+        shape: &s.fields,
+        fields: &diff_fields.fields,
+        bounded_types: diff_fields
+            .types()
+            .map(|ty| BoundedType { lifetimes: None, ty: ty.clone() })
+            .collect(),
+        where_clause: &diff_fields.where_clause,
+        non_exhaustive: non_exhaustive.is_some(),
+        daft_crate,
+    };
     let (impl_gen, ty_gen, _) = &new_generics.split_for_impl();
 
-    let debug_impl = {
+    let debug_impl = generated.debug_impl();
+    let partial_eq_impl = generated.partial_eq_impl();
+    let eq_impl = generated.eq_impl();
+
+    let summary_impl = {
         let where_clause = diff_fields.where_clause_with_trait_bound(
-            &parse_quote! { ::core::fmt::Debug },
+            &parse_quote! { #daft_crate::DiffSummary },
+        );
+        let unchanged = diff_fields.unchanged_exprs(
+            diff_fields.fields.members().map(|m| quote! { &self.#m }),
         );
-        let members = diff_fields.fields.members();
 
-        let finish = if non_exhaustive.is_some() {
-            quote! { .finish_non_exhaustive() }
+        // A diff without any fields is always unchanged.
+        let summary_body: Expr = if diff_fields.fields.is_empty() {
+            parse_quote! { true }
         } else {
-            quote! { .finish() }
+            parse_quote! { #(#unchanged) && * }
         };
 
-        let debug_body = match &s.fields {
-            Fields::Named(_) => {
-                quote! {
-                    f.debug_struct(stringify!(#name))
-                    #(
-                        .field(stringify!(#members), &self.#members)
-                    )*
-                    #finish
-                }
-            }
-            Fields::Unnamed(_) => quote! {
-                f.debug_tuple(stringify!(#name))
-                #(
-                    .field(&self.#members)
-                )*
-                #finish
-            },
-            Fields::Unit => quote! {
-                f.debug_struct(stringify!(#name))
-                    #finish
-            },
-        };
         quote! {
-            impl #impl_gen ::core::fmt::Debug for #name #ty_gen #where_clause {
-                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    #debug_body
+            impl #impl_gen #daft_crate::DiffSummary for #name #ty_gen #where_clause {
+                fn is_unchanged(&self) -> bool {
+                    #summary_body
                 }
             }
         }
     };
 
-    let partial_eq_impl = {
+    let visit_impl = {
         let where_clause = diff_fields.where_clause_with_trait_bound(
-            &parse_quote! { ::core::cmp::PartialEq },
+            &parse_quote! { #daft_crate::VisitDiff },
         );
         let members = diff_fields.fields.members();
-
-        // Return true if there aren't any fields to compare.
-        let partial_eq_body: Expr = if diff_fields.fields.is_empty() {
-            parse_quote! { true }
-        } else {
-            parse_quote! {
-                #(self.#members == other.#members) && *
-            }
-        };
+        // Paths refer to fields of the original struct.
+        let segments = diff_fields
+            .source_members
+            .iter()
+            .map(|member| path_segment(daft_crate, member));
+
+        // Avoid unused variable warnings if there aren't any fields.
+        let unused = diff_fields.fields.is_empty().then(|| {
+            quote! { let _ = (path, visitor); }
+        });
 
         quote! {
-            impl #impl_gen ::core::cmp::PartialEq for #name #ty_gen #where_clause {
-                fn eq(&self, other: &Self) -> bool {
-                    #partial_eq_body
+            impl #impl_gen #daft_crate::VisitDiff for #name #ty_gen #where_clause {
+                fn visit_at(
+                    &self,
+                    path: &#daft_crate::DiffPath<'_>,
+                    visitor: &mut dyn #daft_crate::DiffVisitor,
+                ) {
+                    #unused
+                    #(
+                        #daft_crate::VisitDiff::visit_at(
+                            &self.#members,
+                            &path.join(#segments),
+                            visitor,
+                        );
+                    )*
                 }
             }
         }
     };
 
-    let eq_impl = {
-        let where_clause = diff_fields
-            .where_clause_with_trait_bound(&parse_quote! { ::core::cmp::Eq });
-
-        quote! {
-            impl #impl_gen ::core::cmp::Eq for #name #ty_gen #where_clause {}
-        }
-    };
+    let serialize_impl = generated.serialize_impl();
+    let invert_impl = generated.invert_impl();
+    let patch_impl = generated.patch_impl(&original_ty(input), &diff_fields);
+    let merge_impl = generated.merge_impl(&original_ty(input), &diff_fields);
 
     Some((
         quote! {
@@ -457,171 +556,1476 @@ fn make_diff_struct(
             #debug_impl
             #partial_eq_impl
             #eq_impl
+            #summary_impl
+            #visit_impl
+            #serialize_impl
+            #invert_impl
+            #patch_impl
+            #merge_impl
         },
         diff_fields,
     ))
 }
 
-/// Impl `Diffable` for the original struct
-fn make_diff_impl(
+/// Create the owned diff struct and implement `IntoOwnedDiff` for the diff
+/// struct, for structs annotated with `#[daft(owned)]`.
+///
+/// None means that a field isn't supported in owned diffs.
+fn make_owned_struct(
     input: &DeriveInput,
+    s: &DataStruct,
+    diff_type: &DiffTypeConfig,
     diff_fields: &DiffFields,
-) -> TokenStream {
-    // The name of the original type
+    errors: ErrorSink<'_, syn::Error>,
+) -> Option<TokenStream> {
+    let vis = diff_type.vis(input);
     let ident = &input.ident;
 
-    // The name of the generated type
-    let name = parse_str::<Path>(&format!("{}Diff", input.ident)).unwrap();
-    let diffs =
-        generate_field_diffs(&diff_fields.fields, &diff_fields.field_configs);
+    // The names of the diff struct and the owned diff struct
+    let diff_name = diff_type.name(input);
+    let name = diff_type.owned_name(input);
+
+    let non_exhaustive =
+        input.attrs.iter().find(|attr| attr.path().is_ident("non_exhaustive"));
 
-    let daft_crate = daft_crate();
+    let daft_crate = &diff_fields.daft_crate;
     let daft_lt = daft_lifetime();
-    let new_generics = add_lifetime_to_generics(input, &daft_lt);
+    let static_lt = Lifetime::new("'static", Span::call_site());
+
+    // The owned struct doesn't borrow from the original struct, so it uses
+    // the original generics. The types of its fields are derived from diffs
+    // with the `'static` lifetime, which requires all type parameters to be
+    // `'static`.
+    let mut owned_generics = input.generics.clone();
+    owned_generics.type_params_mut().for_each(|param| {
+        param.bounds.push(syn::TypeParamBound::Lifetime(static_lt.clone()))
+    });
+    let where_clause =
+        owned_generics.where_clause.clone().unwrap_or_else(|| WhereClause {
+            where_token: <Token![where]>::default(),
+            predicates: Default::default(),
+        });
 
-    let (impl_gen, ty_gen, _) = &input.generics.split_for_impl();
-    let (_, new_ty_gen, where_clause) = &new_generics.split_for_impl();
+    // The owned form of each field's diff is obtained by normalizing the
+    // `'static` diff type. Diffs of fields whose types are bare type
+    // parameters can't be normalized, and the owned forms of their diffs
+    // can't be related across lifetimes, so they aren't supported.
+    let type_params: Vec<_> =
+        input.generics.type_params().map(|param| &param.ident).collect();
+    for (ty, config) in
+        diff_fields.source_types.iter().zip(&diff_fields.field_configs)
+    {
+        if matches!(config.mode, FieldMode::Default)
+            && is_type_param(ty, &type_params)
+        {
+            errors.push_critical(syn::Error::new_spanned(
+                ty,
+                "#[daft(owned)] is not supported for fields whose types are \
+                 type parameters (consider using #[daft(leaf)] on this \
+                 field)",
+            ));
+        }
+    }
+    if errors.has_critical_errors() {
+        return None;
+    }
 
-    let constructor = if diff_fields.fields.is_empty() {
-        match &diff_fields.fields {
+    // --- No more errors past this point ---
+
+    let mut fields = diff_fields.fields.clone();
+    let mut bounded_types = Vec::new();
+    for ((field, static_ty), (source_ty, ty)) in fields
+        .iter_mut()
+        .zip(diff_fields.static_types())
+        .zip(diff_fields.source_types.iter().zip(diff_fields.types()))
+    {
+        let owned_ty: Type = parse_quote_spanned! {static_ty.span()=>
+            <#static_ty as #daft_crate::IntoOwnedDiff>::Owned
+        };
+
+        // Bounds on owned types that don't mention type parameters would be
+        // checked eagerly, so express them in terms of the daft lifetime to
+        // defer them until the impl is used.
+        bounded_types.push(if mentions_type_param(source_ty, &type_params) {
+            BoundedType { lifetimes: None, ty: owned_ty.clone() }
+        } else {
+            BoundedType {
+                lifetimes: Some(parse_quote! { for<#daft_lt> }),
+                ty: parse_quote_spanned! {ty.span()=>
+                    <#ty as #daft_crate::IntoOwnedDiff>::Owned
+                },
+            }
+        });
+        field.ty = owned_ty;
+    }
+
+    // As with the diff struct, use a `PhantomData` if there are no fields.
+    let phantom_ty = {
+        let (_, orig_ty_gen, _) = input.generics.split_for_impl();
+        quote! {
+            ::core::marker::PhantomData<fn() -> #ident #orig_ty_gen>
+        }
+    };
+
+    let struct_def = if fields.is_empty() {
+        match &s.fields {
             Fields::Named(_) | Fields::Unit => quote! {
-                Self::Diff { _phantom: ::core::marker::PhantomData }
+                #non_exhaustive
+                #vis struct #name #owned_generics #where_clause {
+                    _phantom: #phantom_ty,
+                }
             },
             Fields::Unnamed(_) => quote! {
-                Self::Diff { 0: ::core::marker::PhantomData }
+                #non_exhaustive
+                #vis struct #name #owned_generics (#phantom_ty) #where_clause;
             },
         }
     } else {
+        match &s.fields {
+            Fields::Named(_) => quote! {
+                #non_exhaustive
+                #vis struct #name #owned_generics #where_clause #fields
+            },
+            Fields::Unnamed(_) => quote! {
+                #non_exhaustive
+                #vis struct #name #owned_generics #fields #where_clause;
+            },
+            Fields::Unit => unreachable!(
+                "Fields::Unit always produces an empty diff struct"
+            ),
+        }
+    };
+
+    let generated = GeneratedStruct {
+        name: &name,
+        generics: &owned_generics,
+        shape: &s.fields,
+        fields: &fields,
+        bounded_types,
+        where_clause: &where_clause,
+        non_exhaustive: non_exhaustive.is_some(),
+        daft_crate,
+    };
+    let debug_impl = generated.debug_impl();
+    let clone_impl = generated.clone_impl();
+    let partial_eq_impl = generated.partial_eq_impl();
+    let eq_impl = generated.eq_impl();
+    let serialize_impl = generated.serialize_impl();
+    let invert_impl = generated.invert_impl();
+    let patch_impl = generated.patch_impl(&original_ty(input), diff_fields);
+
+    // Implement `IntoOwnedDiff` for the diff struct. The owned form of each
+    // field's diff is the same as the owned form with the `'static` lifetime,
+    // which follows from normalization.
+    let into_owned_impl = {
+        let mut diff_generics = add_lifetime_to_generics(input, &daft_lt);
+        diff_generics.type_params_mut().for_each(|param| {
+            param.bounds.push(syn::TypeParamBound::Lifetime(static_lt.clone()))
+        });
+        let (impl_gen, diff_ty_gen, _) = diff_generics.split_for_impl();
+        let (_, owned_ty_gen, _) = owned_generics.split_for_impl();
+
+        let impl_where_clause = &diff_fields.where_clause;
+
+        let members = diff_fields.fields.members();
+        let body = if diff_fields.fields.is_empty() {
+            match &s.fields {
+                Fields::Named(_) | Fields::Unit => quote! {
+                    #name { _phantom: ::core::marker::PhantomData }
+                },
+                Fields::Unnamed(_) => quote! {
+                    #name { 0: ::core::marker::PhantomData }
+                },
+            }
+        } else {
+            quote! {
+                #name {
+                    #(
+                        #members: #daft_crate::IntoOwnedDiff::into_owned(
+                            self.#members,
+                        ),
+                    )*
+                }
+            }
+        };
+
         quote! {
-            Self::Diff {
-                #diffs
+            impl #impl_gen #daft_crate::IntoOwnedDiff for #diff_name #diff_ty_gen
+                #impl_where_clause
+            {
+                type Owned = #name #owned_ty_gen;
+
+                fn into_owned(self) -> Self::Owned {
+                    #body
+                }
             }
         }
     };
 
-    quote! {
-        impl #impl_gen #daft_crate::Diffable for #ident #ty_gen
-            #where_clause
-        {
-            type Diff<#daft_lt> = #name #new_ty_gen where Self: #daft_lt;
+    Some(quote! {
+        #struct_def
+        #debug_impl
+        #clone_impl
+        #partial_eq_impl
+        #eq_impl
+        #serialize_impl
+        #invert_impl
+        #patch_impl
+        #into_owned_impl
+    })
+}
 
-            fn diff<#daft_lt>(&#daft_lt self, other: &#daft_lt Self) -> #name #new_ty_gen {
-                #constructor
+/// Returns the type of the original struct or enum, with its generics.
+fn original_ty(input: &DeriveInput) -> Type {
+    let ident = &input.ident;
+    let (_, ty_gen, _) = input.generics.split_for_impl();
+    parse_quote! { #ident #ty_gen }
+}
+
+/// Returns true if `ty` mentions any of `type_params`.
+fn mentions_type_param(ty: &Type, type_params: &[&Ident]) -> bool {
+    struct MentionsVisitor<'a> {
+        type_params: &'a [&'a Ident],
+        found: bool,
+    }
+
+    impl Visit<'_> for MentionsVisitor<'_> {
+        fn visit_type_path(&mut self, ty: &syn::TypePath) {
+            if ty.qself.is_none() {
+                if let Some(first) = ty.path.segments.first() {
+                    if self.type_params.contains(&&first.ident) {
+                        self.found = true;
+                    }
+                }
             }
+            syn::visit::visit_type_path(self, ty);
         }
     }
+
+    let mut visitor = MentionsVisitor { type_params, found: false };
+    visitor.visit_type(ty);
+    visitor.found
 }
 
-/// For a `Diff` struct generated by this derive macro, tracks the fields that
-/// will be put into that struct.
-///
-/// This also tracks the `where` clause.
+/// Returns true if `ty` is one of `type_params`.
+fn is_type_param(ty: &Type, type_params: &[&Ident]) -> bool {
+    match ty {
+        Type::Path(ty) if ty.qself.is_none() => ty
+            .path
+            .get_ident()
+            .is_some_and(|ident| type_params.contains(&ident)),
+        _ => false,
+    }
+}
+
+/// A struct generated by this derive macro, along with the information needed
+/// to implement common traits on it.
 ///
-/// The goal of this wrapper is to provide helpers to iterate over the fields
-/// and members.
-struct DiffFields {
-    fields: Fields,
-    // Configuration for each field -- a vector with the same length as `self.fields`.
-    field_configs: Vec<FieldConfig>,
-    // The base where clause for the diff struct.
-    where_clause: WhereClause,
+/// We can't rely on `#[derive]` for these traits, because we want to put
+/// bounds on the types of the generated fields, not on the original types.
+struct GeneratedStruct<'a> {
+    name: &'a Ident,
+    generics: &'a Generics,
+    // The fields of the original struct. These determine whether the generated
+    // struct has named or unnamed fields.
+    shape: &'a Fields,
+    // The fields of the generated struct.
+    fields: &'a Fields,
+    // The types that trait bounds are applied to in trait implementations.
+    bounded_types: Vec<BoundedType>,
+    // The base where clause for the generated struct.
+    where_clause: &'a WhereClause,
+    non_exhaustive: bool,
+    // The path to the daft crate.
+    daft_crate: &'a Path,
 }
 
-impl DiffFields {
-    /// None means there was an error parsing a config.
-    fn new(
-        fields: &Fields,
-        where_clause: Option<&WhereClause>,
-        errors: ErrorSink<'_, syn::Error>,
-    ) -> Option<Self> {
-        let (fields, field_configs) = match fields {
-            Fields::Named(fields) => {
-                let (named, configs) = fields
-                    .named
-                    .iter()
-                    .filter_map(|field| {
-                        Self::diff_field(field, errors.new_child())
-                    })
-                    .unzip();
-                (
-                    Fields::Named(syn::FieldsNamed {
-                        brace_token: fields.brace_token,
-                        named,
-                    }),
-                    configs,
-                )
-            }
-            Fields::Unnamed(fields) => {
-                let (unnamed, configs) = fields
-                    .unnamed
-                    .iter()
-                    .filter_map(|field| {
-                        Self::diff_field(field, errors.new_child())
-                    })
-                    .unzip();
-                (
-                    Fields::Unnamed(syn::FieldsUnnamed {
-                        paren_token: fields.paren_token,
-                        unnamed,
-                    }),
-                    configs,
-                )
-            }
-            Fields::Unit => (Fields::Unit, Vec::new()),
-        };
+/// A type that trait bounds are applied to, optionally within a higher-ranked
+/// `for<...>` binder.
+struct BoundedType {
+    lifetimes: Option<syn::BoundLifetimes>,
+    ty: Type,
+}
 
-        // Initialize an empty where clause if none was provided.
-        let where_clause =
-            where_clause.cloned().unwrap_or_else(|| WhereClause {
-                where_token: <Token![where]>::default(),
-                predicates: Default::default(),
-            });
+impl GeneratedStruct<'_> {
+    /// Returns an expanded where clause where the fields have had a trait
+    /// bound applied to them.
+    fn where_clause_with_trait_bound(
+        &self,
+        trait_bound: &syn::TraitBound,
+    ) -> WhereClause {
+        let predicates = self.bounded_types.iter().map(
+            |BoundedType { lifetimes, ty }| -> WherePredicate {
+                parse_quote_spanned! {ty.span()=>
+                    #lifetimes #ty: #trait_bound
+                }
+            },
+        );
 
-        if errors.has_critical_errors() {
-            None
-        } else {
-            Some(Self { fields, field_configs, where_clause })
-        }
+        let mut where_clause = self.where_clause.clone();
+        where_clause.predicates.extend(predicates);
+        where_clause
     }
 
-    /// Return a field for a diff with the appropriate type.
-    ///
-    /// If the type is ignored, or if there's an error parsing configuration,
-    /// return None.
-    fn diff_field(
-        f: &Field,
-        errors: ErrorSink<'_, syn::Error>,
-    ) -> Option<(Field, FieldConfig)> {
-        let Some(config) =
-            FieldConfig::parse_from(&f.attrs, errors.new_child())
-        else {
-            // None means there's an error parsing a config -- return None here,
-            // we'll emit errors at the top level.
-            return None;
+    /// Generate a `Debug` implementation.
+    fn debug_impl(&self) -> TokenStream {
+        let name = self.name;
+        let (impl_gen, ty_gen, _) = self.generics.split_for_impl();
+        let where_clause = self.where_clause_with_trait_bound(
+            &parse_quote! { ::core::fmt::Debug },
+        );
+        let members = self.fields.members();
+
+        let finish = if self.non_exhaustive {
+            quote! { .finish_non_exhaustive() }
+        } else {
+            quote! { .finish() }
         };
-        if config.mode == FieldMode::Ignore {
-            // Skip over this field if there's an ignore.
-            return None;
-        }
 
-        // Always use the daft lifetime for the diff -- associations between the
-        // daft lifetime and existing parameters (both lifetime and type
+        let debug_body = match self.shape {
+            Fields::Named(_) => {
+                quote! {
+                    f.debug_struct(stringify!(#name))
+                    #(
+                        .field(stringify!(#members), &self.#members)
+                    )*
+                    #finish
+                }
+            }
+            Fields::Unnamed(_) => quote! {
+                f.debug_tuple(stringify!(#name))
+                #(
+                    .field(&self.#members)
+                )*
+                #finish
+            },
+            Fields::Unit => quote! {
+                f.debug_struct(stringify!(#name))
+                    #finish
+            },
+        };
+        quote! {
+            impl #impl_gen ::core::fmt::Debug for #name #ty_gen #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #debug_body
+                }
+            }
+        }
+    }
+
+    /// Generate a `Clone` implementation.
+    fn clone_impl(&self) -> TokenStream {
+        let name = self.name;
+        let (impl_gen, ty_gen, _) = self.generics.split_for_impl();
+        let where_clause = self.where_clause_with_trait_bound(
+            &parse_quote! { ::core::clone::Clone },
+        );
+        let members = self.fields.members();
+
+        let clone_body = if self.fields.is_empty() {
+            match self.shape {
+                Fields::Named(_) | Fields::Unit => quote! {
+                    Self { _phantom: ::core::marker::PhantomData }
+                },
+                Fields::Unnamed(_) => quote! {
+                    Self { 0: ::core::marker::PhantomData }
+                },
+            }
+        } else {
+            quote! {
+                Self {
+                    #(
+                        #members: ::core::clone::Clone::clone(&self.#members),
+                    )*
+                }
+            }
+        };
+
+        quote! {
+            impl #impl_gen ::core::clone::Clone for #name #ty_gen #where_clause {
+                fn clone(&self) -> Self {
+                    #clone_body
+                }
+            }
+        }
+    }
+
+    /// Generate a `PartialEq` implementation.
+    fn partial_eq_impl(&self) -> TokenStream {
+        let name = self.name;
+        let (impl_gen, ty_gen, _) = self.generics.split_for_impl();
+        let where_clause = self.where_clause_with_trait_bound(
+            &parse_quote! { ::core::cmp::PartialEq },
+        );
+        let members = self.fields.members();
+
+        // Return true if there aren't any fields to compare.
+        let partial_eq_body: Expr = if self.fields.is_empty() {
+            parse_quote! { true }
+        } else {
+            parse_quote! {
+                #(self.#members == other.#members) && *
+            }
+        };
+
+        quote! {
+            impl #impl_gen ::core::cmp::PartialEq for #name #ty_gen #where_clause {
+                fn eq(&self, other: &Self) -> bool {
+                    #partial_eq_body
+                }
+            }
+        }
+    }
+
+    /// Generate an `Eq` implementation.
+    fn eq_impl(&self) -> TokenStream {
+        let name = self.name;
+        let (impl_gen, ty_gen, _) = self.generics.split_for_impl();
+        let where_clause = self
+            .where_clause_with_trait_bound(&parse_quote! { ::core::cmp::Eq });
+
+        quote! {
+            impl #impl_gen ::core::cmp::Eq for #name #ty_gen #where_clause {}
+        }
+    }
+
+    /// Generate an `Invert` implementation that inverts each field.
+    fn invert_impl(&self) -> TokenStream {
+        let name = self.name;
+        let (impl_gen, ty_gen, _) = self.generics.split_for_impl();
+        let daft_crate = self.daft_crate;
+        let where_clause = self.where_clause_with_trait_bound(
+            &parse_quote! { #daft_crate::Invert },
+        );
+        let members = self.fields.members();
+
+        // A struct without any fields is its own inverse.
+        let invert_body = if self.fields.is_empty() {
+            quote! { self }
+        } else {
+            quote! {
+                Self {
+                    #(
+                        #members: #daft_crate::Invert::invert(self.#members),
+                    )*
+                }
+            }
+        };
+
+        quote! {
+            impl #impl_gen #daft_crate::Invert for #name #ty_gen #where_clause {
+                fn invert(self) -> Self {
+                    #invert_body
+                }
+            }
+        }
+    }
+
+    /// Generate a `Patch` implementation that applies the diff of each field
+    /// to the corresponding field of `target_ty`, the original struct.
+    fn patch_impl(
+        &self,
+        target_ty: &Type,
+        diff_fields: &DiffFields,
+    ) -> TokenStream {
+        let name = self.name;
+        let (impl_gen, ty_gen, _) = self.generics.split_for_impl();
+        let daft_crate = self.daft_crate;
+
+        let mut where_clause = self.where_clause.clone();
+        where_clause.predicates.extend(
+            self.bounded_types.iter().zip(&diff_fields.source_types).map(
+                |(BoundedType { lifetimes, ty }, source_ty)| -> WherePredicate {
+                    parse_quote_spanned! {ty.span()=>
+                        #lifetimes #ty: #daft_crate::Patch<#source_ty>
+                    }
+                },
+            ),
+        );
+        let members = self.fields.members();
+        let source_members = &diff_fields.source_members;
+
+        // Avoid unused variable warnings if there aren't any fields.
+        let unused = self.fields.is_empty().then(|| {
+            quote! { let _ = target; }
+        });
+
+        quote! {
+            impl #impl_gen #daft_crate::Patch<#target_ty> for #name #ty_gen #where_clause {
+                fn apply(
+                    &self,
+                    target: &mut #target_ty,
+                ) -> ::core::result::Result<(), #daft_crate::PatchError> {
+                    #unused
+                    #(
+                        #daft_crate::Patch::apply(
+                            &self.#members,
+                            &mut target.#source_members,
+                        )?;
+                    )*
+                    ::core::result::Result::Ok(())
+                }
+            }
+        }
+    }
+
+    /// Generate a `Merge` implementation that merges the diff of each field
+    /// into the corresponding field of `target_ty`, the original struct.
+    ///
+    /// Owned diffs don't record the base values needed for merging, so this is
+    /// only used for the borrowed diff struct.
+    fn merge_impl(
+        &self,
+        target_ty: &Type,
+        diff_fields: &DiffFields,
+    ) -> TokenStream {
+        let name = self.name;
+        let (impl_gen, ty_gen, _) = self.generics.split_for_impl();
+        let daft_crate = self.daft_crate;
+
+        let mut where_clause = self.where_clause.clone();
+        where_clause.predicates.extend(
+            self.bounded_types.iter().zip(&diff_fields.source_types).map(
+                |(BoundedType { lifetimes, ty }, source_ty)| -> WherePredicate {
+                    parse_quote_spanned! {ty.span()=>
+                        #lifetimes #ty: #daft_crate::Merge<#source_ty>
+                    }
+                },
+            ),
+        );
+        let members = self.fields.members();
+        let source_members = &diff_fields.source_members;
+        let segments = diff_fields
+            .source_members
+            .iter()
+            .map(|member| path_segment(daft_crate, member));
+
+        // Avoid unused variable warnings if there aren't any fields.
+        let unused = self.fields.is_empty().then(|| {
+            quote! { let _ = (theirs, target, path, conflict); }
+        });
+
+        quote! {
+            impl #impl_gen #daft_crate::Merge<#target_ty> for #name #ty_gen #where_clause {
+                fn merge_at(
+                    &self,
+                    theirs: &Self,
+                    target: &mut #target_ty,
+                    path: &#daft_crate::DiffPath<'_>,
+                    conflict: &mut dyn FnMut(
+                        &#daft_crate::DiffPath<'_>,
+                        #daft_crate::MergeConflictKind,
+                    ),
+                ) {
+                    #unused
+                    #(
+                        #daft_crate::Merge::merge_at(
+                            &self.#members,
+                            &theirs.#members,
+                            &mut target.#source_members,
+                            &path.join(#segments),
+                            conflict,
+                        );
+                    )*
+                }
+            }
+        }
+    }
+
+    /// Generate a `Serialize` implementation, used if daft's `serde` feature
+    /// is enabled.
+    fn serialize_impl(&self) -> TokenStream {
+        let name = self.name;
+        let (impl_gen, ty_gen, _) = self.generics.split_for_impl();
+        let daft_crate = self.daft_crate;
+        let serde = serde_path(daft_crate);
+        let where_clause = self
+            .where_clause_with_trait_bound(&parse_quote! { #serde::Serialize });
+        let members: Vec<_> = self.fields.members().collect();
+        let len = Literal::usize_unsuffixed(members.len());
+
+        // Match the representation used by `#[derive(Serialize)]`.
+        let serialize_body = match self.shape {
+            Fields::Named(_) => {
+                let names = members.iter().map(member_name);
+                quote! {
+                    let mut __state = #serde::Serializer::serialize_struct(
+                        __serializer,
+                        stringify!(#name),
+                        #len,
+                    )?;
+                    #(
+                        #serde::ser::SerializeStruct::serialize_field(
+                            &mut __state,
+                            #names,
+                            &self.#members,
+                        )?;
+                    )*
+                    #serde::ser::SerializeStruct::end(__state)
+                }
+            }
+            Fields::Unnamed(_) if members.len() == 1 => quote! {
+                #serde::Serializer::serialize_newtype_struct(
+                    __serializer,
+                    stringify!(#name),
+                    &self.0,
+                )
+            },
+            Fields::Unnamed(_) => quote! {
+                let mut __state = #serde::Serializer::serialize_tuple_struct(
+                    __serializer,
+                    stringify!(#name),
+                    #len,
+                )?;
+                #(
+                    #serde::ser::SerializeTupleStruct::serialize_field(
+                        &mut __state,
+                        &self.#members,
+                    )?;
+                )*
+                #serde::ser::SerializeTupleStruct::end(__state)
+            },
+            Fields::Unit => quote! {
+                #serde::Serializer::serialize_unit_struct(
+                    __serializer,
+                    stringify!(#name),
+                )
+            },
+        };
+
+        quote! {
+            #daft_crate::__private::serde_impl! {
+                impl #impl_gen #serde::Serialize for #name #ty_gen #where_clause {
+                    fn serialize<__S>(
+                        &self,
+                        __serializer: __S,
+                    ) -> ::core::result::Result<__S::Ok, __S::Error>
+                    where
+                        __S: #serde::Serializer,
+                    {
+                        #serialize_body
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Impl `Diffable` for the original struct
+fn make_diff_impl(
+    input: &DeriveInput,
+    diff_type: &DiffTypeConfig,
+    diff_fields: &DiffFields,
+) -> TokenStream {
+    // The name of the original type
+    let ident = &input.ident;
+
+    // The name of the generated type
+    let name = diff_type.name(input);
+    let (before, after): (Vec<_>, Vec<_>) = diff_fields
+        .source_members
+        .iter()
+        .map(|member| (quote! { &self.#member }, quote! { &other.#member }))
+        .unzip();
+    let diffs = generate_field_diffs(diff_fields, &before, &after);
+
+    let daft_crate = &diff_fields.daft_crate;
+    let daft_lt = daft_lifetime();
+    let new_generics = add_lifetime_to_generics(input, &daft_lt);
+
+    let (impl_gen, ty_gen, _) = &input.generics.split_for_impl();
+    let (_, new_ty_gen, where_clause) = &new_generics.split_for_impl();
+
+    let constructor = if diff_fields.fields.is_empty() {
+        match &diff_fields.fields {
+            Fields::Named(_) | Fields::Unit => quote! {
+                Self::Diff { _phantom: ::core::marker::PhantomData }
+            },
+            Fields::Unnamed(_) => quote! {
+                Self::Diff { 0: ::core::marker::PhantomData }
+            },
+        }
+    } else {
+        quote! {
+            Self::Diff {
+                #diffs
+            }
+        }
+    };
+
+    quote! {
+        impl #impl_gen #daft_crate::Diffable for #ident #ty_gen
+            #where_clause
+        {
+            type Diff<#daft_lt> = #name #new_ty_gen where Self: #daft_lt;
+
+            fn diff<#daft_lt>(&#daft_lt self, other: &#daft_lt Self) -> #name #new_ty_gen {
+                #constructor
+            }
+        }
+    }
+}
+
+fn make_enum_impl(
+    input: &DeriveInput,
+    e: &DataEnum,
+    errors: ErrorSink<'_, syn::Error>,
+) -> Option<TokenStream> {
+    // Errors in the enum configuration are not fatal: we still generate an
+    // implementation so that rust-analyzer understands it exists.
+    let enum_config = EnumConfig::parse_from(&input.attrs, errors.new_child());
+
+    match enum_config.mode {
+        // Implement enums as `Leaf`s by default.
+        EnumMode::Leaf => Some(make_leaf(
+            input,
+            AttrPosition::Enum,
+            &enum_config.daft_crate,
+            errors.new_child(),
+        )),
+        EnumMode::Recursive => make_diff_enum(
+            input,
+            e,
+            &enum_config.daft_crate,
+            &enum_config.diff_type,
+            errors.new_child(),
+        )
+        .map(|(generated_enum, diff_variants)| {
+            let diff_impl = make_enum_diff_impl(
+                input,
+                &diff_variants,
+                &enum_config.daft_crate,
+                &enum_config.diff_type,
+            );
+            quote! {
+                #generated_enum
+                #diff_impl
+            }
+        }),
+    }
+}
+
+/// The name of the variant used by recursive enum diffs when the two sides
+/// have different variants.
+const VARIANT_CHANGED: &str = "VariantChanged";
+
+/// Create the `Diff` enum for an enum annotated with `#[daft(recursive)]`.
+fn make_diff_enum(
+    input: &DeriveInput,
+    e: &DataEnum,
+    daft_crate: &Path,
+    diff_type: &DiffTypeConfig,
+    errors: ErrorSink<'_, syn::Error>,
+) -> Option<(TokenStream, Vec<DiffVariant>)> {
+    let vis = diff_type.vis(input);
+    let ident = &input.ident;
+
+    // The name of the generated type
+    let name = diff_type.name(input);
+
+    // Derives and attributes specified for the generated type.
+    let diff_type_attrs = diff_type.attrs();
+
+    // Copy over the non-exhaustive attribute from the original enum.
+    let non_exhaustive =
+        input.attrs.iter().find(|attr| attr.path().is_ident("non_exhaustive"));
+
+    let daft_lt = daft_lifetime();
+    let new_generics = add_lifetime_to_generics(input, &daft_lt);
+    let where_clause = &new_generics.where_clause;
+
+    let diff_variants: Vec<_> = e
+        .variants
+        .iter()
+        .filter_map(|v| {
+            DiffVariant::new(
+                v,
+                where_clause.as_ref(),
+                daft_crate,
+                errors.new_child(),
+            )
+        })
+        .collect();
+    if errors.has_critical_errors() {
+        // An error occurred parsing variants -- don't generate the diff enum.
+        return None;
+    }
+
+    // --- No more errors past this point ---
+
+    // The variant used if the two sides have different variants. Its type
+    // uses both `'__daft` and the original generics, so there's no need for a
+    // `PhantomData` even if all other variants are empty.
+    let variant_changed = format_ident!("{}", VARIANT_CHANGED);
+    let variant_changed_ty: Type = {
+        let (_, orig_ty_gen, _) = input.generics.split_for_impl();
+        parse_quote! { #daft_crate::Leaf<&#daft_lt #ident #orig_ty_gen> }
+    };
+
+    let variant_defs = diff_variants.iter().map(|v| {
+        let ident = &v.ident;
+        let fields = &v.diff_fields;
+        quote! { #ident #fields }
+    });
+
+    let enum_def = quote! {
+        #diff_type_attrs
+        #non_exhaustive
+        #vis enum #name #new_generics #where_clause {
+            #(#variant_defs,)*
+            #variant_changed(#variant_changed_ty),
+        }
+    };
+
+    // As with structs, generate PartialEq, Eq, and Debug implementations by
+    // hand so that the bounds are on the Diffable::Diff types.
+    let (impl_gen, ty_gen, _) = &new_generics.split_for_impl();
+    let base_where_clause =
+        where_clause.clone().unwrap_or_else(|| WhereClause {
+            where_token: <Token![where]>::default(),
+            predicates: Default::default(),
+        });
+    let where_clause_with_trait_bound = |trait_bound: &syn::TraitBound| {
+        let mut where_clause = base_where_clause.clone();
+        for v in &diff_variants {
+            where_clause.predicates.extend(
+                v.diff_fields
+                    .where_clause_with_trait_bound(trait_bound)
+                    .predicates,
+            );
+        }
+        where_clause.predicates.push(parse_quote! {
+            #variant_changed_ty: #trait_bound
+        });
+        where_clause
+    };
+
+    let debug_impl = {
+        let where_clause =
+            where_clause_with_trait_bound(&parse_quote! { ::core::fmt::Debug });
+        let arms = diff_variants.iter().map(|v| {
+            let ident = &v.ident;
+            let members: Vec<_> = v.diff_fields.fields.members().collect();
+            let bindings = field_bindings("__field", members.len());
+            let body = match &v.diff_fields.fields {
+                Fields::Named(_) => quote! {
+                    f.debug_struct(stringify!(#ident))
+                    #(
+                        .field(stringify!(#members), #bindings)
+                    )*
+                    .finish()
+                },
+                Fields::Unnamed(_) => quote! {
+                    f.debug_tuple(stringify!(#ident))
+                    #(
+                        .field(#bindings)
+                    )*
+                    .finish()
+                },
+                Fields::Unit => quote! {
+                    f.write_str(stringify!(#ident))
+                },
+            };
+            quote! {
+                Self::#ident { #(#members: #bindings),* } => #body,
+            }
+        });
+        quote! {
+            impl #impl_gen ::core::fmt::Debug for #name #ty_gen #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        #(#arms)*
+                        Self::#variant_changed(leaf) => f
+                            .debug_tuple(stringify!(#variant_changed))
+                            .field(leaf)
+                            .finish(),
+                    }
+                }
+            }
+        }
+    };
+
+    let partial_eq_impl = {
+        let where_clause = where_clause_with_trait_bound(
+            &parse_quote! { ::core::cmp::PartialEq },
+        );
+        let arms = diff_variants.iter().map(|v| {
+            let ident = &v.ident;
+            let members: Vec<_> = v.diff_fields.fields.members().collect();
+            let self_bindings = field_bindings("__self", members.len());
+            let other_bindings = field_bindings("__other", members.len());
+
+            // Return true if there aren't any fields to compare.
+            let body: Expr = if members.is_empty() {
+                parse_quote! { true }
+            } else {
+                parse_quote! {
+                    #(#self_bindings == #other_bindings) && *
+                }
+            };
+            quote! {
+                (
+                    Self::#ident { #(#members: #self_bindings),* },
+                    Self::#ident { #(#members: #other_bindings),* },
+                ) => #body,
+            }
+        });
+        quote! {
+            impl #impl_gen ::core::cmp::PartialEq for #name #ty_gen #where_clause {
+                fn eq(&self, other: &Self) -> bool {
+                    match (self, other) {
+                        #(#arms)*
+                        (
+                            Self::#variant_changed(self_leaf),
+                            Self::#variant_changed(other_leaf),
+                        ) => self_leaf == other_leaf,
+                        #[allow(unreachable_patterns)]
+                        _ => false,
+                    }
+                }
+            }
+        }
+    };
+
+    let eq_impl = {
+        let where_clause =
+            where_clause_with_trait_bound(&parse_quote! { ::core::cmp::Eq });
+
+        quote! {
+            impl #impl_gen ::core::cmp::Eq for #name #ty_gen #where_clause {}
+        }
+    };
+
+    let summary_impl = {
+        // The `VariantChanged` variant is always modified, so it doesn't need
+        // a bound.
+        let mut where_clause = base_where_clause.clone();
+        for v in &diff_variants {
+            where_clause.predicates.extend(
+                v.diff_fields
+                    .where_clause_with_trait_bound(
+                        &parse_quote! { #daft_crate::DiffSummary },
+                    )
+                    .predicates,
+            );
+        }
+        let arms = diff_variants.iter().map(|v| {
+            let ident = &v.ident;
+            let members: Vec<_> = v.diff_fields.fields.members().collect();
+            let bindings = field_bindings("__field", members.len());
+            let unchanged = v
+                .diff_fields
+                .unchanged_exprs(bindings.iter().map(|b| quote! { #b }));
+
+            // A variant without any fields is always unchanged.
+            let body: Expr = if members.is_empty() {
+                parse_quote! { true }
+            } else {
+                parse_quote! { #(#unchanged) && * }
+            };
+            quote! {
+                Self::#ident { #(#members: #bindings),* } => #body,
+            }
+        });
+        quote! {
+            impl #impl_gen #daft_crate::DiffSummary for #name #ty_gen #where_clause {
+                fn is_unchanged(&self) -> bool {
+                    match self {
+                        #(#arms)*
+                        Self::#variant_changed(_) => false,
+                    }
+                }
+            }
+        }
+    };
+
+    let visit_impl = {
+        let mut where_clause = base_where_clause.clone();
+        for v in &diff_variants {
+            where_clause.predicates.extend(
+                v.diff_fields
+                    .where_clause_with_trait_bound(
+                        &parse_quote! { #daft_crate::VisitDiff },
+                    )
+                    .predicates,
+            );
+        }
+        // If the variant changed, the before and after values are reported
+        // as a modification.
+        let (_, orig_ty_gen, _) = input.generics.split_for_impl();
+        where_clause.predicates.push(parse_quote! {
+            &#daft_lt #ident #orig_ty_gen: ::core::fmt::Debug
+        });
+
+        let arms = diff_variants.iter().map(|v| {
+            let ident = &v.ident;
+            let members: Vec<_> = v.diff_fields.fields.members().collect();
+            let bindings = field_bindings("__field", members.len());
+            if members.is_empty() {
+                return quote! { Self::#ident {} => {} };
+            }
+
+            let variant = LitStr::new(&ident.unraw().to_string(), ident.span());
+            let segments = v
+                .diff_fields
+                .source_members
+                .iter()
+                .map(|member| path_segment(daft_crate, member));
+            quote! {
+                Self::#ident { #(#members: #bindings),* } => {
+                    let path = path.join(
+                        #daft_crate::PathSegment::Variant(#variant),
+                    );
+                    #(
+                        #daft_crate::VisitDiff::visit_at(
+                            #bindings,
+                            &path.join(#segments),
+                            visitor,
+                        );
+                    )*
+                }
+            }
+        });
+        quote! {
+            impl #impl_gen #daft_crate::VisitDiff for #name #ty_gen #where_clause {
+                fn visit_at(
+                    &self,
+                    path: &#daft_crate::DiffPath<'_>,
+                    visitor: &mut dyn #daft_crate::DiffVisitor,
+                ) {
+                    match self {
+                        #(#arms)*
+                        Self::#variant_changed(leaf) => {
+                            #daft_crate::DiffVisitor::modified(
+                                visitor,
+                                path,
+                                &leaf.before,
+                                &leaf.after,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    let invert_impl = {
+        let where_clause = where_clause_with_trait_bound(
+            &parse_quote! { #daft_crate::Invert },
+        );
+        let arms = diff_variants.iter().map(|v| {
+            let ident = &v.ident;
+            let members: Vec<_> = v.diff_fields.fields.members().collect();
+            let bindings = field_bindings("__field", members.len());
+            quote! {
+                Self::#ident { #(#members: #bindings),* } => Self::#ident {
+                    #(#members: #daft_crate::Invert::invert(#bindings)),*
+                },
+            }
+        });
+        quote! {
+            impl #impl_gen #daft_crate::Invert for #name #ty_gen #where_clause {
+                fn invert(self) -> Self {
+                    match self {
+                        #(#arms)*
+                        Self::#variant_changed(leaf) => Self::#variant_changed(
+                            #daft_crate::Invert::invert(leaf),
+                        ),
+                    }
+                }
+            }
+        }
+    };
+
+    let serialize_impl = {
+        let serde = serde_path(daft_crate);
+        let where_clause =
+            where_clause_with_trait_bound(&parse_quote! { #serde::Serialize });
+
+        // Match the representation used by `#[derive(Serialize)]`.
+        let arms = diff_variants.iter().enumerate().map(|(index, v)| {
+            let ident = &v.ident;
+            let index = Literal::u32_unsuffixed(index as u32);
+            let variant = LitStr::new(&ident.unraw().to_string(), ident.span());
+            let members: Vec<_> = v.diff_fields.fields.members().collect();
+            let bindings = field_bindings("__field", members.len());
+            let len = Literal::usize_unsuffixed(members.len());
+            let body = match &v.diff_fields.fields {
+                Fields::Named(_) => {
+                    let names = members.iter().map(member_name);
+                    quote! {{
+                        let mut __state =
+                            #serde::Serializer::serialize_struct_variant(
+                                __serializer,
+                                stringify!(#name),
+                                #index,
+                                #variant,
+                                #len,
+                            )?;
+                        #(
+                            #serde::ser::SerializeStructVariant::serialize_field(
+                                &mut __state,
+                                #names,
+                                #bindings,
+                            )?;
+                        )*
+                        #serde::ser::SerializeStructVariant::end(__state)
+                    }}
+                }
+                Fields::Unnamed(_) if members.len() == 1 => quote! {
+                    #serde::Serializer::serialize_newtype_variant(
+                        __serializer,
+                        stringify!(#name),
+                        #index,
+                        #variant,
+                        #(#bindings)*,
+                    )
+                },
+                Fields::Unnamed(_) => quote! {{
+                    let mut __state =
+                        #serde::Serializer::serialize_tuple_variant(
+                            __serializer,
+                            stringify!(#name),
+                            #index,
+                            #variant,
+                            #len,
+                        )?;
+                    #(
+                        #serde::ser::SerializeTupleVariant::serialize_field(
+                            &mut __state,
+                            #bindings,
+                        )?;
+                    )*
+                    #serde::ser::SerializeTupleVariant::end(__state)
+                }},
+                Fields::Unit => quote! {
+                    #serde::Serializer::serialize_unit_variant(
+                        __serializer,
+                        stringify!(#name),
+                        #index,
+                        #variant,
+                    )
+                },
+            };
+            quote! {
+                Self::#ident { #(#members: #bindings),* } => #body,
+            }
+        });
+        let variant_changed_index =
+            Literal::u32_unsuffixed(diff_variants.len() as u32);
+
+        quote! {
+            #daft_crate::__private::serde_impl! {
+                impl #impl_gen #serde::Serialize for #name #ty_gen #where_clause {
+                    fn serialize<__S>(
+                        &self,
+                        __serializer: __S,
+                    ) -> ::core::result::Result<__S::Ok, __S::Error>
+                    where
+                        __S: #serde::Serializer,
+                    {
+                        match self {
+                            #(#arms)*
+                            Self::#variant_changed(leaf) => {
+                                #serde::Serializer::serialize_newtype_variant(
+                                    __serializer,
+                                    stringify!(#name),
+                                    #variant_changed_index,
+                                    stringify!(#variant_changed),
+                                    leaf,
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    Some((
+        quote! {
+            #enum_def
+            #debug_impl
+            #partial_eq_impl
+            #eq_impl
+            #summary_impl
+            #visit_impl
+            #invert_impl
+            #serialize_impl
+        },
+        diff_variants,
+    ))
+}
+
+/// Impl `Diffable` for the original enum, for enums annotated with
+/// `#[daft(recursive)]`.
+fn make_enum_diff_impl(
+    input: &DeriveInput,
+    diff_variants: &[DiffVariant],
+    daft_crate: &Path,
+    diff_type: &DiffTypeConfig,
+) -> TokenStream {
+    // The name of the original type
+    let ident = &input.ident;
+
+    // The name of the generated type
+    let name = diff_type.name(input);
+
+    let daft_lt = daft_lifetime();
+    let new_generics = add_lifetime_to_generics(input, &daft_lt);
+    let variant_changed = format_ident!("{}", VARIANT_CHANGED);
+
+    let (impl_gen, ty_gen, _) = &input.generics.split_for_impl();
+    let (_, new_ty_gen, where_clause) = &new_generics.split_for_impl();
+
+    // Each pair of matching variants is diffed field by field. Ignored fields
+    // are skipped over with `..`.
+    let arms = diff_variants.iter().map(|v| {
+        let variant = &v.ident;
+        let members = &v.diff_fields.source_members;
+        let before = field_bindings("__daft_before", members.len());
+        let after = field_bindings("__daft_after", members.len());
+        let diffs = generate_field_diffs(
+            &v.diff_fields,
+            &before.iter().map(ToTokens::to_token_stream).collect::<Vec<_>>(),
+            &after.iter().map(ToTokens::to_token_stream).collect::<Vec<_>>(),
+        );
+        quote! {
+            (
+                Self::#variant { #(#members: #before,)* .. },
+                Self::#variant { #(#members: #after,)* .. },
+            ) => #name::#variant { #diffs },
+        }
+    });
+
+    quote! {
+        impl #impl_gen #daft_crate::Diffable for #ident #ty_gen
+            #where_clause
+        {
+            type Diff<#daft_lt> = #name #new_ty_gen where Self: #daft_lt;
+
+            fn diff<#daft_lt>(&#daft_lt self, other: &#daft_lt Self) -> #name #new_ty_gen {
+                match (self, other) {
+                    #(#arms)*
+                    // This is unreachable if the enum has a single variant.
+                    #[allow(unreachable_patterns)]
+                    _ => #name::#variant_changed(#daft_crate::Leaf {
+                        before: self,
+                        after: other,
+                    }),
+                }
+            }
+        }
+    }
+}
+
+/// Returns the path to the `serde` crate, as re-exported by daft.
+///
+/// Uses of this path must be wrapped in the `serde_impl!` macro, since the
+/// re-export only exists if daft's `serde` feature is enabled.
+fn serde_path(daft_crate: &Path) -> Path {
+    parse_quote! { #daft_crate::__private::serde }
+}
+
+/// Returns the serialized name of a named member.
+fn member_name(member: &Member) -> LitStr {
+    match member {
+        Member::Named(ident) => {
+            LitStr::new(&ident.unraw().to_string(), ident.span())
+        }
+        Member::Unnamed(_) => {
+            unreachable!("member_name is only called for named fields")
+        }
+    }
+}
+
+/// Returns a `PathSegment` expression for a member of the original type.
+fn path_segment(daft_crate: &Path, member: &Member) -> TokenStream {
+    match member {
+        Member::Named(ident) => {
+            let name = LitStr::new(&ident.unraw().to_string(), ident.span());
+            quote! { #daft_crate::PathSegment::Field(#name) }
+        }
+        Member::Unnamed(index) => {
+            let index = Literal::usize_unsuffixed(index.index as usize);
+            quote! { #daft_crate::PathSegment::TupleIndex(#index) }
+        }
+    }
+}
+
+/// Returns `count` identifiers with the given prefix, for use as bindings in
+/// patterns.
+fn field_bindings(prefix: &str, count: usize) -> Vec<Ident> {
+    (0..count).map(|i| format_ident!("{}_{}", prefix, i)).collect()
+}
+
+/// For a `Diff` enum generated by this derive macro, tracks a variant and its
+/// fields.
+struct DiffVariant {
+    ident: Ident,
+    diff_fields: DiffFields,
+}
+
+impl DiffVariant {
+    /// None means there was an error parsing a config.
+    fn new(
+        v: &Variant,
+        where_clause: Option<&WhereClause>,
+        daft_crate: &Path,
+        errors: ErrorSink<'_, syn::Error>,
+    ) -> Option<Self> {
+        // Variants themselves don't currently accept any daft attributes.
+        for attr in &v.attrs {
+            if attr.path().is_ident("daft") {
+                errors.push_critical(syn::Error::new_spanned(
+                    attr,
+                    format!(
+                        "daft attributes are not allowed {}",
+                        AttrPosition::Variant.as_locative_str(),
+                    ),
+                ));
+            }
+        }
+
+        if v.ident == VARIANT_CHANGED {
+            errors.push_critical(syn::Error::new_spanned(
+                &v.ident,
+                format!(
+                    "variant name `{VARIANT_CHANGED}` is reserved for the \
+                     diff type generated by #[daft(recursive)]",
+                ),
+            ));
+        }
+
+        let diff_fields = DiffFields::new(
+            &v.fields,
+            where_clause,
+            daft_crate,
+            errors.new_child(),
+        )?;
+
+        if errors.has_critical_errors() {
+            None
+        } else {
+            Some(Self { ident: v.ident.clone(), diff_fields })
+        }
+    }
+}
+
+/// For a `Diff` struct generated by this derive macro, tracks the fields that
+/// will be put into that struct.
+///
+/// This also tracks the `where` clause.
+///
+/// The goal of this wrapper is to provide helpers to iterate over the fields
+/// and members.
+struct DiffFields {
+    fields: Fields,
+    // Configuration for each field -- a vector with the same length as `self.fields`.
+    field_configs: Vec<FieldConfig>,
+    // The members of the original type that each field was created from -- a
+    // vector with the same length as `self.fields`. For tuple-like fields,
+    // these differ from `self.fields.members()` if any fields are ignored.
+    source_members: Vec<Member>,
+    // The types of the original fields -- a vector with the same length as
+    // `self.fields`.
+    source_types: Vec<Type>,
+    // The base where clause for the diff struct.
+    where_clause: WhereClause,
+    // The path to the daft crate.
+    daft_crate: Path,
+}
+
+impl DiffFields {
+    /// None means there was an error parsing a config.
+    fn new(
+        fields: &Fields,
+        where_clause: Option<&WhereClause>,
+        daft_crate: &Path,
+        errors: ErrorSink<'_, syn::Error>,
+    ) -> Option<Self> {
+        let mut diff_fields = Punctuated::<Field, Token![,]>::new();
+        let mut field_configs = Vec::new();
+        let mut source_members = Vec::new();
+        let mut source_types = Vec::new();
+        for (member, field) in fields.members().zip(fields) {
+            if let Some((f, config)) =
+                Self::diff_field(field, daft_crate, errors.new_child())
+            {
+                diff_fields.push(f);
+                field_configs.push(config);
+                source_members.push(member);
+                source_types.push(field.ty.clone());
+            }
+        }
+
+        let fields = match fields {
+            Fields::Named(fields) => Fields::Named(syn::FieldsNamed {
+                brace_token: fields.brace_token,
+                named: diff_fields,
+            }),
+            Fields::Unnamed(fields) => Fields::Unnamed(syn::FieldsUnnamed {
+                paren_token: fields.paren_token,
+                unnamed: diff_fields,
+            }),
+            Fields::Unit => Fields::Unit,
+        };
+
+        // Initialize an empty where clause if none was provided.
+        let where_clause =
+            where_clause.cloned().unwrap_or_else(|| WhereClause {
+                where_token: <Token![where]>::default(),
+                predicates: Default::default(),
+            });
+
+        if errors.has_critical_errors() {
+            None
+        } else {
+            Some(Self {
+                fields,
+                field_configs,
+                source_members,
+                source_types,
+                where_clause,
+                daft_crate: daft_crate.clone(),
+            })
+        }
+    }
+
+    /// Return a field for a diff with the appropriate type.
+    ///
+    /// If the type is ignored, or if there's an error parsing configuration,
+    /// return None.
+    fn diff_field(
+        f: &Field,
+        daft_crate: &Path,
+        errors: ErrorSink<'_, syn::Error>,
+    ) -> Option<(Field, FieldConfig)> {
+        let Some(config) =
+            FieldConfig::parse_from(&f.attrs, errors.new_child())
+        else {
+            // None means there's an error parsing a config -- return None here,
+            // we'll emit errors at the top level.
+            return None;
+        };
+        if matches!(config.mode, FieldMode::Ignore) {
+            // Skip over this field if there's an ignore.
+            return None;
+        }
+
+        // Always use the daft lifetime for the diff -- associations between the
+        // daft lifetime and existing parameters (both lifetime and type
         // parameters) are created in `add_lifetime_to_generics`, e.g. `'a:
         // '__daft`, or `T: '__daft`.
         let lt = daft_lifetime();
-        let daft_crate = daft_crate();
-        let ty = &f.ty;
         let mut f = f.clone();
-
-        f.ty = if config.mode == FieldMode::Leaf {
-            parse_quote_spanned! {f.span()=>
-                #daft_crate::Leaf<&#lt #ty>
-            }
-        } else {
-            parse_quote_spanned! {f.span()=>
-                <#ty as #daft_crate::Diffable>::Diff<#lt>
-            }
-        };
+        f.ty = diff_field_ty(
+            daft_crate,
+            &f.ty,
+            &config.mode,
+            &lt.lifetime,
+            f.span(),
+        );
 
         // Drop all attributes for now. We may want to carry some over in the
         // future.
@@ -636,28 +2040,150 @@ impl DiffFields {
         Some((f, config))
     }
 
+    /// Returns an iterator over the types of the diffs of each field, with
+    /// the `'static` lifetime rather than the daft lifetime.
+    fn static_types(&self) -> impl Iterator<Item = Type> + '_ {
+        let lt = Lifetime::new("'static", Span::call_site());
+        self.source_types.iter().zip(&self.field_configs).map(
+            move |(ty, config)| {
+                diff_field_ty(
+                    &self.daft_crate,
+                    ty,
+                    &config.mode,
+                    &lt,
+                    ty.span(),
+                )
+            },
+        )
+    }
+
     /// Returns an iterator over field types.
     fn types(&self) -> impl Iterator<Item = &syn::Type> {
         self.fields.iter().map(|f| &f.ty)
     }
 
+    /// Returns expressions that check whether each field is unchanged, given
+    /// references to the diffs of the fields.
+    ///
+    /// Fields annotated with `#[daft(tolerance = ...)]` are compared
+    /// approximately, and other fields use `DiffSummary`.
+    fn unchanged_exprs<'a>(
+        &'a self,
+        values: impl IntoIterator<Item = TokenStream> + 'a,
+    ) -> impl Iterator<Item = Expr> + 'a {
+        let daft_crate = &self.daft_crate;
+        values.into_iter().zip(&self.field_configs).map(
+            move |(value, config)| match &config.tolerance {
+                Some(tolerance) => parse_quote! {
+                    (#value).approx_eq(
+                        #daft_crate::Tolerance::from(#tolerance),
+                    )
+                },
+                None => parse_quote! {
+                    #daft_crate::DiffSummary::is_unchanged(#value)
+                },
+            },
+        )
+    }
+
     /// Returns an expanded where clause where the fields have had a trait bound
     /// applied to them.
     fn where_clause_with_trait_bound(
         &self,
         trait_bound: &syn::TraitBound,
     ) -> WhereClause {
-        let predicates = self.types().map(|ty| -> WherePredicate {
-            parse_quote_spanned! {ty.span()=>
-                #ty: #trait_bound
+        add_trait_bound(&self.where_clause, self.types(), trait_bound)
+    }
+}
+
+/// Returns the type of the diff for a field of type `ty`, borrowing from the
+/// original value with the lifetime `lt`.
+fn diff_field_ty(
+    daft_crate: &Path,
+    ty: &Type,
+    mode: &FieldMode,
+    lt: &Lifetime,
+    span: Span,
+) -> Type {
+    match mode {
+        FieldMode::Leaf => parse_quote_spanned! {span=>
+            #daft_crate::Leaf<&#lt #ty>
+        },
+        FieldMode::Text => parse_quote_spanned! {span=>
+            #daft_crate::TextDiff<#lt>
+        },
+        FieldMode::RecursiveMap => parse_quote_spanned! {span=>
+            <#ty as #daft_crate::DiffableMap>::RecursiveDiff<#lt>
+        },
+        FieldMode::Keyed(keyed) => {
+            let key_ty = &keyed.key_ty;
+            // The element type is obtained by iterating over a reference to
+            // the field, which works for vectors, slices, and other sequence
+            // types.
+            parse_quote_spanned! {span=>
+                #daft_crate::KeyedDiff<
+                    #lt,
+                    #key_ty,
+                    <<&#lt #ty as ::core::iter::IntoIterator>::Item
+                        as ::core::ops::Deref>::Target,
+                >
             }
-        });
+        }
+        FieldMode::Custom(custom) => {
+            let mut diff_ty = custom.diff_ty.clone();
+            FillElidedLifetimes { lt }.visit_type_mut(&mut diff_ty);
+            parse_quote_spanned! {span=>
+                <#diff_ty as #daft_crate::__private::CustomDiff<#lt>>::Output
+            }
+        }
+        FieldMode::Default | FieldMode::Ignore => {
+            parse_quote_spanned! {span=>
+                <#ty as #daft_crate::Diffable>::Diff<#lt>
+            }
+        }
+    }
+}
 
-        let mut where_clause = self.where_clause.clone();
-        where_clause.predicates.extend(predicates);
+/// Replaces `'_` and the elided lifetimes of references in a type with `lt`.
+struct FillElidedLifetimes<'a> {
+    lt: &'a Lifetime,
+}
 
-        where_clause
+impl VisitMut for FillElidedLifetimes<'_> {
+    fn visit_lifetime_mut(&mut self, lt: &mut Lifetime) {
+        if lt.ident == "_" {
+            *lt = self.lt.clone();
+        }
     }
+
+    fn visit_type_reference_mut(&mut self, r: &mut syn::TypeReference) {
+        if r.lifetime.is_none() {
+            r.lifetime = Some(self.lt.clone());
+        }
+        syn::visit_mut::visit_type_reference_mut(self, r);
+    }
+
+    // Elided lifetimes within function pointer types are bound by the
+    // function pointer, so leave them alone.
+    fn visit_type_fn_ptr_mut(&mut self, _: &mut syn::TypeFnPtr) {}
+}
+
+/// Returns `where_clause` with `trait_bound` applied to each of `types`.
+fn add_trait_bound<'a>(
+    where_clause: &WhereClause,
+    types: impl IntoIterator<Item = &'a Type>,
+    trait_bound: &syn::TraitBound,
+) -> WhereClause {
+    let predicates = types.into_iter().map(|ty| -> WherePredicate {
+        parse_quote_spanned! {ty.span()=>
+            #ty: #trait_bound
+        }
+    });
+
+    let mut where_clause = where_clause.clone();
+    where_clause.predicates.extend(predicates);
+
+    where_clause
 }
 
 impl ToTokens for DiffFields {
@@ -666,35 +2192,68 @@ impl ToTokens for DiffFields {
     }
 }
 
-/// Generate a call to `diff` for each field of the original struct that isn't
+/// Generate a call to `diff` for each field of the original type that isn't
 /// ignored.
+///
+/// `before` and `after` are expressions that evaluate to references to each
+/// field of the original type -- they should be the same length as
+/// `diff_fields.fields`.
 fn generate_field_diffs(
-    fields: &Fields,
-    // Should be the same length as `fields`.
-    field_configs: &[FieldConfig],
+    diff_fields: &DiffFields,
+    before: &[TokenStream],
+    after: &[TokenStream],
 ) -> TokenStream {
-    let daft_crate = daft_crate();
-    let field_diffs =
-        fields.iter().zip(field_configs).enumerate().map(|(i, (f, config))| {
-            let field_name = match &f.ident {
-                Some(ident) => quote! { #ident },
-                None => {
-                    let ident: Index = i.into();
-                    quote! { #ident }
+    let daft_crate = &diff_fields.daft_crate;
+    let field_diffs = diff_fields
+        .fields
+        .iter()
+        .zip(diff_fields.fields.members())
+        .zip(&diff_fields.field_configs)
+        .zip(before.iter().zip(after))
+        .map(|(((f, member), config), (before, after))| match &config.mode {
+            FieldMode::Leaf => quote_spanned! {f.span()=>
+                #member: #daft_crate::Leaf {
+                    before: #before,
+                    after: #after
                 }
-            };
-            if config.mode == FieldMode::Leaf {
+            },
+            FieldMode::Text => quote_spanned! {f.span()=>
+                #member: #daft_crate::TextDiff::new(
+                    ::core::convert::AsRef::<str>::as_ref(#before),
+                    ::core::convert::AsRef::<str>::as_ref(#after),
+                    #daft_crate::TextUnit::Line
+                )
+            },
+            FieldMode::RecursiveMap => quote_spanned! {f.span()=>
+                #member: #daft_crate::DiffableMap::recursive_diff(
+                    #before,
+                    #after
+                )
+            },
+            FieldMode::Keyed(keyed) => {
+                let key_fn = &keyed.key_fn;
                 quote_spanned! {f.span()=>
-                    #field_name: #daft_crate::Leaf {
-                        before: &self.#field_name,
-                        after: &other.#field_name
-                    }
+                    #member: #daft_crate::KeyedDiff::new(
+                        #before,
+                        #after,
+                        #key_fn
+                    )
                 }
-            } else {
+            }
+            FieldMode::Custom(custom) => {
+                let diff_fn = &custom.diff_fn;
+                quote_spanned! {f.span()=>
+                    #member: #diff_fn(
+                        #before,
+                        #after
+                    )
+                }
+            }
+            FieldMode::Default | FieldMode::Ignore => {
                 quote_spanned! {f.span()=>
-                    #field_name: #daft_crate::Diffable::diff(
-                        &self.#field_name,
-                        &other.#field_name
+                    #member: #daft_crate::Diffable::diff(
+                        #before,
+                        #after
                     )
                 }
             }
@@ -702,9 +2261,14 @@ fn generate_field_diffs(
     quote! { #(#field_diffs),* }
 }
 
-#[derive(Debug)]
 struct StructConfig {
     mode: StructMode,
+    // Whether to generate an owned diff struct.
+    owned: bool,
+    // The path to the daft crate.
+    daft_crate: Path,
+    // Options for the generated diff struct.
+    diff_type: DiffTypeConfig,
 }
 
 impl StructConfig {
@@ -713,6 +2277,9 @@ impl StructConfig {
         errors: ErrorSink<'_, syn::Error>,
     ) -> Option<Self> {
         let mut mode = StructMode::Default;
+        let mut owned: Option<Span> = None;
+        let mut daft_crate = None;
+        let mut diff_type = DiffTypeConfig::default();
 
         for attr in attrs {
             {
@@ -729,10 +2296,25 @@ impl StructConfig {
                                 ));
                                 }
                             }
-                        } else {
+                        } else if meta.path.is_ident("owned") {
+                            if owned.is_some() {
+                                errors.push_warning(meta.error(
+                                    "#[daft(owned)] specified multiple times",
+                                ));
+                            } else {
+                                owned = Some(meta.path.span());
+                            }
+                        } else if meta.path.is_ident("crate") {
+                            return parse_crate_path(
+                                &meta,
+                                &mut daft_crate,
+                                &errors,
+                            );
+                        } else if !diff_type.parse_meta(&meta, &errors)? {
                             errors.push_critical(meta.error(
                                 "unknown attribute \
-                                 (supported attributes: leaf)",
+                                 (supported attributes: leaf, owned, crate, \
+                                 name, vis, derive, attr)",
                             ));
                         }
 
@@ -746,7 +2328,32 @@ impl StructConfig {
             }
         }
 
-        if errors.has_critical_errors() { None } else { Some(Self { mode }) }
+        if let (StructMode::Leaf, Some(span)) = (mode, owned) {
+            errors.push_critical(syn::Error::new(
+                span,
+                "this is unnecessary: Leaf diffs can be converted into owned \
+                 diffs with IntoOwnedDiff",
+            ));
+        }
+        if let (StructMode::Leaf, Some((span, option))) =
+            (mode, diff_type.first)
+        {
+            errors.push_critical(syn::Error::new(
+                span,
+                format!("#[daft({option})] conflicts with #[daft(leaf)]"),
+            ));
+        }
+
+        if errors.has_critical_errors() {
+            None
+        } else {
+            Some(Self {
+                mode,
+                owned: owned.is_some(),
+                daft_crate: daft_crate.unwrap_or_else(default_daft_crate),
+                diff_type,
+            })
+        }
     }
 }
 
@@ -758,9 +2365,230 @@ enum StructMode {
     Leaf,
 }
 
-#[derive(Debug)]
+struct EnumConfig {
+    mode: EnumMode,
+    // The path to the daft crate.
+    daft_crate: Path,
+    // Options for the generated diff enum.
+    diff_type: DiffTypeConfig,
+}
+
+impl EnumConfig {
+    // Unlike `StructConfig::parse_from`, this always returns a configuration,
+    // since a `Leaf` implementation is generated even if there are errors.
+    fn parse_from(
+        attrs: &[Attribute],
+        errors: ErrorSink<'_, syn::Error>,
+    ) -> Self {
+        let mut mode = EnumMode::Leaf;
+        let mut daft_crate = None;
+        let mut diff_type = DiffTypeConfig::default();
+
+        for attr in attrs {
+            if attr.path().is_ident("daft") {
+                let res = attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("recursive") {
+                        match mode {
+                            EnumMode::Leaf => {
+                                mode = EnumMode::Recursive;
+                            }
+                            EnumMode::Recursive => {
+                                errors.push_warning(meta.error(
+                                    "#[daft(recursive)] specified multiple \
+                                     times",
+                                ));
+                            }
+                        }
+                    } else if meta.path.is_ident("leaf") {
+                        errors.push_critical(meta.error(
+                            "this is unnecessary: the Diffable \
+                             implementation for enums is a leaf by default",
+                        ));
+                    } else if meta.path.is_ident("crate") {
+                        return parse_crate_path(
+                            &meta,
+                            &mut daft_crate,
+                            &errors,
+                        );
+                    } else if !diff_type.parse_meta(&meta, &errors)? {
+                        errors.push_critical(meta.error(
+                            "unknown attribute \
+                             (supported attributes: recursive, crate, name, \
+                             vis, derive, attr)",
+                        ));
+                    }
+
+                    Ok(())
+                });
+
+                if let Err(err) = res {
+                    errors.push_critical(err);
+                }
+            }
+        }
+
+        if let (EnumMode::Leaf, Some((span, option))) = (mode, diff_type.first)
+        {
+            errors.push_critical(syn::Error::new(
+                span,
+                format!("#[daft({option})] requires #[daft(recursive)]"),
+            ));
+        }
+
+        Self {
+            mode,
+            daft_crate: daft_crate.unwrap_or_else(default_daft_crate),
+            diff_type,
+        }
+    }
+}
+
+/// Options for the generated diff type, accepted by structs and by enums
+/// annotated with `#[daft(recursive)]`.
+#[derive(Default)]
+struct DiffTypeConfig {
+    // The first option specified, used to report errors for leaf diffs, which
+    // don't have a generated type.
+    first: Option<(Span, &'static str)>,
+    // The name of the diff type, if specified with #[daft(name = "...")].
+    name: Option<Ident>,
+    // The visibility of the diff type, if specified with #[daft(vis = "...")].
+    vis: Option<Visibility>,
+    // Traits to derive for the diff type, from #[daft(derive(...))].
+    derives: Vec<Path>,
+    // Attributes for the diff type, from #[daft(attr(...))].
+    attrs: Vec<Meta>,
+}
+
+impl DiffTypeConfig {
+    /// Parses `meta` if it's an option for the diff type, returning false if
+    /// it isn't one.
+    fn parse_meta(
+        &mut self,
+        meta: &ParseNestedMeta<'_>,
+        errors: &ErrorSink<'_, syn::Error>,
+    ) -> syn::Result<bool> {
+        let option = if meta.path.is_ident("name") {
+            // #[daft(name = "Ident")]
+            let value: LitStr = meta.value()?.parse()?;
+            match value.parse::<Ident>() {
+                Ok(_) if self.name.is_some() => {
+                    errors.push_critical(
+                        meta.error("#[daft(name)] specified multiple times"),
+                    );
+                }
+                Ok(name) => self.name = Some(name),
+                Err(_) => errors.push_critical(syn::Error::new_spanned(
+                    &value,
+                    "expected an identifier",
+                )),
+            }
+            "name"
+        } else if meta.path.is_ident("vis") {
+            // #[daft(vis = "pub(crate)")]
+            let value: LitStr = meta.value()?.parse()?;
+            match value.parse::<Visibility>() {
+                Ok(_) if self.vis.is_some() => {
+                    errors.push_critical(
+                        meta.error("#[daft(vis)] specified multiple times"),
+                    );
+                }
+                Ok(vis) => self.vis = Some(vis),
+                Err(_) => errors.push_critical(syn::Error::new_spanned(
+                    &value,
+                    "expected a visibility, e.g. \"pub(crate)\"",
+                )),
+            }
+            "vis"
+        } else if meta.path.is_ident("derive") {
+            // #[daft(derive(Trait, ...))]
+            let content;
+            parenthesized!(content in meta.input);
+            let derives =
+                content.parse_terminated(Path::parse_mod_style, Token![,])?;
+            for derive in derives {
+                // These traits are implemented by hand for the diff type, so
+                // deriving them would result in conflicting implementations.
+                let name = derive.segments.last().map(|s| s.ident.to_string());
+                if let Some(
+                    name @ ("Debug" | "PartialEq" | "Eq" | "Serialize"),
+                ) = name.as_deref()
+                {
+                    errors.push_critical(syn::Error::new_spanned(
+                        &derive,
+                        format!(
+                            "#[daft(derive({name}))] conflicts with the \
+                             {name} implementation generated for the diff \
+                             type"
+                        ),
+                    ));
+                } else {
+                    self.derives.push(derive);
+                }
+            }
+            "derive"
+        } else if meta.path.is_ident("attr") {
+            // #[daft(attr(meta, ...))]
+            let content;
+            parenthesized!(content in meta.input);
+            self.attrs.extend(
+                content.parse_terminated(<Meta as Parse>::parse, Token![,])?,
+            );
+            "attr"
+        } else {
+            return Ok(false);
+        };
+
+        self.first.get_or_insert((meta.path.span(), option));
+        Ok(true)
+    }
+
+    /// Returns the name of the diff type.
+    fn name(&self, input: &DeriveInput) -> Ident {
+        self.name.clone().unwrap_or_else(|| {
+            format_ident!("{}Diff", input.ident, span = Span::call_site())
+        })
+    }
+
+    /// Returns the name of the owned diff struct, for structs annotated with
+    /// `#[daft(owned)]`.
+    fn owned_name(&self, input: &DeriveInput) -> Ident {
+        format_ident!("{}Owned", self.name(input), span = Span::call_site())
+    }
+
+    /// Returns the visibility of the diff type, which is the same as the
+    /// original type's by default.
+    fn vis<'a>(&'a self, input: &'a DeriveInput) -> &'a Visibility {
+        self.vis.as_ref().unwrap_or(&input.vis)
+    }
+
+    /// Returns the derives and attributes to add to the diff type.
+    fn attrs(&self) -> TokenStream {
+        let derives = &self.derives;
+        let derive = (!derives.is_empty()).then(|| {
+            quote! { #[derive(#(#derives),*)] }
+        });
+        let attrs = &self.attrs;
+        quote! {
+            #derive
+            #(#[#attrs])*
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EnumMode {
+    // The default mode: use a `Leaf` for this enum.
+    Leaf,
+    // Generate a diff enum with a recursive diff for each variant.
+    Recursive,
+}
+
 struct FieldConfig {
     mode: FieldMode,
+    // The tolerance used to check whether the field is unchanged, if
+    // specified with #[daft(tolerance = ...)].
+    tolerance: Option<Expr>,
 }
 
 impl FieldConfig {
@@ -769,6 +2597,17 @@ impl FieldConfig {
         errors: ErrorSink<'_, syn::Error>,
     ) -> Option<Self> {
         let mut mode = FieldMode::Default;
+        // #[daft(keyed_by = ...)] and #[daft(key = ...)] must be specified
+        // together, so they're collected separately and turned into a mode
+        // at the end.
+        let mut key_fn: Option<(Span, Path)> = None;
+        let mut key_ty: Option<(Span, Type)> = None;
+        // Similarly, #[daft(diff_fn = ...)] and #[daft(diff_ty = ...)] must be
+        // specified together, and #[daft(with = "...")] is shorthand for both.
+        let mut with: Option<(Span, Path)> = None;
+        let mut diff_fn: Option<(Span, Path)> = None;
+        let mut diff_ty: Option<(Span, Type)> = None;
+        let mut tolerance: Option<(Span, Expr)> = None;
 
         for attr in attrs {
             if attr.path().is_ident("daft") {
@@ -809,10 +2648,131 @@ impl FieldConfig {
                                 ));
                             }
                         }
+                    } else if meta.path.is_ident("text") {
+                        // #[daft(text)]
+                        match mode {
+                            FieldMode::Default => {
+                                mode = FieldMode::Text;
+                            }
+                            FieldMode::Text => {
+                                errors.push_warning(meta.error(
+                                    "#[daft(text)] specified multiple times",
+                                ));
+                            }
+                            _ => {
+                                errors.push_critical(meta.error(
+                                    "#[daft(text)] conflicts with \
+                                     other attributes",
+                                ));
+                            }
+                        }
+                    } else if meta.path.is_ident("map_values") {
+                        // #[daft(map_values = "recursive")]
+                        let value: LitStr = meta.value()?.parse()?;
+                        if value.value() != "recursive" {
+                            errors.push_critical(syn::Error::new_spanned(
+                                &value,
+                                "unknown value for #[daft(map_values)] \
+                                 (supported values: \"recursive\")",
+                            ));
+                            return Ok(());
+                        }
+                        match mode {
+                            FieldMode::Default => {
+                                mode = FieldMode::RecursiveMap;
+                            }
+                            FieldMode::RecursiveMap => {
+                                errors.push_warning(meta.error(
+                                    "#[daft(map_values)] specified multiple \
+                                     times",
+                                ));
+                            }
+                            _ => {
+                                errors.push_critical(meta.error(
+                                    "#[daft(map_values)] conflicts with \
+                                     other attributes",
+                                ));
+                            }
+                        }
+                    } else if meta.path.is_ident("keyed_by") {
+                        // #[daft(keyed_by = path::to::fn)]
+                        let span = meta.path.span();
+                        let path: Path = meta.value()?.parse()?;
+                        if key_fn.is_some() {
+                            errors.push_critical(meta.error(
+                                "#[daft(keyed_by)] specified multiple times",
+                            ));
+                        } else {
+                            key_fn = Some((span, path));
+                        }
+                    } else if meta.path.is_ident("key") {
+                        // #[daft(key = Type)]
+                        let span = meta.path.span();
+                        let ty: Type = meta.value()?.parse()?;
+                        if key_ty.is_some() {
+                            errors.push_critical(meta.error(
+                                "#[daft(key)] specified multiple times",
+                            ));
+                        } else {
+                            key_ty = Some((span, ty));
+                        }
+                    } else if meta.path.is_ident("with") {
+                        // #[daft(with = "path::to::module")]
+                        let span = meta.path.span();
+                        let value: LitStr = meta.value()?.parse()?;
+                        let Ok(module) = value.parse::<Path>() else {
+                            errors.push_critical(syn::Error::new_spanned(
+                                &value,
+                                "expected a path to a module",
+                            ));
+                            return Ok(());
+                        };
+                        if with.is_some() {
+                            errors.push_critical(meta.error(
+                                "#[daft(with)] specified multiple times",
+                            ));
+                        } else {
+                            with = Some((span, module));
+                        }
+                    } else if meta.path.is_ident("diff_fn") {
+                        // #[daft(diff_fn = path::to::fn)]
+                        let span = meta.path.span();
+                        let path: Path = meta.value()?.parse()?;
+                        if diff_fn.is_some() {
+                            errors.push_critical(meta.error(
+                                "#[daft(diff_fn)] specified multiple times",
+                            ));
+                        } else {
+                            diff_fn = Some((span, path));
+                        }
+                    } else if meta.path.is_ident("diff_ty") {
+                        // #[daft(diff_ty = Type)]
+                        let span = meta.path.span();
+                        let ty: Type = meta.value()?.parse()?;
+                        if diff_ty.is_some() {
+                            errors.push_critical(meta.error(
+                                "#[daft(diff_ty)] specified multiple times",
+                            ));
+                        } else {
+                            diff_ty = Some((span, ty));
+                        }
+                    } else if meta.path.is_ident("tolerance") {
+                        // #[daft(tolerance = expr)]
+                        let span = meta.path.span();
+                        let expr: Expr = meta.value()?.parse()?;
+                        if tolerance.is_some() {
+                            errors.push_critical(meta.error(
+                                "#[daft(tolerance)] specified multiple times",
+                            ));
+                        } else {
+                            tolerance = Some((span, expr));
+                        }
                     } else {
                         errors.push_critical(meta.error(
                             "unknown attribute \
-                             (supported attributes: leaf, ignore)",
+                             (supported attributes: leaf, ignore, text, \
+                             map_values, keyed_by, key, with, diff_fn, \
+                             diff_ty, tolerance)",
                         ));
                     }
 
@@ -825,11 +2785,113 @@ impl FieldConfig {
             }
         }
 
-        if errors.has_critical_errors() { None } else { Some(Self { mode }) }
+        match (key_fn, key_ty) {
+            (None, None) => {}
+            (Some((span, key_fn)), Some((_, key_ty))) => {
+                if matches!(mode, FieldMode::Default) {
+                    mode = FieldMode::Keyed(Box::new(KeyedConfig {
+                        key_fn,
+                        key_ty,
+                    }));
+                } else {
+                    errors.push_critical(syn::Error::new(
+                        span,
+                        "#[daft(keyed_by)] conflicts with other attributes",
+                    ));
+                }
+            }
+            (Some((span, _)), None) => {
+                errors.push_critical(syn::Error::new(
+                    span,
+                    "#[daft(keyed_by)] requires the key type to be \
+                     specified with #[daft(key = Type)]",
+                ));
+            }
+            (None, Some((span, _))) => {
+                errors.push_critical(syn::Error::new(
+                    span,
+                    "#[daft(key)] can only be used along with \
+                     #[daft(keyed_by = path)]",
+                ));
+            }
+        }
+
+        // #[daft(with = "module")] expands to #[daft(diff_fn = module::diff,
+        // diff_ty = module::Diff<'_>)].
+        let custom = match (with, diff_fn, diff_ty) {
+            (None, None, None) => None,
+            (Some((span, module)), None, None) => Some((
+                span,
+                "with",
+                CustomConfig {
+                    diff_fn: parse_quote! { #module::diff },
+                    diff_ty: parse_quote! { #module::Diff<'_> },
+                },
+            )),
+            (Some((span, _)), _, _) => {
+                errors.push_critical(syn::Error::new(
+                    span,
+                    "#[daft(with)] conflicts with other attributes",
+                ));
+                None
+            }
+            (None, Some((span, diff_fn)), Some((_, diff_ty))) => {
+                Some((span, "diff_fn", CustomConfig { diff_fn, diff_ty }))
+            }
+            (None, Some((span, _)), None) => {
+                errors.push_critical(syn::Error::new(
+                    span,
+                    "#[daft(diff_fn)] requires the diff type to be \
+                     specified with #[daft(diff_ty = Type)]",
+                ));
+                None
+            }
+            (None, None, Some((span, _))) => {
+                errors.push_critical(syn::Error::new(
+                    span,
+                    "#[daft(diff_ty)] can only be used along with \
+                     #[daft(diff_fn = path)]",
+                ));
+                None
+            }
+        };
+        if let Some((span, name, custom)) = custom {
+            if matches!(mode, FieldMode::Default) {
+                mode = FieldMode::Custom(Box::new(custom));
+            } else {
+                errors.push_critical(syn::Error::new(
+                    span,
+                    format!("#[daft({name})] conflicts with other attributes"),
+                ));
+            }
+        }
+
+        // Tolerances only apply to fields whose diffs are leaves of floats.
+        if let Some((span, _)) = &tolerance {
+            if matches!(
+                mode,
+                FieldMode::Ignore
+                    | FieldMode::Text
+                    | FieldMode::RecursiveMap
+                    | FieldMode::Keyed(_)
+                    | FieldMode::Custom(_)
+            ) {
+                errors.push_critical(syn::Error::new(
+                    *span,
+                    "#[daft(tolerance)] conflicts with other attributes",
+                ));
+            }
+        }
+        let tolerance = tolerance.map(|(_, expr)| expr);
+
+        if errors.has_critical_errors() {
+            None
+        } else {
+            Some(Self { mode, tolerance })
+        }
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum FieldMode {
     // The default mode: do a recursive diff for this field.
     Default,
@@ -837,4 +2899,28 @@ enum FieldMode {
     Leaf,
     // Ignore this field.
     Ignore,
+    // Diff this field line by line as text.
+    Text,
+    // Diff this map field with the values of common entries diffed
+    // recursively.
+    RecursiveMap,
+    // Diff this field as a map from an extracted key to elements.
+    Keyed(Box<KeyedConfig>),
+    // Diff this field with a user-supplied function.
+    Custom(Box<CustomConfig>),
+}
+
+struct KeyedConfig {
+    // The function used to extract the key from each element.
+    key_fn: Path,
+    // The type of the key.
+    key_ty: Type,
+}
+
+struct CustomConfig {
+    // The function used to diff the field.
+    diff_fn: Path,
+    // The type returned by `diff_fn`. Elided lifetimes, including `'_`, refer
+    // to the lifetime of the values being diffed.
+    diff_ty: Type,
 }
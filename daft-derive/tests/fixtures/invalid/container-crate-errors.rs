@@ -0,0 +1,46 @@
+use daft::Diffable;
+
+// Not a string.
+#[derive(Diffable)]
+#[daft(crate = daft)]
+struct MyStruct {
+    a: i32,
+}
+
+// Not a path.
+#[derive(Diffable)]
+#[daft(crate = "not a path")]
+struct MyStruct2 {
+    a: i32,
+}
+
+// Specified multiple times.
+#[derive(Diffable)]
+#[daft(crate = "::daft", crate = "::daft")]
+struct MyStruct3 {
+    a: i32,
+}
+
+#[derive(Diffable)]
+#[daft(crate = "::daft")]
+#[daft(crate = "::daft")]
+enum MyEnum {
+    A(i32),
+}
+
+#[derive(Diffable)]
+#[daft(crate = "not a path")]
+union MyUnion {
+    a: i32,
+    b: u32,
+}
+
+fn main() {
+    // The types should still exist, even though the Diffable impls couldn't be
+    // generated.
+    let _ = MyStruct { a: 0 };
+    let _ = MyStruct2 { a: 0 };
+    let _ = MyStruct3 { a: 0 };
+    let _ = MyEnum::A(0);
+    let _ = MyUnion { a: 0 };
+}
@@ -0,0 +1,54 @@
+use daft::Diffable;
+
+// Not an identifier.
+#[derive(Diffable)]
+#[daft(name = "not an identifier")]
+struct MyStruct {
+    a: i32,
+}
+
+// Not a visibility.
+#[derive(Diffable)]
+#[daft(vis = "public")]
+struct MyStruct2 {
+    a: i32,
+}
+
+// Specified multiple times.
+#[derive(Diffable)]
+#[daft(name = "Changes", name = "Changes")]
+struct MyStruct3 {
+    a: i32,
+}
+
+// Along with leaf.
+#[derive(Diffable)]
+#[daft(leaf, derive(Clone))]
+struct MyStruct4 {
+    a: i32,
+}
+
+// On an enum that isn't recursive.
+#[derive(Diffable)]
+#[daft(name = "MyEnumChanges")]
+enum MyEnum {
+    A(i32),
+}
+
+// Derives for traits that are always implemented.
+#[derive(Diffable)]
+#[daft(derive(Clone, Debug, std::cmp::PartialEq))]
+struct MyStruct5 {
+    a: i32,
+}
+
+fn main() {
+    // The types should still exist, even though the Diffable impls couldn't be
+    // generated.
+    let _ = MyStruct { a: 0 };
+    let _ = MyStruct2 { a: 0 };
+    let _ = MyStruct3 { a: 0 };
+    let _ = MyStruct4 { a: 0 };
+    let _ = MyStruct5 { a: 0 };
+    let _ = MyEnum::A(0);
+}
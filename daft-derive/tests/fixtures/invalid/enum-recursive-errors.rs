@@ -0,0 +1,27 @@
+use daft::Diffable;
+
+#[derive(Diffable)]
+#[daft(recursive, leaf, foo)]
+enum MyEnum {
+    #[daft(leaf)]
+    A(#[daft(foo)] i32),
+    VariantChanged {
+        #[daft(leaf, ignore)]
+        data: usize,
+    },
+}
+
+#[derive(Diffable)]
+#[daft(recursive)]
+#[daft(recursive)]
+enum MyEnum2 {
+    A(i32),
+}
+
+fn main() {
+    // MyEnum2 and its Diffable impl should exist.
+    let before = MyEnum2::A(0);
+    let after = MyEnum2::A(1);
+
+    let _diff: MyEnum2Diff<'_> = before.diff(&after);
+}
@@ -0,0 +1,44 @@
+use daft::Diffable;
+
+fn diff_u32(before: &u32, after: &u32) -> bool {
+    before != after
+}
+
+mod bool_diff {
+    pub type Diff<'daft> = bool;
+
+    pub fn diff(before: &u32, after: &u32) -> Diff<'static> {
+        before != after
+    }
+}
+
+#[derive(Diffable)]
+struct MyStruct {
+    // diff_fn without a diff type.
+    #[daft(diff_fn = diff_u32)]
+    a: u32,
+    // A diff type without diff_fn.
+    #[daft(diff_ty = bool)]
+    b: u32,
+    // with along with diff_fn.
+    #[daft(with = "bool_diff", diff_fn = diff_u32, diff_ty = bool)]
+    c: u32,
+    // diff_fn along with leaf.
+    #[daft(leaf, diff_fn = diff_u32, diff_ty = bool)]
+    d: u32,
+    // with along with tolerance.
+    #[daft(with = "bool_diff", tolerance = 1e-6)]
+    e: u32,
+    // with that isn't a path.
+    #[daft(with = "not a path")]
+    f: u32,
+    // with specified multiple times.
+    #[daft(with = "bool_diff", with = "bool_diff")]
+    g: u32,
+}
+
+fn main() {
+    // MyStruct should still exist, even though the Diffable impl couldn't be
+    // generated.
+    let _ = MyStruct { a: 0, b: 0, c: 0, d: 0, e: 0, f: 0, g: 0 };
+}
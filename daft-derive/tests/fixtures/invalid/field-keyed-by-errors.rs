@@ -0,0 +1,24 @@
+use daft::Diffable;
+
+fn id(x: &(u32, u32)) -> &u32 {
+    &x.0
+}
+
+#[derive(Diffable)]
+struct MyStruct {
+    // keyed_by without a key type.
+    #[daft(keyed_by = id)]
+    a: Vec<(u32, u32)>,
+    // A key type without keyed_by.
+    #[daft(key = u32)]
+    b: Vec<(u32, u32)>,
+    // keyed_by along with leaf.
+    #[daft(leaf, keyed_by = id, key = u32)]
+    c: Vec<(u32, u32)>,
+}
+
+fn main() {
+    // MyStruct should still exist, even though the Diffable impl couldn't be
+    // generated.
+    let _ = MyStruct { a: vec![], b: vec![], c: vec![] };
+}
@@ -0,0 +1,29 @@
+use daft::Diffable;
+use std::collections::BTreeMap;
+
+#[derive(Diffable)]
+struct MyStruct {
+    // Unknown value.
+    #[daft(map_values = "lazy")]
+    a: BTreeMap<u32, u32>,
+    // map_values along with leaf.
+    #[daft(map_values = "recursive", leaf)]
+    b: BTreeMap<u32, u32>,
+    // map_values along with tolerance.
+    #[daft(map_values = "recursive", tolerance = 1e-6)]
+    c: BTreeMap<u32, u32>,
+    // map_values without a value.
+    #[daft(map_values)]
+    d: BTreeMap<u32, u32>,
+}
+
+fn main() {
+    // MyStruct should still exist, even though the Diffable impl couldn't be
+    // generated.
+    let _ = MyStruct {
+        a: BTreeMap::new(),
+        b: BTreeMap::new(),
+        c: BTreeMap::new(),
+        d: BTreeMap::new(),
+    };
+}
@@ -0,0 +1,20 @@
+use daft::Diffable;
+
+#[derive(Diffable)]
+struct MyStruct {
+    // text along with leaf.
+    #[daft(text, leaf)]
+    a: String,
+    // text along with tolerance.
+    #[daft(text, tolerance = 1e-6)]
+    b: String,
+    // text along with keyed_by.
+    #[daft(text, keyed_by = str::len, key = usize)]
+    c: String,
+}
+
+fn main() {
+    // MyStruct should still exist, even though the Diffable impl couldn't be
+    // generated.
+    let _ = MyStruct { a: String::new(), b: String::new(), c: String::new() };
+}
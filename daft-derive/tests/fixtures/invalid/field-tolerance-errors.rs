@@ -0,0 +1,20 @@
+use daft::Diffable;
+
+#[derive(Diffable)]
+struct MyStruct {
+    // tolerance along with ignore.
+    #[daft(ignore, tolerance = 1e-6)]
+    a: f64,
+    // tolerance specified twice.
+    #[daft(tolerance = 1e-6, tolerance = 1e-3)]
+    b: f64,
+    // tolerance without a value.
+    #[daft(tolerance)]
+    c: f64,
+}
+
+fn main() {
+    // MyStruct should still exist, even though the Diffable impl couldn't be
+    // generated.
+    let _ = MyStruct { a: 0.0, b: 0.0, c: 0.0 };
+}
@@ -0,0 +1,126 @@
+enum MyEnum2Diff<'__daft> {
+    A(<i32 as ::daft::Diffable>::Diff<'__daft>),
+    VariantChanged(::daft::Leaf<&'__daft MyEnum2>),
+}
+impl<'__daft> ::core::fmt::Debug for MyEnum2Diff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    ::daft::Leaf<&'__daft MyEnum2>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::A { 0: __field_0 } => {
+                f.debug_tuple(stringify!(A)).field(__field_0).finish()
+            }
+            Self::VariantChanged(leaf) => {
+                f.debug_tuple(stringify!(VariantChanged)).field(leaf).finish()
+            }
+        }
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for MyEnum2Diff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    ::daft::Leaf<&'__daft MyEnum2>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::A { 0: __self_0 }, Self::A { 0: __other_0 }) => __self_0 == __other_0,
+            (Self::VariantChanged(self_leaf), Self::VariantChanged(other_leaf)) => {
+                self_leaf == other_leaf
+            }
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+impl<'__daft> ::core::cmp::Eq for MyEnum2Diff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    ::daft::Leaf<&'__daft MyEnum2>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for MyEnum2Diff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        match self {
+            Self::A { 0: __field_0 } => ::daft::DiffSummary::is_unchanged(__field_0),
+            Self::VariantChanged(_) => false,
+        }
+    }
+}
+impl<'__daft> ::daft::VisitDiff for MyEnum2Diff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    &'__daft MyEnum2: ::core::fmt::Debug,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        match self {
+            Self::A { 0: __field_0 } => {
+                let path = path.join(::daft::PathSegment::Variant("A"));
+                ::daft::VisitDiff::visit_at(
+                    __field_0,
+                    &path.join(::daft::PathSegment::TupleIndex(0)),
+                    visitor,
+                );
+            }
+            Self::VariantChanged(leaf) => {
+                ::daft::DiffVisitor::modified(visitor, path, &leaf.before, &leaf.after);
+            }
+        }
+    }
+}
+impl<'__daft> ::daft::Invert for MyEnum2Diff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    ::daft::Leaf<&'__daft MyEnum2>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        match self {
+            Self::A { 0: __field_0 } => {
+                Self::A {
+                    0: ::daft::Invert::invert(__field_0),
+                }
+            }
+            Self::VariantChanged(leaf) => {
+                Self::VariantChanged(::daft::Invert::invert(leaf))
+            }
+        }
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for MyEnum2Diff < '__daft >
+    where < i32 as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, ::daft::Leaf < & '__daft MyEnum2 > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { match self { Self::A { 0 : __field_0 } =>
+    ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(MyEnum2Diff), 0, "A", __field_0,), Self::VariantChanged(leaf) => {
+    ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(MyEnum2Diff), 1, stringify!(VariantChanged), leaf,) } } } }
+}
+impl ::daft::Diffable for MyEnum2 {
+    type Diff<'__daft> = MyEnum2Diff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> MyEnum2Diff<'__daft> {
+        match (self, other) {
+            (Self::A { 0: __daft_before_0, .. }, Self::A { 0: __daft_after_0, .. }) => {
+                MyEnum2Diff::A {
+                    0: ::daft::Diffable::diff(__daft_before_0, __daft_after_0),
+                }
+            }
+            #[allow(unreachable_patterns)]
+            _ => {
+                MyEnum2Diff::VariantChanged(::daft::Leaf {
+                    before: self,
+                    after: other,
+                })
+            }
+        }
+    }
+}
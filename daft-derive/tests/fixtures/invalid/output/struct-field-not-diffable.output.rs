@@ -28,6 +28,106 @@ where
     <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
     <NonDiffable as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
 {}
+impl<'__daft> ::daft::DiffSummary for MyStructDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <NonDiffable as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.a)
+            && ::daft::DiffSummary::is_unchanged(&self.b)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for MyStructDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <NonDiffable as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.a,
+            &path.join(::daft::PathSegment::Field("a")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.b,
+            &path.join(::daft::PathSegment::Field("b")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for MyStructDiff < '__daft >
+    where < i32 as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, < NonDiffable as ::daft::Diffable > ::Diff <
+    '__daft > : ::daft::__private::serde::Serialize { fn serialize < __S > (& self,
+    __serializer : __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(MyStructDiff), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "a", &
+    self.a,) ?; ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "b", & self.b,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for MyStructDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <NonDiffable as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            a: ::daft::Invert::invert(self.a),
+            b: ::daft::Invert::invert(self.b),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<MyStruct> for MyStructDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<i32>,
+    <NonDiffable as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<NonDiffable>,
+{
+    fn apply(
+        &self,
+        target: &mut MyStruct,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.a, &mut target.a)?;
+        ::daft::Patch::apply(&self.b, &mut target.b)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<MyStruct> for MyStructDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<i32>,
+    <NonDiffable as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<NonDiffable>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut MyStruct,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.a,
+            &theirs.a,
+            &mut target.a,
+            &path.join(::daft::PathSegment::Field("a")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.b,
+            &theirs.b,
+            &mut target.b,
+            &path.join(::daft::PathSegment::Field("b")),
+            conflict,
+        );
+    }
+}
 impl ::daft::Diffable for MyStruct {
     type Diff<'__daft> = MyStructDiff<'__daft> where Self: '__daft;
     fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> MyStructDiff<'__daft> {
@@ -0,0 +1,196 @@
+struct OwnedTwiceDiff<'__daft> {
+    a: <i32 as ::daft::Diffable>::Diff<'__daft>,
+}
+impl<'__daft> ::core::fmt::Debug for OwnedTwiceDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(OwnedTwiceDiff)).field(stringify!(a), &self.a).finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for OwnedTwiceDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a
+    }
+}
+impl<'__daft> ::core::cmp::Eq for OwnedTwiceDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for OwnedTwiceDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.a)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for OwnedTwiceDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.a,
+            &path.join(::daft::PathSegment::Field("a")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for OwnedTwiceDiff < '__daft >
+    where < i32 as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(OwnedTwiceDiff), 1,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "a", &
+    self.a,) ?; ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for OwnedTwiceDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            a: ::daft::Invert::invert(self.a),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<OwnedTwice> for OwnedTwiceDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<i32>,
+{
+    fn apply(
+        &self,
+        target: &mut OwnedTwice,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.a, &mut target.a)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<OwnedTwice> for OwnedTwiceDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<i32>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut OwnedTwice,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.a,
+            &theirs.a,
+            &mut target.a,
+            &path.join(::daft::PathSegment::Field("a")),
+            conflict,
+        );
+    }
+}
+impl ::daft::Diffable for OwnedTwice {
+    type Diff<'__daft> = OwnedTwiceDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> OwnedTwiceDiff<'__daft> {
+        Self::Diff {
+            a: ::daft::Diffable::diff(&self.a, &other.a),
+        }
+    }
+}
+struct OwnedTwiceDiffOwned {
+    a: <<i32 as ::daft::Diffable>::Diff<'static> as ::daft::IntoOwnedDiff>::Owned,
+}
+impl ::core::fmt::Debug for OwnedTwiceDiffOwned
+where
+    for<'__daft> <<i32 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(OwnedTwiceDiffOwned))
+            .field(stringify!(a), &self.a)
+            .finish()
+    }
+}
+impl ::core::clone::Clone for OwnedTwiceDiffOwned
+where
+    for<'__daft> <<i32 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            a: ::core::clone::Clone::clone(&self.a),
+        }
+    }
+}
+impl ::core::cmp::PartialEq for OwnedTwiceDiffOwned
+where
+    for<'__daft> <<i32 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a
+    }
+}
+impl ::core::cmp::Eq for OwnedTwiceDiffOwned
+where
+    for<'__daft> <<i32 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+{}
+::daft::__private::serde_impl! {
+    impl ::daft::__private::serde::Serialize for OwnedTwiceDiffOwned where for < '__daft
+    > < < i32 as ::daft::Diffable > ::Diff < '__daft > as ::daft::IntoOwnedDiff > ::Owned
+    : ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(OwnedTwiceDiffOwned), 1,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "a", &
+    self.a,) ?; ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl ::daft::Invert for OwnedTwiceDiffOwned
+where
+    for<'__daft> <<i32 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            a: ::daft::Invert::invert(self.a),
+        }
+    }
+}
+impl ::daft::Patch<OwnedTwice> for OwnedTwiceDiffOwned
+where
+    for<'__daft> <<i32 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<i32>,
+{
+    fn apply(
+        &self,
+        target: &mut OwnedTwice,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.a, &mut target.a)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::IntoOwnedDiff for OwnedTwiceDiff<'__daft> {
+    type Owned = OwnedTwiceDiffOwned;
+    fn into_owned(self) -> Self::Owned {
+        OwnedTwiceDiffOwned {
+            a: ::daft::IntoOwnedDiff::into_owned(self.a),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use daft::Diffable;
+
+// Leaf diffs are already convertible into owned diffs.
+#[derive(Diffable)]
+#[daft(leaf, owned)]
+struct LeafOwned {
+    a: i32,
+}
+
+// Owned diffs can't borrow from the original struct.
+#[derive(Diffable)]
+#[daft(owned)]
+struct WithLifetime<'a> {
+    a: &'a str,
+}
+
+#[derive(Diffable)]
+#[daft(owned, owned)]
+struct OwnedTwice {
+    a: i32,
+}
+
+// Fields whose types are type parameters must be leaves.
+#[derive(Diffable)]
+#[daft(owned)]
+struct Generic<T: Diffable> {
+    a: T,
+    #[daft(leaf)]
+    b: T,
+}
+
+fn main() {
+    // The structs should still exist, even though the Diffable impls couldn't
+    // be generated.
+    let _ = LeafOwned { a: 0 };
+    let _ = WithLifetime { a: "foo" };
+    let _ = OwnedTwice { a: 0 };
+    let _ = Generic { a: 0, b: 0 };
+}
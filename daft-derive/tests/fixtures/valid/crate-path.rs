@@ -0,0 +1,61 @@
+// The daft crate might only be reachable through another crate that
+// re-exports it.
+mod facade {
+    pub use daft;
+}
+
+use facade::daft::{Diffable, DiffSummary, Leaf};
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+#[daft(crate = "crate::facade::daft", owned)]
+struct Config {
+    threads: usize,
+    name: String,
+}
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+#[daft(leaf, crate = "crate::facade::daft")]
+struct Token(u64);
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+#[daft(crate = "crate::facade::daft", recursive)]
+enum State {
+    Idle,
+    Running { config: Config },
+}
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+#[daft(crate = "crate::facade::daft")]
+enum Mode {
+    Fast,
+    Slow,
+}
+
+#[derive(Diffable)]
+#[daft(crate = "crate::facade::daft")]
+union Bits {
+    a: u32,
+    b: i32,
+}
+
+fn main() {
+    let before = Config { threads: 1, name: "a".to_owned() };
+    let after = Config { threads: 2, name: "a".to_owned() };
+    let diff = before.diff(&after);
+    assert_eq!(diff.threads, Leaf { before: &1, after: &2 });
+    assert!(diff.name.is_unchanged());
+
+    let diff: Leaf<&Token> = Token(1).diff(&Token(2));
+    assert!(diff.is_modified());
+
+    let before = State::Running { config: before };
+    let after = State::Running { config: after };
+    assert!(before.diff(&after).is_modified());
+    assert!(State::Idle.diff(&State::Idle).is_unchanged());
+
+    let diff: Leaf<&Mode> = Mode::Fast.diff(&Mode::Slow);
+    assert!(diff.is_modified());
+
+    let diff: Leaf<&Bits> = Bits { a: 1 }.diff(&Bits { b: 2 });
+    assert_eq!(unsafe { diff.before.a }, 1);
+}
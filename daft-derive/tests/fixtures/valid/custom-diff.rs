@@ -0,0 +1,84 @@
+use daft::{DiffSummary, Diffable, Leaf};
+
+// A type from another crate that doesn't implement Diffable.
+#[derive(Debug, Eq, PartialEq)]
+struct Foreign(u32);
+
+fn foreign_diff(before: &Foreign, after: &Foreign) -> i64 {
+    i64::from(after.0) - i64::from(before.0)
+}
+
+// A diff type that borrows from the values being diffed.
+fn common_prefix<'a>(before: &'a [u32], after: &'a [u32]) -> &'a [u32] {
+    let len = before.iter().zip(after).take_while(|(a, b)| a == b).count();
+    &before[..len]
+}
+
+// A diff type that doesn't implement Eq.
+fn float_delta(before: &f64, after: &f64) -> Option<f64> {
+    (before != after).then(|| after - before)
+}
+
+mod leaf_diff {
+    use daft::Leaf;
+
+    pub type Diff<'daft> = Leaf<&'daft str>;
+
+    pub fn diff<'daft>(before: &'daft String, after: &'daft String) -> Diff<'daft> {
+        Leaf { before, after }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+struct Sled {
+    #[daft(diff_fn = foreign_diff, diff_ty = i64)]
+    foreign: Foreign,
+    // Elided lifetimes refer to the lifetime of the values being diffed.
+    #[daft(diff_fn = common_prefix, diff_ty = &[u32])]
+    zpools: Vec<u32>,
+    #[daft(with = "leaf_diff")]
+    name: String,
+}
+
+#[derive(Debug, Diffable)]
+struct Measurement {
+    #[daft(diff_fn = float_delta, diff_ty = Option<f64>)]
+    value: f64,
+}
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+#[daft(recursive)]
+enum State {
+    Running(#[daft(with = "leaf_diff")] String),
+    Stopped,
+}
+
+fn main() {
+    let before = Sled {
+        foreign: Foreign(1),
+        zpools: vec![1, 2, 3],
+        name: "a".to_owned(),
+    };
+    let after = Sled {
+        foreign: Foreign(3),
+        zpools: vec![1, 2, 4],
+        name: "b".to_owned(),
+    };
+    let diff = before.diff(&after);
+    assert_eq!(
+        diff,
+        SledDiff {
+            foreign: 2,
+            zpools: &[1, 2],
+            name: Leaf { before: "a", after: "b" },
+        }
+    );
+
+    let before = Measurement { value: 1.0 };
+    let after = Measurement { value: 1.5 };
+    assert_eq!(before.diff(&after).value, Some(0.5));
+
+    let before = State::Running("a".to_owned());
+    let after = State::Running("a".to_owned());
+    assert!(before.diff(&after).is_unchanged());
+}
@@ -0,0 +1,41 @@
+use daft::{Diffable, IntoOwnedDiff, Leaf};
+
+// An existing type whose name would otherwise collide with the generated
+// diff struct.
+#[expect(dead_code)]
+struct SledConfigDiff;
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+#[daft(name = "SledConfigChanges", owned)]
+// The diff struct can be more visible than the original struct, but not less.
+#[daft(vis = "pub(crate)", derive(Clone, Copy))]
+#[daft(attr(doc = "Changes to a sled's configuration."), attr(must_use))]
+struct SledConfig {
+    generation: u64,
+    #[daft(leaf)]
+    serial: &'static str,
+}
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+#[daft(recursive, name = "PhaseChanges", derive(Clone))]
+enum Phase {
+    Starting { attempt: u32 },
+    Running,
+}
+
+fn main() {
+    let before = SledConfig { generation: 1, serial: "a" };
+    let after = SledConfig { generation: 2, serial: "a" };
+
+    let diff: SledConfigChanges<'_> = before.diff(&after);
+    let copy = diff;
+    assert_eq!(copy.generation, Leaf { before: &1, after: &2 });
+
+    let owned: SledConfigChangesOwned = diff.into_owned();
+    assert_eq!(owned.serial, Leaf { before: "a", after: "a" });
+
+    let before = Phase::Starting { attempt: 1 };
+    let after = Phase::Running;
+    let diff: PhaseChanges<'_> = before.diff(&after);
+    assert!(matches!(diff.clone(), PhaseChanges::VariantChanged(_)));
+}
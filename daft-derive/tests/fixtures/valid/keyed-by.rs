@@ -0,0 +1,36 @@
+use daft::{Diffable, KeyedDiff};
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+struct Zpool {
+    id: u64,
+    size: u64,
+}
+
+fn zpool_id(zpool: &Zpool) -> &u64 {
+    &zpool.id
+}
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+struct Sled {
+    #[daft(keyed_by = zpool_id, key = u64)]
+    zpools: Vec<Zpool>,
+    // A path to a function in another module, with the attributes specified
+    // separately.
+    #[daft(keyed_by = keys::name)]
+    #[daft(key = String)]
+    names: Vec<(String, u32)>,
+}
+
+mod keys {
+    pub(crate) fn name(entry: &(String, u32)) -> &String {
+        &entry.0
+    }
+}
+
+fn main() {
+    let before = Sled { zpools: vec![], names: vec![] };
+    let after = Sled { zpools: vec![], names: vec![("a".to_owned(), 1)] };
+    let diff = before.diff(&after);
+    let _: &KeyedDiff<'_, u64, Zpool> = &diff.zpools;
+    let _: &KeyedDiff<'_, String, (String, u32)> = &diff.names;
+}
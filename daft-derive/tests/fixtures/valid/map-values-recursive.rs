@@ -0,0 +1,57 @@
+use daft::{
+    BTreeMapRecursiveDiff, DiffSummary, Diffable, IntoOwnedDiff, Leaf, Merge,
+    Patch,
+};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Clone, Debug, PartialEq, Eq, Diffable)]
+#[daft(owned)]
+struct Sled {
+    serial: String,
+    generation: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Diffable)]
+#[daft(owned)]
+struct Inventory {
+    #[daft(map_values = "recursive")]
+    sleds: BTreeMap<u32, Sled>,
+    #[daft(map_values = "recursive")]
+    zones: HashMap<String, u64>,
+    racks: BTreeMap<u32, Sled>,
+}
+
+fn main() {
+    let sled = |serial: &str, generation| Sled {
+        serial: serial.to_owned(),
+        generation,
+    };
+    let before = Inventory {
+        sleds: [(1, sled("a", 1)), (2, sled("b", 1))].into(),
+        zones: [("ntp".to_owned(), 1)].into(),
+        racks: BTreeMap::new(),
+    };
+    let after = Inventory {
+        sleds: [(1, sled("a", 2)), (3, sled("c", 1))].into(),
+        zones: [("ntp".to_owned(), 1)].into(),
+        racks: BTreeMap::new(),
+    };
+
+    // The nested diffs are stored as fields.
+    let diff = before.diff(&after);
+    let sleds: &BTreeMapRecursiveDiff<'_, u32, Sled> = &diff.sleds;
+    assert_eq!(sleds.common[&1].generation, Leaf { before: &1, after: &2 });
+    assert_eq!(sleds.modified_keys().collect::<Vec<_>>(), [&1]);
+    assert!(DiffSummary::is_unchanged(&diff.zones));
+
+    let mut target = before.clone();
+    diff.apply(&mut target).unwrap();
+    assert_eq!(target, after);
+
+    let mut merged = after.clone();
+    diff.merge(&diff, &mut merged).unwrap();
+    assert_eq!(merged, after);
+
+    let owned = diff.into_owned();
+    assert!(owned.sleds.common[&1].generation.is_modified());
+}
@@ -53,6 +53,189 @@ where
     ::daft::Leaf<&'__daft usize>: ::core::cmp::Eq,
     <usize as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
 {}
+impl<'__daft> ::daft::DiffSummary for WithAttrsDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <BTreeMap<
+        Uuid,
+        BTreeSet<usize>,
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    ::daft::Leaf<&'__daft Lazy>: ::daft::DiffSummary,
+    ::daft::Leaf<&'__daft usize>: ::daft::DiffSummary,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.a)
+            && ::daft::DiffSummary::is_unchanged(&self.b)
+            && ::daft::DiffSummary::is_unchanged(&self.d)
+            && ::daft::DiffSummary::is_unchanged(&self.e)
+            && ::daft::DiffSummary::is_unchanged(&self.f)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for WithAttrsDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <BTreeMap<
+        Uuid,
+        BTreeSet<usize>,
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    ::daft::Leaf<&'__daft Lazy>: ::daft::VisitDiff,
+    ::daft::Leaf<&'__daft usize>: ::daft::VisitDiff,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.a,
+            &path.join(::daft::PathSegment::Field("a")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.b,
+            &path.join(::daft::PathSegment::Field("b")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.d,
+            &path.join(::daft::PathSegment::Field("d")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.e,
+            &path.join(::daft::PathSegment::Field("e")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.f,
+            &path.join(::daft::PathSegment::Field("f")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for WithAttrsDiff < '__daft >
+    where < i32 as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, < BTreeMap < Uuid, BTreeSet < usize > > as
+    ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize,
+    ::daft::Leaf < & '__daft Lazy > : ::daft::__private::serde::Serialize, ::daft::Leaf <
+    & '__daft usize > : ::daft::__private::serde::Serialize, < usize as ::daft::Diffable
+    > ::Diff < '__daft > : ::daft::__private::serde::Serialize { fn serialize < __S > (&
+    self, __serializer : __S,) -> ::core::result::Result < __S::Ok, __S::Error > where
+    __S : ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(WithAttrsDiff), 5,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "a", &
+    self.a,) ?; ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "b", & self.b,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "d", &
+    self.d,) ?; ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "e", & self.e,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "f", &
+    self.f,) ?; ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for WithAttrsDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <BTreeMap<Uuid, BTreeSet<usize>> as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    ::daft::Leaf<&'__daft Lazy>: ::daft::Invert,
+    ::daft::Leaf<&'__daft usize>: ::daft::Invert,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            a: ::daft::Invert::invert(self.a),
+            b: ::daft::Invert::invert(self.b),
+            d: ::daft::Invert::invert(self.d),
+            e: ::daft::Invert::invert(self.e),
+            f: ::daft::Invert::invert(self.f),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<WithAttrs> for WithAttrsDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<i32>,
+    <BTreeMap<
+        Uuid,
+        BTreeSet<usize>,
+    > as ::daft::Diffable>::Diff<
+        '__daft,
+    >: ::daft::Patch<BTreeMap<Uuid, BTreeSet<usize>>>,
+    ::daft::Leaf<&'__daft Lazy>: ::daft::Patch<Lazy>,
+    ::daft::Leaf<&'__daft usize>: ::daft::Patch<usize>,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<usize>,
+{
+    fn apply(
+        &self,
+        target: &mut WithAttrs,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.a, &mut target.a)?;
+        ::daft::Patch::apply(&self.b, &mut target.b)?;
+        ::daft::Patch::apply(&self.d, &mut target.d)?;
+        ::daft::Patch::apply(&self.e, &mut target.e)?;
+        ::daft::Patch::apply(&self.f, &mut target.f)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<WithAttrs> for WithAttrsDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<i32>,
+    <BTreeMap<
+        Uuid,
+        BTreeSet<usize>,
+    > as ::daft::Diffable>::Diff<
+        '__daft,
+    >: ::daft::Merge<BTreeMap<Uuid, BTreeSet<usize>>>,
+    ::daft::Leaf<&'__daft Lazy>: ::daft::Merge<Lazy>,
+    ::daft::Leaf<&'__daft usize>: ::daft::Merge<usize>,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<usize>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut WithAttrs,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.a,
+            &theirs.a,
+            &mut target.a,
+            &path.join(::daft::PathSegment::Field("a")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.b,
+            &theirs.b,
+            &mut target.b,
+            &path.join(::daft::PathSegment::Field("b")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.d,
+            &theirs.d,
+            &mut target.d,
+            &path.join(::daft::PathSegment::Field("d")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.e,
+            &theirs.e,
+            &mut target.e,
+            &path.join(::daft::PathSegment::Field("e")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.f,
+            &theirs.f,
+            &mut target.f,
+            &path.join(::daft::PathSegment::Field("f")),
+            conflict,
+        );
+    }
+}
 impl ::daft::Diffable for WithAttrs {
     type Diff<'__daft> = WithAttrsDiff<'__daft> where Self: '__daft;
     fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> WithAttrsDiff<'__daft> {
@@ -101,6 +284,106 @@ where
     <usize as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
     <usize as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
 {}
+impl<'__daft> ::daft::DiffSummary for LazyDiff<'__daft>
+where
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.x)
+            && ::daft::DiffSummary::is_unchanged(&self.y)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for LazyDiff<'__daft>
+where
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.x,
+            &path.join(::daft::PathSegment::Field("x")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.y,
+            &path.join(::daft::PathSegment::Field("y")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for LazyDiff < '__daft > where <
+    usize as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize,
+    < usize as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(LazyDiff), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "x", &
+    self.x,) ?; ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "y", & self.y,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for LazyDiff<'__daft>
+where
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            x: ::daft::Invert::invert(self.x),
+            y: ::daft::Invert::invert(self.y),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Lazy> for LazyDiff<'__daft>
+where
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<usize>,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<usize>,
+{
+    fn apply(
+        &self,
+        target: &mut Lazy,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.x, &mut target.x)?;
+        ::daft::Patch::apply(&self.y, &mut target.y)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Lazy> for LazyDiff<'__daft>
+where
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<usize>,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<usize>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Lazy,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.x,
+            &theirs.x,
+            &mut target.x,
+            &path.join(::daft::PathSegment::Field("x")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.y,
+            &theirs.y,
+            &mut target.y,
+            &path.join(::daft::PathSegment::Field("y")),
+            conflict,
+        );
+    }
+}
 impl ::daft::Diffable for Lazy {
     type Diff<'__daft> = LazyDiff<'__daft> where Self: '__daft;
     fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> LazyDiff<'__daft> {
@@ -37,6 +37,122 @@ where
         BTreeSet<usize>,
     > as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
 {}
+impl<'__daft> ::daft::DiffSummary for BasicDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <BTreeMap<
+        Uuid,
+        BTreeSet<usize>,
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.a)
+            && ::daft::DiffSummary::is_unchanged(&self.b)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for BasicDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <BTreeMap<
+        Uuid,
+        BTreeSet<usize>,
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.a,
+            &path.join(::daft::PathSegment::Field("a")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.b,
+            &path.join(::daft::PathSegment::Field("b")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for BasicDiff < '__daft > where
+    < i32 as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize,
+    < BTreeMap < Uuid, BTreeSet < usize > > as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(BasicDiff), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "a", &
+    self.a,) ?; ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "b", & self.b,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for BasicDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <BTreeMap<Uuid, BTreeSet<usize>> as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            a: ::daft::Invert::invert(self.a),
+            b: ::daft::Invert::invert(self.b),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Basic> for BasicDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<i32>,
+    <BTreeMap<
+        Uuid,
+        BTreeSet<usize>,
+    > as ::daft::Diffable>::Diff<
+        '__daft,
+    >: ::daft::Patch<BTreeMap<Uuid, BTreeSet<usize>>>,
+{
+    fn apply(
+        &self,
+        target: &mut Basic,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.a, &mut target.a)?;
+        ::daft::Patch::apply(&self.b, &mut target.b)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Basic> for BasicDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<i32>,
+    <BTreeMap<
+        Uuid,
+        BTreeSet<usize>,
+    > as ::daft::Diffable>::Diff<
+        '__daft,
+    >: ::daft::Merge<BTreeMap<Uuid, BTreeSet<usize>>>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Basic,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.a,
+            &theirs.a,
+            &mut target.a,
+            &path.join(::daft::PathSegment::Field("a")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.b,
+            &theirs.b,
+            &mut target.b,
+            &path.join(::daft::PathSegment::Field("b")),
+            conflict,
+        );
+    }
+}
 impl ::daft::Diffable for Basic {
     type Diff<'__daft> = BasicDiff<'__daft> where Self: '__daft;
     fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> BasicDiff<'__daft> {
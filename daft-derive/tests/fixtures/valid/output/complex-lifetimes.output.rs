@@ -73,6 +73,204 @@ where
     <PhantomData<Cell<&'inv ()>> as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
     <PhantomData<fn(&'contra ())> as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
 {}
+impl<
+    '__daft,
+    'a: '__daft,
+    'b: '__daft,
+    'daft: 'a + '__daft,
+    'inv: '__daft,
+    'contra: '__daft,
+> ::daft::DiffSummary for SDiff<'__daft, 'a, 'b, 'daft, 'inv, 'contra>
+where
+    <&'a &'b Vec<u8> as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <&'daft Vec<u8> as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <PhantomData<
+        Cell<&'inv ()>,
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <PhantomData<
+        fn(&'contra ()),
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.multi_ref)
+            && ::daft::DiffSummary::is_unchanged(&self.bound_ref)
+            && ::daft::DiffSummary::is_unchanged(&self.inv_ref)
+            && ::daft::DiffSummary::is_unchanged(&self.contra_ref)
+    }
+}
+impl<
+    '__daft,
+    'a: '__daft,
+    'b: '__daft,
+    'daft: 'a + '__daft,
+    'inv: '__daft,
+    'contra: '__daft,
+> ::daft::VisitDiff for SDiff<'__daft, 'a, 'b, 'daft, 'inv, 'contra>
+where
+    <&'a &'b Vec<u8> as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <&'daft Vec<u8> as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <PhantomData<Cell<&'inv ()>> as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <PhantomData<fn(&'contra ())> as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.multi_ref,
+            &path.join(::daft::PathSegment::Field("multi_ref")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.bound_ref,
+            &path.join(::daft::PathSegment::Field("bound_ref")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.inv_ref,
+            &path.join(::daft::PathSegment::Field("inv_ref")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.contra_ref,
+            &path.join(::daft::PathSegment::Field("contra_ref")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft, 'a : '__daft, 'b : '__daft, 'daft : 'a + '__daft, 'inv : '__daft,
+    'contra : '__daft > ::daft::__private::serde::Serialize for SDiff < '__daft, 'a, 'b,
+    'daft, 'inv, 'contra > where < & 'a & 'b Vec < u8 > as ::daft::Diffable > ::Diff <
+    '__daft > : ::daft::__private::serde::Serialize, < & 'daft Vec < u8 > as
+    ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize, <
+    PhantomData < Cell < & 'inv() > > as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, < PhantomData < fn (& 'contra()) > as
+    ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize { fn
+    serialize < __S > (& self, __serializer : __S,) -> ::core::result::Result < __S::Ok,
+    __S::Error > where __S : ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(SDiff), 4,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "multi_ref", & self.multi_ref,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "bound_ref", & self.bound_ref,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "inv_ref", & self.inv_ref,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "contra_ref", & self.contra_ref,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<
+    '__daft,
+    'a: '__daft,
+    'b: '__daft,
+    'daft: 'a + '__daft,
+    'inv: '__daft,
+    'contra: '__daft,
+> ::daft::Invert for SDiff<'__daft, 'a, 'b, 'daft, 'inv, 'contra>
+where
+    <&'a &'b Vec<u8> as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <&'daft Vec<u8> as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <PhantomData<Cell<&'inv ()>> as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <PhantomData<fn(&'contra ())> as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            multi_ref: ::daft::Invert::invert(self.multi_ref),
+            bound_ref: ::daft::Invert::invert(self.bound_ref),
+            inv_ref: ::daft::Invert::invert(self.inv_ref),
+            contra_ref: ::daft::Invert::invert(self.contra_ref),
+        }
+    }
+}
+impl<
+    '__daft,
+    'a: '__daft,
+    'b: '__daft,
+    'daft: 'a + '__daft,
+    'inv: '__daft,
+    'contra: '__daft,
+> ::daft::Patch<S<'a, 'b, 'daft, 'inv, 'contra>>
+for SDiff<'__daft, 'a, 'b, 'daft, 'inv, 'contra>
+where
+    <&'a &'b Vec<u8> as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<&'a &'b Vec<u8>>,
+    <&'daft Vec<u8> as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<&'daft Vec<u8>>,
+    <PhantomData<
+        Cell<&'inv ()>,
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<PhantomData<Cell<&'inv ()>>>,
+    <PhantomData<
+        fn(&'contra ()),
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<PhantomData<fn(&'contra ())>>,
+{
+    fn apply(
+        &self,
+        target: &mut S<'a, 'b, 'daft, 'inv, 'contra>,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.multi_ref, &mut target.multi_ref)?;
+        ::daft::Patch::apply(&self.bound_ref, &mut target.bound_ref)?;
+        ::daft::Patch::apply(&self.inv_ref, &mut target.inv_ref)?;
+        ::daft::Patch::apply(&self.contra_ref, &mut target.contra_ref)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<
+    '__daft,
+    'a: '__daft,
+    'b: '__daft,
+    'daft: 'a + '__daft,
+    'inv: '__daft,
+    'contra: '__daft,
+> ::daft::Merge<S<'a, 'b, 'daft, 'inv, 'contra>>
+for SDiff<'__daft, 'a, 'b, 'daft, 'inv, 'contra>
+where
+    <&'a &'b Vec<u8> as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<&'a &'b Vec<u8>>,
+    <&'daft Vec<u8> as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<&'daft Vec<u8>>,
+    <PhantomData<
+        Cell<&'inv ()>,
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<PhantomData<Cell<&'inv ()>>>,
+    <PhantomData<
+        fn(&'contra ()),
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<PhantomData<fn(&'contra ())>>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut S<'a, 'b, 'daft, 'inv, 'contra>,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.multi_ref,
+            &theirs.multi_ref,
+            &mut target.multi_ref,
+            &path.join(::daft::PathSegment::Field("multi_ref")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.bound_ref,
+            &theirs.bound_ref,
+            &mut target.bound_ref,
+            &path.join(::daft::PathSegment::Field("bound_ref")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.inv_ref,
+            &theirs.inv_ref,
+            &mut target.inv_ref,
+            &path.join(::daft::PathSegment::Field("inv_ref")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.contra_ref,
+            &theirs.contra_ref,
+            &mut target.contra_ref,
+            &path.join(::daft::PathSegment::Field("contra_ref")),
+            conflict,
+        );
+    }
+}
 impl<'a, 'b, 'daft: 'a, 'inv, 'contra> ::daft::Diffable
 for S<'a, 'b, 'daft, 'inv, 'contra> {
     type Diff<'__daft> = SDiff<'__daft, 'a, 'b, 'daft, 'inv, 'contra>
@@ -0,0 +1,476 @@
+struct ConfigDiff<'__daft> {
+    threads: <usize as crate::facade::daft::Diffable>::Diff<'__daft>,
+    name: <String as crate::facade::daft::Diffable>::Diff<'__daft>,
+}
+impl<'__daft> ::core::fmt::Debug for ConfigDiff<'__daft>
+where
+    <usize as crate::facade::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    <String as crate::facade::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(ConfigDiff))
+            .field(stringify!(threads), &self.threads)
+            .field(stringify!(name), &self.name)
+            .finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for ConfigDiff<'__daft>
+where
+    <usize as crate::facade::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    <String as crate::facade::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.threads == other.threads && self.name == other.name
+    }
+}
+impl<'__daft> ::core::cmp::Eq for ConfigDiff<'__daft>
+where
+    <usize as crate::facade::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    <String as crate::facade::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+{}
+impl<'__daft> crate::facade::daft::DiffSummary for ConfigDiff<'__daft>
+where
+    <usize as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    >: crate::facade::daft::DiffSummary,
+    <String as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    >: crate::facade::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        crate::facade::daft::DiffSummary::is_unchanged(&self.threads)
+            && crate::facade::daft::DiffSummary::is_unchanged(&self.name)
+    }
+}
+impl<'__daft> crate::facade::daft::VisitDiff for ConfigDiff<'__daft>
+where
+    <usize as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    >: crate::facade::daft::VisitDiff,
+    <String as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    >: crate::facade::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &crate::facade::daft::DiffPath<'_>,
+        visitor: &mut dyn crate::facade::daft::DiffVisitor,
+    ) {
+        crate::facade::daft::VisitDiff::visit_at(
+            &self.threads,
+            &path.join(crate::facade::daft::PathSegment::Field("threads")),
+            visitor,
+        );
+        crate::facade::daft::VisitDiff::visit_at(
+            &self.name,
+            &path.join(crate::facade::daft::PathSegment::Field("name")),
+            visitor,
+        );
+    }
+}
+crate::facade::daft::__private::serde_impl! {
+    impl < '__daft > crate ::facade::daft::__private::serde::Serialize for ConfigDiff <
+    '__daft > where < usize as crate ::facade::daft::Diffable > ::Diff < '__daft > :
+    crate ::facade::daft::__private::serde::Serialize, < String as crate
+    ::facade::daft::Diffable > ::Diff < '__daft > : crate
+    ::facade::daft::__private::serde::Serialize { fn serialize < __S > (& self,
+    __serializer : __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    crate ::facade::daft::__private::serde::Serializer, { let mut __state = crate
+    ::facade::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(ConfigDiff), 2,) ?; crate
+    ::facade::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "threads", & self.threads,) ?; crate
+    ::facade::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "name", & self.name,) ?; crate
+    ::facade::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> crate::facade::daft::Invert for ConfigDiff<'__daft>
+where
+    <usize as crate::facade::daft::Diffable>::Diff<'__daft>: crate::facade::daft::Invert,
+    <String as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    >: crate::facade::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            threads: crate::facade::daft::Invert::invert(self.threads),
+            name: crate::facade::daft::Invert::invert(self.name),
+        }
+    }
+}
+impl<'__daft> crate::facade::daft::Patch<Config> for ConfigDiff<'__daft>
+where
+    <usize as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    >: crate::facade::daft::Patch<usize>,
+    <String as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    >: crate::facade::daft::Patch<String>,
+{
+    fn apply(
+        &self,
+        target: &mut Config,
+    ) -> ::core::result::Result<(), crate::facade::daft::PatchError> {
+        crate::facade::daft::Patch::apply(&self.threads, &mut target.threads)?;
+        crate::facade::daft::Patch::apply(&self.name, &mut target.name)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> crate::facade::daft::Merge<Config> for ConfigDiff<'__daft>
+where
+    <usize as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    >: crate::facade::daft::Merge<usize>,
+    <String as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    >: crate::facade::daft::Merge<String>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Config,
+        path: &crate::facade::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(
+            &crate::facade::daft::DiffPath<'_>,
+            crate::facade::daft::MergeConflictKind,
+        ),
+    ) {
+        crate::facade::daft::Merge::merge_at(
+            &self.threads,
+            &theirs.threads,
+            &mut target.threads,
+            &path.join(crate::facade::daft::PathSegment::Field("threads")),
+            conflict,
+        );
+        crate::facade::daft::Merge::merge_at(
+            &self.name,
+            &theirs.name,
+            &mut target.name,
+            &path.join(crate::facade::daft::PathSegment::Field("name")),
+            conflict,
+        );
+    }
+}
+impl crate::facade::daft::Diffable for Config {
+    type Diff<'__daft> = ConfigDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> ConfigDiff<'__daft> {
+        Self::Diff {
+            threads: crate::facade::daft::Diffable::diff(&self.threads, &other.threads),
+            name: crate::facade::daft::Diffable::diff(&self.name, &other.name),
+        }
+    }
+}
+struct ConfigDiffOwned {
+    threads: <<usize as crate::facade::daft::Diffable>::Diff<
+        'static,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned,
+    name: <<String as crate::facade::daft::Diffable>::Diff<
+        'static,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned,
+}
+impl ::core::fmt::Debug for ConfigDiffOwned
+where
+    for<'__daft> <<usize as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+    for<'__daft> <<String as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(ConfigDiffOwned))
+            .field(stringify!(threads), &self.threads)
+            .field(stringify!(name), &self.name)
+            .finish()
+    }
+}
+impl ::core::clone::Clone for ConfigDiffOwned
+where
+    for<'__daft> <<usize as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+    for<'__daft> <<String as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            threads: ::core::clone::Clone::clone(&self.threads),
+            name: ::core::clone::Clone::clone(&self.name),
+        }
+    }
+}
+impl ::core::cmp::PartialEq for ConfigDiffOwned
+where
+    for<'__daft> <<usize as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+    for<'__daft> <<String as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.threads == other.threads && self.name == other.name
+    }
+}
+impl ::core::cmp::Eq for ConfigDiffOwned
+where
+    for<'__daft> <<usize as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+    for<'__daft> <<String as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+{}
+crate::facade::daft::__private::serde_impl! {
+    impl crate ::facade::daft::__private::serde::Serialize for ConfigDiffOwned where for
+    < '__daft > < < usize as crate ::facade::daft::Diffable > ::Diff < '__daft > as crate
+    ::facade::daft::IntoOwnedDiff > ::Owned : crate
+    ::facade::daft::__private::serde::Serialize, for < '__daft > < < String as crate
+    ::facade::daft::Diffable > ::Diff < '__daft > as crate ::facade::daft::IntoOwnedDiff
+    > ::Owned : crate ::facade::daft::__private::serde::Serialize { fn serialize < __S >
+    (& self, __serializer : __S,) -> ::core::result::Result < __S::Ok, __S::Error > where
+    __S : crate ::facade::daft::__private::serde::Serializer, { let mut __state = crate
+    ::facade::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(ConfigDiffOwned), 2,) ?; crate
+    ::facade::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "threads", & self.threads,) ?; crate
+    ::facade::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "name", & self.name,) ?; crate
+    ::facade::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl crate::facade::daft::Invert for ConfigDiffOwned
+where
+    for<'__daft> <<usize as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned: crate::facade::daft::Invert,
+    for<'__daft> <<String as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned: crate::facade::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            threads: crate::facade::daft::Invert::invert(self.threads),
+            name: crate::facade::daft::Invert::invert(self.name),
+        }
+    }
+}
+impl crate::facade::daft::Patch<Config> for ConfigDiffOwned
+where
+    for<'__daft> <<usize as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned: crate::facade::daft::Patch<usize>,
+    for<'__daft> <<String as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    > as crate::facade::daft::IntoOwnedDiff>::Owned: crate::facade::daft::Patch<String>,
+{
+    fn apply(
+        &self,
+        target: &mut Config,
+    ) -> ::core::result::Result<(), crate::facade::daft::PatchError> {
+        crate::facade::daft::Patch::apply(&self.threads, &mut target.threads)?;
+        crate::facade::daft::Patch::apply(&self.name, &mut target.name)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> crate::facade::daft::IntoOwnedDiff for ConfigDiff<'__daft> {
+    type Owned = ConfigDiffOwned;
+    fn into_owned(self) -> Self::Owned {
+        ConfigDiffOwned {
+            threads: crate::facade::daft::IntoOwnedDiff::into_owned(self.threads),
+            name: crate::facade::daft::IntoOwnedDiff::into_owned(self.name),
+        }
+    }
+}
+impl crate::facade::daft::Diffable for Token {
+    type Diff<'__daft> = crate::facade::daft::Leaf<&'__daft Self> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> Self::Diff<'__daft> {
+        crate::facade::daft::Leaf {
+            before: self,
+            after: other,
+        }
+    }
+}
+enum StateDiff<'__daft> {
+    Idle,
+    Running { config: <Config as crate::facade::daft::Diffable>::Diff<'__daft> },
+    VariantChanged(crate::facade::daft::Leaf<&'__daft State>),
+}
+impl<'__daft> ::core::fmt::Debug for StateDiff<'__daft>
+where
+    <Config as crate::facade::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    crate::facade::daft::Leaf<&'__daft State>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Idle {} => f.write_str(stringify!(Idle)),
+            Self::Running { config: __field_0 } => {
+                f.debug_struct(stringify!(Running))
+                    .field(stringify!(config), __field_0)
+                    .finish()
+            }
+            Self::VariantChanged(leaf) => {
+                f.debug_tuple(stringify!(VariantChanged)).field(leaf).finish()
+            }
+        }
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for StateDiff<'__daft>
+where
+    <Config as crate::facade::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    crate::facade::daft::Leaf<&'__daft State>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Idle {}, Self::Idle {}) => true,
+            (Self::Running { config: __self_0 }, Self::Running { config: __other_0 }) => {
+                __self_0 == __other_0
+            }
+            (Self::VariantChanged(self_leaf), Self::VariantChanged(other_leaf)) => {
+                self_leaf == other_leaf
+            }
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+impl<'__daft> ::core::cmp::Eq for StateDiff<'__daft>
+where
+    <Config as crate::facade::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    crate::facade::daft::Leaf<&'__daft State>: ::core::cmp::Eq,
+{}
+impl<'__daft> crate::facade::daft::DiffSummary for StateDiff<'__daft>
+where
+    <Config as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    >: crate::facade::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        match self {
+            Self::Idle {} => true,
+            Self::Running { config: __field_0 } => {
+                crate::facade::daft::DiffSummary::is_unchanged(__field_0)
+            }
+            Self::VariantChanged(_) => false,
+        }
+    }
+}
+impl<'__daft> crate::facade::daft::VisitDiff for StateDiff<'__daft>
+where
+    <Config as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    >: crate::facade::daft::VisitDiff,
+    &'__daft State: ::core::fmt::Debug,
+{
+    fn visit_at(
+        &self,
+        path: &crate::facade::daft::DiffPath<'_>,
+        visitor: &mut dyn crate::facade::daft::DiffVisitor,
+    ) {
+        match self {
+            Self::Idle {} => {}
+            Self::Running { config: __field_0 } => {
+                let path = path
+                    .join(crate::facade::daft::PathSegment::Variant("Running"));
+                crate::facade::daft::VisitDiff::visit_at(
+                    __field_0,
+                    &path.join(crate::facade::daft::PathSegment::Field("config")),
+                    visitor,
+                );
+            }
+            Self::VariantChanged(leaf) => {
+                crate::facade::daft::DiffVisitor::modified(
+                    visitor,
+                    path,
+                    &leaf.before,
+                    &leaf.after,
+                );
+            }
+        }
+    }
+}
+impl<'__daft> crate::facade::daft::Invert for StateDiff<'__daft>
+where
+    <Config as crate::facade::daft::Diffable>::Diff<
+        '__daft,
+    >: crate::facade::daft::Invert,
+    crate::facade::daft::Leaf<&'__daft State>: crate::facade::daft::Invert,
+{
+    fn invert(self) -> Self {
+        match self {
+            Self::Idle {} => Self::Idle {},
+            Self::Running { config: __field_0 } => {
+                Self::Running {
+                    config: crate::facade::daft::Invert::invert(__field_0),
+                }
+            }
+            Self::VariantChanged(leaf) => {
+                Self::VariantChanged(crate::facade::daft::Invert::invert(leaf))
+            }
+        }
+    }
+}
+crate::facade::daft::__private::serde_impl! {
+    impl < '__daft > crate ::facade::daft::__private::serde::Serialize for StateDiff <
+    '__daft > where < Config as crate ::facade::daft::Diffable > ::Diff < '__daft > :
+    crate ::facade::daft::__private::serde::Serialize, crate ::facade::daft::Leaf < &
+    '__daft State > : crate ::facade::daft::__private::serde::Serialize { fn serialize <
+    __S > (& self, __serializer : __S,) -> ::core::result::Result < __S::Ok, __S::Error >
+    where __S : crate ::facade::daft::__private::serde::Serializer, { match self {
+    Self::Idle {} => crate
+    ::facade::daft::__private::serde::Serializer::serialize_unit_variant(__serializer,
+    stringify!(StateDiff), 0, "Idle",), Self::Running { config : __field_0 } => { let mut
+    __state = crate
+    ::facade::daft::__private::serde::Serializer::serialize_struct_variant(__serializer,
+    stringify!(StateDiff), 1, "Running", 1,) ?; crate
+    ::facade::daft::__private::serde::ser::SerializeStructVariant::serialize_field(& mut
+    __state, "config", __field_0,) ?; crate
+    ::facade::daft::__private::serde::ser::SerializeStructVariant::end(__state) },
+    Self::VariantChanged(leaf) => { crate
+    ::facade::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(StateDiff), 2, stringify!(VariantChanged), leaf,) } } } }
+}
+impl crate::facade::daft::Diffable for State {
+    type Diff<'__daft> = StateDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> StateDiff<'__daft> {
+        match (self, other) {
+            (Self::Idle { .. }, Self::Idle { .. }) => StateDiff::Idle {},
+            (
+                Self::Running { config: __daft_before_0, .. },
+                Self::Running { config: __daft_after_0, .. },
+            ) => {
+                StateDiff::Running {
+                    config: crate::facade::daft::Diffable::diff(
+                        __daft_before_0,
+                        __daft_after_0,
+                    ),
+                }
+            }
+            #[allow(unreachable_patterns)]
+            _ => {
+                StateDiff::VariantChanged(crate::facade::daft::Leaf {
+                    before: self,
+                    after: other,
+                })
+            }
+        }
+    }
+}
+impl crate::facade::daft::Diffable for Mode {
+    type Diff<'__daft> = crate::facade::daft::Leaf<&'__daft Self> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> Self::Diff<'__daft> {
+        crate::facade::daft::Leaf {
+            before: self,
+            after: other,
+        }
+    }
+}
+impl crate::facade::daft::Diffable for Bits {
+    type Diff<'__daft> = crate::facade::daft::Leaf<&'__daft Self> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> Self::Diff<'__daft> {
+        crate::facade::daft::Leaf {
+            before: self,
+            after: other,
+        }
+    }
+}
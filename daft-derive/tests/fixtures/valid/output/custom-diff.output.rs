@@ -0,0 +1,469 @@
+struct SledDiff<'__daft> {
+    foreign: <i64 as ::daft::__private::CustomDiff<'__daft>>::Output,
+    zpools: <&'__daft [u32] as ::daft::__private::CustomDiff<'__daft>>::Output,
+    name: <leaf_diff::Diff<'__daft> as ::daft::__private::CustomDiff<'__daft>>::Output,
+}
+impl<'__daft> ::core::fmt::Debug for SledDiff<'__daft>
+where
+    <i64 as ::daft::__private::CustomDiff<'__daft>>::Output: ::core::fmt::Debug,
+    <&'__daft [u32] as ::daft::__private::CustomDiff<
+        '__daft,
+    >>::Output: ::core::fmt::Debug,
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(SledDiff))
+            .field(stringify!(foreign), &self.foreign)
+            .field(stringify!(zpools), &self.zpools)
+            .field(stringify!(name), &self.name)
+            .finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for SledDiff<'__daft>
+where
+    <i64 as ::daft::__private::CustomDiff<'__daft>>::Output: ::core::cmp::PartialEq,
+    <&'__daft [u32] as ::daft::__private::CustomDiff<
+        '__daft,
+    >>::Output: ::core::cmp::PartialEq,
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.foreign == other.foreign && self.zpools == other.zpools
+            && self.name == other.name
+    }
+}
+impl<'__daft> ::core::cmp::Eq for SledDiff<'__daft>
+where
+    <i64 as ::daft::__private::CustomDiff<'__daft>>::Output: ::core::cmp::Eq,
+    <&'__daft [u32] as ::daft::__private::CustomDiff<'__daft>>::Output: ::core::cmp::Eq,
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for SledDiff<'__daft>
+where
+    <i64 as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::DiffSummary,
+    <&'__daft [u32] as ::daft::__private::CustomDiff<
+        '__daft,
+    >>::Output: ::daft::DiffSummary,
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.foreign)
+            && ::daft::DiffSummary::is_unchanged(&self.zpools)
+            && ::daft::DiffSummary::is_unchanged(&self.name)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for SledDiff<'__daft>
+where
+    <i64 as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::VisitDiff,
+    <&'__daft [u32] as ::daft::__private::CustomDiff<
+        '__daft,
+    >>::Output: ::daft::VisitDiff,
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.foreign,
+            &path.join(::daft::PathSegment::Field("foreign")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.zpools,
+            &path.join(::daft::PathSegment::Field("zpools")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.name,
+            &path.join(::daft::PathSegment::Field("name")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for SledDiff < '__daft > where <
+    i64 as ::daft::__private::CustomDiff < '__daft > > ::Output :
+    ::daft::__private::serde::Serialize, < & '__daft[u32] as
+    ::daft::__private::CustomDiff < '__daft > > ::Output :
+    ::daft::__private::serde::Serialize, < leaf_diff::Diff < '__daft > as
+    ::daft::__private::CustomDiff < '__daft > > ::Output :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(SledDiff), 3,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "foreign", & self.foreign,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "zpools", & self.zpools,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "name", & self.name,) ?; ::daft::__private::serde::ser::SerializeStruct::end(__state)
+    } }
+}
+impl<'__daft> ::daft::Invert for SledDiff<'__daft>
+where
+    <i64 as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::Invert,
+    <&'__daft [u32] as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::Invert,
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            foreign: ::daft::Invert::invert(self.foreign),
+            zpools: ::daft::Invert::invert(self.zpools),
+            name: ::daft::Invert::invert(self.name),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Sled> for SledDiff<'__daft>
+where
+    <i64 as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::Patch<Foreign>,
+    <&'__daft [u32] as ::daft::__private::CustomDiff<
+        '__daft,
+    >>::Output: ::daft::Patch<Vec<u32>>,
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::Patch<String>,
+{
+    fn apply(
+        &self,
+        target: &mut Sled,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.foreign, &mut target.foreign)?;
+        ::daft::Patch::apply(&self.zpools, &mut target.zpools)?;
+        ::daft::Patch::apply(&self.name, &mut target.name)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Sled> for SledDiff<'__daft>
+where
+    <i64 as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::Merge<Foreign>,
+    <&'__daft [u32] as ::daft::__private::CustomDiff<
+        '__daft,
+    >>::Output: ::daft::Merge<Vec<u32>>,
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::Merge<String>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Sled,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.foreign,
+            &theirs.foreign,
+            &mut target.foreign,
+            &path.join(::daft::PathSegment::Field("foreign")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.zpools,
+            &theirs.zpools,
+            &mut target.zpools,
+            &path.join(::daft::PathSegment::Field("zpools")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.name,
+            &theirs.name,
+            &mut target.name,
+            &path.join(::daft::PathSegment::Field("name")),
+            conflict,
+        );
+    }
+}
+impl ::daft::Diffable for Sled {
+    type Diff<'__daft> = SledDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> SledDiff<'__daft> {
+        Self::Diff {
+            foreign: foreign_diff(&self.foreign, &other.foreign),
+            zpools: common_prefix(&self.zpools, &other.zpools),
+            name: leaf_diff::diff(&self.name, &other.name),
+        }
+    }
+}
+struct MeasurementDiff<'__daft> {
+    value: <Option<f64> as ::daft::__private::CustomDiff<'__daft>>::Output,
+}
+impl<'__daft> ::core::fmt::Debug for MeasurementDiff<'__daft>
+where
+    <Option<f64> as ::daft::__private::CustomDiff<'__daft>>::Output: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(MeasurementDiff))
+            .field(stringify!(value), &self.value)
+            .finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for MeasurementDiff<'__daft>
+where
+    <Option<
+        f64,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+impl<'__daft> ::core::cmp::Eq for MeasurementDiff<'__daft>
+where
+    <Option<f64> as ::daft::__private::CustomDiff<'__daft>>::Output: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for MeasurementDiff<'__daft>
+where
+    <Option<f64> as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.value)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for MeasurementDiff<'__daft>
+where
+    <Option<f64> as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.value,
+            &path.join(::daft::PathSegment::Field("value")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for MeasurementDiff < '__daft >
+    where < Option < f64 > as ::daft::__private::CustomDiff < '__daft > > ::Output :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(MeasurementDiff), 1,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "value", & self.value,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for MeasurementDiff<'__daft>
+where
+    <Option<f64> as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            value: ::daft::Invert::invert(self.value),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Measurement> for MeasurementDiff<'__daft>
+where
+    <Option<f64> as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::Patch<f64>,
+{
+    fn apply(
+        &self,
+        target: &mut Measurement,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.value, &mut target.value)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Measurement> for MeasurementDiff<'__daft>
+where
+    <Option<f64> as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::Merge<f64>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Measurement,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.value,
+            &theirs.value,
+            &mut target.value,
+            &path.join(::daft::PathSegment::Field("value")),
+            conflict,
+        );
+    }
+}
+impl ::daft::Diffable for Measurement {
+    type Diff<'__daft> = MeasurementDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> MeasurementDiff<'__daft> {
+        Self::Diff {
+            value: float_delta(&self.value, &other.value),
+        }
+    }
+}
+enum StateDiff<'__daft> {
+    Running(
+        <leaf_diff::Diff<'__daft> as ::daft::__private::CustomDiff<'__daft>>::Output,
+    ),
+    Stopped,
+    VariantChanged(::daft::Leaf<&'__daft State>),
+}
+impl<'__daft> ::core::fmt::Debug for StateDiff<'__daft>
+where
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::core::fmt::Debug,
+    ::daft::Leaf<&'__daft State>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Running { 0: __field_0 } => {
+                f.debug_tuple(stringify!(Running)).field(__field_0).finish()
+            }
+            Self::Stopped {} => f.write_str(stringify!(Stopped)),
+            Self::VariantChanged(leaf) => {
+                f.debug_tuple(stringify!(VariantChanged)).field(leaf).finish()
+            }
+        }
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for StateDiff<'__daft>
+where
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::core::cmp::PartialEq,
+    ::daft::Leaf<&'__daft State>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Running { 0: __self_0 }, Self::Running { 0: __other_0 }) => {
+                __self_0 == __other_0
+            }
+            (Self::Stopped {}, Self::Stopped {}) => true,
+            (Self::VariantChanged(self_leaf), Self::VariantChanged(other_leaf)) => {
+                self_leaf == other_leaf
+            }
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+impl<'__daft> ::core::cmp::Eq for StateDiff<'__daft>
+where
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::core::cmp::Eq,
+    ::daft::Leaf<&'__daft State>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for StateDiff<'__daft>
+where
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        match self {
+            Self::Running { 0: __field_0 } => {
+                ::daft::DiffSummary::is_unchanged(__field_0)
+            }
+            Self::Stopped {} => true,
+            Self::VariantChanged(_) => false,
+        }
+    }
+}
+impl<'__daft> ::daft::VisitDiff for StateDiff<'__daft>
+where
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::VisitDiff,
+    &'__daft State: ::core::fmt::Debug,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        match self {
+            Self::Running { 0: __field_0 } => {
+                let path = path.join(::daft::PathSegment::Variant("Running"));
+                ::daft::VisitDiff::visit_at(
+                    __field_0,
+                    &path.join(::daft::PathSegment::TupleIndex(0)),
+                    visitor,
+                );
+            }
+            Self::Stopped {} => {}
+            Self::VariantChanged(leaf) => {
+                ::daft::DiffVisitor::modified(visitor, path, &leaf.before, &leaf.after);
+            }
+        }
+    }
+}
+impl<'__daft> ::daft::Invert for StateDiff<'__daft>
+where
+    <leaf_diff::Diff<
+        '__daft,
+    > as ::daft::__private::CustomDiff<'__daft>>::Output: ::daft::Invert,
+    ::daft::Leaf<&'__daft State>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        match self {
+            Self::Running { 0: __field_0 } => {
+                Self::Running {
+                    0: ::daft::Invert::invert(__field_0),
+                }
+            }
+            Self::Stopped {} => Self::Stopped {},
+            Self::VariantChanged(leaf) => {
+                Self::VariantChanged(::daft::Invert::invert(leaf))
+            }
+        }
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for StateDiff < '__daft > where
+    < leaf_diff::Diff < '__daft > as ::daft::__private::CustomDiff < '__daft > > ::Output
+    : ::daft::__private::serde::Serialize, ::daft::Leaf < & '__daft State > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { match self { Self::Running { 0 : __field_0 }
+    => ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(StateDiff), 0, "Running", __field_0,), Self::Stopped {} =>
+    ::daft::__private::serde::Serializer::serialize_unit_variant(__serializer,
+    stringify!(StateDiff), 1, "Stopped",), Self::VariantChanged(leaf) => {
+    ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(StateDiff), 2, stringify!(VariantChanged), leaf,) } } } }
+}
+impl ::daft::Diffable for State {
+    type Diff<'__daft> = StateDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> StateDiff<'__daft> {
+        match (self, other) {
+            (
+                Self::Running { 0: __daft_before_0, .. },
+                Self::Running { 0: __daft_after_0, .. },
+            ) => {
+                StateDiff::Running {
+                    0: leaf_diff::diff(__daft_before_0, __daft_after_0),
+                }
+            }
+            (Self::Stopped { .. }, Self::Stopped { .. }) => StateDiff::Stopped {},
+            #[allow(unreachable_patterns)]
+            _ => {
+                StateDiff::VariantChanged(::daft::Leaf {
+                    before: self,
+                    after: other,
+                })
+            }
+        }
+    }
+}
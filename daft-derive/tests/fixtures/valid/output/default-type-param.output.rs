@@ -26,6 +26,89 @@ for StructWithDefaultTypeParamDiff<'__daft, T>
 where
     <T as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
 {}
+impl<'__daft, T: Diffable + '__daft> ::daft::DiffSummary
+for StructWithDefaultTypeParamDiff<'__daft, T>
+where
+    <T as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.field)
+    }
+}
+impl<'__daft, T: Diffable + '__daft> ::daft::VisitDiff
+for StructWithDefaultTypeParamDiff<'__daft, T>
+where
+    <T as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.field,
+            &path.join(::daft::PathSegment::Field("field")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft, T : Diffable + '__daft > ::daft::__private::serde::Serialize for
+    StructWithDefaultTypeParamDiff < '__daft, T > where < T as ::daft::Diffable > ::Diff
+    < '__daft > : ::daft::__private::serde::Serialize { fn serialize < __S > (& self,
+    __serializer : __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(StructWithDefaultTypeParamDiff), 1,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "field", & self.field,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft, T: Diffable + '__daft> ::daft::Invert
+for StructWithDefaultTypeParamDiff<'__daft, T>
+where
+    <T as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            field: ::daft::Invert::invert(self.field),
+        }
+    }
+}
+impl<'__daft, T: Diffable + '__daft> ::daft::Patch<StructWithDefaultTypeParam<T>>
+for StructWithDefaultTypeParamDiff<'__daft, T>
+where
+    <T as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<T>,
+{
+    fn apply(
+        &self,
+        target: &mut StructWithDefaultTypeParam<T>,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.field, &mut target.field)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft, T: Diffable + '__daft> ::daft::Merge<StructWithDefaultTypeParam<T>>
+for StructWithDefaultTypeParamDiff<'__daft, T>
+where
+    <T as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<T>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut StructWithDefaultTypeParam<T>,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.field,
+            &theirs.field,
+            &mut target.field,
+            &path.join(::daft::PathSegment::Field("field")),
+            conflict,
+        );
+    }
+}
 impl<T: Diffable> ::daft::Diffable for StructWithDefaultTypeParam<T> {
     type Diff<'__daft> = StructWithDefaultTypeParamDiff<'__daft, T> where Self: '__daft;
     fn diff<'__daft>(
@@ -0,0 +1,415 @@
+#[derive(Clone, Copy)]
+///Changes to a sled's configuration.
+#[must_use]
+pub(crate) struct SledConfigChanges<'__daft> {
+    generation: <u64 as ::daft::Diffable>::Diff<'__daft>,
+    serial: ::daft::Leaf<&'__daft &'static str>,
+}
+impl<'__daft> ::core::fmt::Debug for SledConfigChanges<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    ::daft::Leaf<&'__daft &'static str>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(SledConfigChanges))
+            .field(stringify!(generation), &self.generation)
+            .field(stringify!(serial), &self.serial)
+            .finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for SledConfigChanges<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    ::daft::Leaf<&'__daft &'static str>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.generation == other.generation && self.serial == other.serial
+    }
+}
+impl<'__daft> ::core::cmp::Eq for SledConfigChanges<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    ::daft::Leaf<&'__daft &'static str>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for SledConfigChanges<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    ::daft::Leaf<&'__daft &'static str>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.generation)
+            && ::daft::DiffSummary::is_unchanged(&self.serial)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for SledConfigChanges<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    ::daft::Leaf<&'__daft &'static str>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.generation,
+            &path.join(::daft::PathSegment::Field("generation")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.serial,
+            &path.join(::daft::PathSegment::Field("serial")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for SledConfigChanges < '__daft
+    > where < u64 as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, ::daft::Leaf < & '__daft & 'static str > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(SledConfigChanges), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "generation", & self.generation,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "serial", & self.serial,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for SledConfigChanges<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    ::daft::Leaf<&'__daft &'static str>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            generation: ::daft::Invert::invert(self.generation),
+            serial: ::daft::Invert::invert(self.serial),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<SledConfig> for SledConfigChanges<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<u64>,
+    ::daft::Leaf<&'__daft &'static str>: ::daft::Patch<&'static str>,
+{
+    fn apply(
+        &self,
+        target: &mut SledConfig,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.generation, &mut target.generation)?;
+        ::daft::Patch::apply(&self.serial, &mut target.serial)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<SledConfig> for SledConfigChanges<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<u64>,
+    ::daft::Leaf<&'__daft &'static str>: ::daft::Merge<&'static str>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut SledConfig,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.generation,
+            &theirs.generation,
+            &mut target.generation,
+            &path.join(::daft::PathSegment::Field("generation")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.serial,
+            &theirs.serial,
+            &mut target.serial,
+            &path.join(::daft::PathSegment::Field("serial")),
+            conflict,
+        );
+    }
+}
+impl ::daft::Diffable for SledConfig {
+    type Diff<'__daft> = SledConfigChanges<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> SledConfigChanges<'__daft> {
+        Self::Diff {
+            generation: ::daft::Diffable::diff(&self.generation, &other.generation),
+            serial: ::daft::Leaf {
+                before: &self.serial,
+                after: &other.serial,
+            },
+        }
+    }
+}
+pub(crate) struct SledConfigChangesOwned {
+    generation: <<u64 as ::daft::Diffable>::Diff<
+        'static,
+    > as ::daft::IntoOwnedDiff>::Owned,
+    serial: <::daft::Leaf<&'static &'static str> as ::daft::IntoOwnedDiff>::Owned,
+}
+impl ::core::fmt::Debug for SledConfigChangesOwned
+where
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+    for<'__daft> <::daft::Leaf<
+        &'__daft &'static str,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(SledConfigChangesOwned))
+            .field(stringify!(generation), &self.generation)
+            .field(stringify!(serial), &self.serial)
+            .finish()
+    }
+}
+impl ::core::clone::Clone for SledConfigChangesOwned
+where
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+    for<'__daft> <::daft::Leaf<
+        &'__daft &'static str,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            generation: ::core::clone::Clone::clone(&self.generation),
+            serial: ::core::clone::Clone::clone(&self.serial),
+        }
+    }
+}
+impl ::core::cmp::PartialEq for SledConfigChangesOwned
+where
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+    for<'__daft> <::daft::Leaf<
+        &'__daft &'static str,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.generation == other.generation && self.serial == other.serial
+    }
+}
+impl ::core::cmp::Eq for SledConfigChangesOwned
+where
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+    for<'__daft> <::daft::Leaf<
+        &'__daft &'static str,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+{}
+::daft::__private::serde_impl! {
+    impl ::daft::__private::serde::Serialize for SledConfigChangesOwned where for <
+    '__daft > < < u64 as ::daft::Diffable > ::Diff < '__daft > as ::daft::IntoOwnedDiff >
+    ::Owned : ::daft::__private::serde::Serialize, for < '__daft > < ::daft::Leaf < &
+    '__daft & 'static str > as ::daft::IntoOwnedDiff > ::Owned :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(SledConfigChangesOwned), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "generation", & self.generation,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "serial", & self.serial,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl ::daft::Invert for SledConfigChangesOwned
+where
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+    for<'__daft> <::daft::Leaf<
+        &'__daft &'static str,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            generation: ::daft::Invert::invert(self.generation),
+            serial: ::daft::Invert::invert(self.serial),
+        }
+    }
+}
+impl ::daft::Patch<SledConfig> for SledConfigChangesOwned
+where
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<u64>,
+    for<'__daft> <::daft::Leaf<
+        &'__daft &'static str,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<&'static str>,
+{
+    fn apply(
+        &self,
+        target: &mut SledConfig,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.generation, &mut target.generation)?;
+        ::daft::Patch::apply(&self.serial, &mut target.serial)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::IntoOwnedDiff for SledConfigChanges<'__daft> {
+    type Owned = SledConfigChangesOwned;
+    fn into_owned(self) -> Self::Owned {
+        SledConfigChangesOwned {
+            generation: ::daft::IntoOwnedDiff::into_owned(self.generation),
+            serial: ::daft::IntoOwnedDiff::into_owned(self.serial),
+        }
+    }
+}
+#[derive(Clone)]
+enum PhaseChanges<'__daft> {
+    Starting { attempt: <u32 as ::daft::Diffable>::Diff<'__daft> },
+    Running,
+    VariantChanged(::daft::Leaf<&'__daft Phase>),
+}
+impl<'__daft> ::core::fmt::Debug for PhaseChanges<'__daft>
+where
+    <u32 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    ::daft::Leaf<&'__daft Phase>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Starting { attempt: __field_0 } => {
+                f.debug_struct(stringify!(Starting))
+                    .field(stringify!(attempt), __field_0)
+                    .finish()
+            }
+            Self::Running {} => f.write_str(stringify!(Running)),
+            Self::VariantChanged(leaf) => {
+                f.debug_tuple(stringify!(VariantChanged)).field(leaf).finish()
+            }
+        }
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for PhaseChanges<'__daft>
+where
+    <u32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    ::daft::Leaf<&'__daft Phase>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Starting { attempt: __self_0 },
+                Self::Starting { attempt: __other_0 },
+            ) => __self_0 == __other_0,
+            (Self::Running {}, Self::Running {}) => true,
+            (Self::VariantChanged(self_leaf), Self::VariantChanged(other_leaf)) => {
+                self_leaf == other_leaf
+            }
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+impl<'__daft> ::core::cmp::Eq for PhaseChanges<'__daft>
+where
+    <u32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    ::daft::Leaf<&'__daft Phase>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for PhaseChanges<'__daft>
+where
+    <u32 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        match self {
+            Self::Starting { attempt: __field_0 } => {
+                ::daft::DiffSummary::is_unchanged(__field_0)
+            }
+            Self::Running {} => true,
+            Self::VariantChanged(_) => false,
+        }
+    }
+}
+impl<'__daft> ::daft::VisitDiff for PhaseChanges<'__daft>
+where
+    <u32 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    &'__daft Phase: ::core::fmt::Debug,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        match self {
+            Self::Starting { attempt: __field_0 } => {
+                let path = path.join(::daft::PathSegment::Variant("Starting"));
+                ::daft::VisitDiff::visit_at(
+                    __field_0,
+                    &path.join(::daft::PathSegment::Field("attempt")),
+                    visitor,
+                );
+            }
+            Self::Running {} => {}
+            Self::VariantChanged(leaf) => {
+                ::daft::DiffVisitor::modified(visitor, path, &leaf.before, &leaf.after);
+            }
+        }
+    }
+}
+impl<'__daft> ::daft::Invert for PhaseChanges<'__daft>
+where
+    <u32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    ::daft::Leaf<&'__daft Phase>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        match self {
+            Self::Starting { attempt: __field_0 } => {
+                Self::Starting {
+                    attempt: ::daft::Invert::invert(__field_0),
+                }
+            }
+            Self::Running {} => Self::Running {},
+            Self::VariantChanged(leaf) => {
+                Self::VariantChanged(::daft::Invert::invert(leaf))
+            }
+        }
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for PhaseChanges < '__daft >
+    where < u32 as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, ::daft::Leaf < & '__daft Phase > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { match self { Self::Starting { attempt :
+    __field_0 } => { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct_variant(__serializer,
+    stringify!(PhaseChanges), 0, "Starting", 1,) ?;
+    ::daft::__private::serde::ser::SerializeStructVariant::serialize_field(& mut __state,
+    "attempt", __field_0,) ?;
+    ::daft::__private::serde::ser::SerializeStructVariant::end(__state) }, Self::Running
+    {} => ::daft::__private::serde::Serializer::serialize_unit_variant(__serializer,
+    stringify!(PhaseChanges), 1, "Running",), Self::VariantChanged(leaf) => {
+    ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(PhaseChanges), 2, stringify!(VariantChanged), leaf,) } } } }
+}
+impl ::daft::Diffable for Phase {
+    type Diff<'__daft> = PhaseChanges<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> PhaseChanges<'__daft> {
+        match (self, other) {
+            (
+                Self::Starting { attempt: __daft_before_0, .. },
+                Self::Starting { attempt: __daft_after_0, .. },
+            ) => {
+                PhaseChanges::Starting {
+                    attempt: ::daft::Diffable::diff(__daft_before_0, __daft_after_0),
+                }
+            }
+            (Self::Running { .. }, Self::Running { .. }) => PhaseChanges::Running {},
+            #[allow(unreachable_patterns)]
+            _ => {
+                PhaseChanges::VariantChanged(::daft::Leaf {
+                    before: self,
+                    after: other,
+                })
+            }
+        }
+    }
+}
@@ -12,6 +12,52 @@ impl<'__daft> ::core::cmp::PartialEq for UnitStructDiff<'__daft> {
     }
 }
 impl<'__daft> ::core::cmp::Eq for UnitStructDiff<'__daft> {}
+impl<'__daft> ::daft::DiffSummary for UnitStructDiff<'__daft> {
+    fn is_unchanged(&self) -> bool {
+        true
+    }
+}
+impl<'__daft> ::daft::VisitDiff for UnitStructDiff<'__daft> {
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        let _ = (path, visitor);
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for UnitStructDiff < '__daft > {
+    fn serialize < __S > (& self, __serializer : __S,) -> ::core::result::Result <
+    __S::Ok, __S::Error > where __S : ::daft::__private::serde::Serializer, {
+    ::daft::__private::serde::Serializer::serialize_unit_struct(__serializer,
+    stringify!(UnitStructDiff),) } }
+}
+impl<'__daft> ::daft::Invert for UnitStructDiff<'__daft> {
+    fn invert(self) -> Self {
+        self
+    }
+}
+impl<'__daft> ::daft::Patch<UnitStruct> for UnitStructDiff<'__daft> {
+    fn apply(
+        &self,
+        target: &mut UnitStruct,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        let _ = target;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<UnitStruct> for UnitStructDiff<'__daft> {
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut UnitStruct,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        let _ = (theirs, target, path, conflict);
+    }
+}
 impl ::daft::Diffable for UnitStruct {
     type Diff<'__daft> = UnitStructDiff<'__daft> where Self: '__daft;
     fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> UnitStructDiff<'__daft> {
@@ -34,6 +80,53 @@ impl<'__daft> ::core::cmp::PartialEq for EmptyNamedDiff<'__daft> {
     }
 }
 impl<'__daft> ::core::cmp::Eq for EmptyNamedDiff<'__daft> {}
+impl<'__daft> ::daft::DiffSummary for EmptyNamedDiff<'__daft> {
+    fn is_unchanged(&self) -> bool {
+        true
+    }
+}
+impl<'__daft> ::daft::VisitDiff for EmptyNamedDiff<'__daft> {
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        let _ = (path, visitor);
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for EmptyNamedDiff < '__daft > {
+    fn serialize < __S > (& self, __serializer : __S,) -> ::core::result::Result <
+    __S::Ok, __S::Error > where __S : ::daft::__private::serde::Serializer, { let mut
+    __state = ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(EmptyNamedDiff), 0,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for EmptyNamedDiff<'__daft> {
+    fn invert(self) -> Self {
+        self
+    }
+}
+impl<'__daft> ::daft::Patch<EmptyNamed> for EmptyNamedDiff<'__daft> {
+    fn apply(
+        &self,
+        target: &mut EmptyNamed,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        let _ = target;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<EmptyNamed> for EmptyNamedDiff<'__daft> {
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut EmptyNamed,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        let _ = (theirs, target, path, conflict);
+    }
+}
 impl ::daft::Diffable for EmptyNamed {
     type Diff<'__daft> = EmptyNamedDiff<'__daft> where Self: '__daft;
     fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> EmptyNamedDiff<'__daft> {
@@ -54,6 +147,53 @@ impl<'__daft> ::core::cmp::PartialEq for EmptyTupleDiff<'__daft> {
     }
 }
 impl<'__daft> ::core::cmp::Eq for EmptyTupleDiff<'__daft> {}
+impl<'__daft> ::daft::DiffSummary for EmptyTupleDiff<'__daft> {
+    fn is_unchanged(&self) -> bool {
+        true
+    }
+}
+impl<'__daft> ::daft::VisitDiff for EmptyTupleDiff<'__daft> {
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        let _ = (path, visitor);
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for EmptyTupleDiff < '__daft > {
+    fn serialize < __S > (& self, __serializer : __S,) -> ::core::result::Result <
+    __S::Ok, __S::Error > where __S : ::daft::__private::serde::Serializer, { let mut
+    __state = ::daft::__private::serde::Serializer::serialize_tuple_struct(__serializer,
+    stringify!(EmptyTupleDiff), 0,) ?;
+    ::daft::__private::serde::ser::SerializeTupleStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for EmptyTupleDiff<'__daft> {
+    fn invert(self) -> Self {
+        self
+    }
+}
+impl<'__daft> ::daft::Patch<EmptyTuple> for EmptyTupleDiff<'__daft> {
+    fn apply(
+        &self,
+        target: &mut EmptyTuple,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        let _ = target;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<EmptyTuple> for EmptyTupleDiff<'__daft> {
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut EmptyTuple,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        let _ = (theirs, target, path, conflict);
+    }
+}
 impl ::daft::Diffable for EmptyTuple {
     type Diff<'__daft> = EmptyTupleDiff<'__daft> where Self: '__daft;
     fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> EmptyTupleDiff<'__daft> {
@@ -76,6 +216,54 @@ impl<'__daft> ::core::cmp::PartialEq for AllIgnoredNamedDiff<'__daft> {
     }
 }
 impl<'__daft> ::core::cmp::Eq for AllIgnoredNamedDiff<'__daft> {}
+impl<'__daft> ::daft::DiffSummary for AllIgnoredNamedDiff<'__daft> {
+    fn is_unchanged(&self) -> bool {
+        true
+    }
+}
+impl<'__daft> ::daft::VisitDiff for AllIgnoredNamedDiff<'__daft> {
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        let _ = (path, visitor);
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for AllIgnoredNamedDiff <
+    '__daft > { fn serialize < __S > (& self, __serializer : __S,) ->
+    ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(AllIgnoredNamedDiff), 0,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for AllIgnoredNamedDiff<'__daft> {
+    fn invert(self) -> Self {
+        self
+    }
+}
+impl<'__daft> ::daft::Patch<AllIgnoredNamed> for AllIgnoredNamedDiff<'__daft> {
+    fn apply(
+        &self,
+        target: &mut AllIgnoredNamed,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        let _ = target;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<AllIgnoredNamed> for AllIgnoredNamedDiff<'__daft> {
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut AllIgnoredNamed,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        let _ = (theirs, target, path, conflict);
+    }
+}
 impl ::daft::Diffable for AllIgnoredNamed {
     type Diff<'__daft> = AllIgnoredNamedDiff<'__daft> where Self: '__daft;
     fn diff<'__daft>(
@@ -101,6 +289,54 @@ impl<'__daft> ::core::cmp::PartialEq for AllIgnoredTupleDiff<'__daft> {
     }
 }
 impl<'__daft> ::core::cmp::Eq for AllIgnoredTupleDiff<'__daft> {}
+impl<'__daft> ::daft::DiffSummary for AllIgnoredTupleDiff<'__daft> {
+    fn is_unchanged(&self) -> bool {
+        true
+    }
+}
+impl<'__daft> ::daft::VisitDiff for AllIgnoredTupleDiff<'__daft> {
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        let _ = (path, visitor);
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for AllIgnoredTupleDiff <
+    '__daft > { fn serialize < __S > (& self, __serializer : __S,) ->
+    ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_tuple_struct(__serializer,
+    stringify!(AllIgnoredTupleDiff), 0,) ?;
+    ::daft::__private::serde::ser::SerializeTupleStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for AllIgnoredTupleDiff<'__daft> {
+    fn invert(self) -> Self {
+        self
+    }
+}
+impl<'__daft> ::daft::Patch<AllIgnoredTuple> for AllIgnoredTupleDiff<'__daft> {
+    fn apply(
+        &self,
+        target: &mut AllIgnoredTuple,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        let _ = target;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<AllIgnoredTuple> for AllIgnoredTupleDiff<'__daft> {
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut AllIgnoredTuple,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        let _ = (theirs, target, path, conflict);
+    }
+}
 impl ::daft::Diffable for AllIgnoredTuple {
     type Diff<'__daft> = AllIgnoredTupleDiff<'__daft> where Self: '__daft;
     fn diff<'__daft>(
@@ -126,6 +362,56 @@ impl<'__daft, T: '__daft> ::core::cmp::PartialEq for GenericAllIgnoredDiff<'__da
     }
 }
 impl<'__daft, T: '__daft> ::core::cmp::Eq for GenericAllIgnoredDiff<'__daft, T> {}
+impl<'__daft, T: '__daft> ::daft::DiffSummary for GenericAllIgnoredDiff<'__daft, T> {
+    fn is_unchanged(&self) -> bool {
+        true
+    }
+}
+impl<'__daft, T: '__daft> ::daft::VisitDiff for GenericAllIgnoredDiff<'__daft, T> {
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        let _ = (path, visitor);
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft, T : '__daft > ::daft::__private::serde::Serialize for
+    GenericAllIgnoredDiff < '__daft, T > { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(GenericAllIgnoredDiff), 0,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft, T: '__daft> ::daft::Invert for GenericAllIgnoredDiff<'__daft, T> {
+    fn invert(self) -> Self {
+        self
+    }
+}
+impl<'__daft, T: '__daft> ::daft::Patch<GenericAllIgnored<T>>
+for GenericAllIgnoredDiff<'__daft, T> {
+    fn apply(
+        &self,
+        target: &mut GenericAllIgnored<T>,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        let _ = target;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft, T: '__daft> ::daft::Merge<GenericAllIgnored<T>>
+for GenericAllIgnoredDiff<'__daft, T> {
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut GenericAllIgnored<T>,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        let _ = (theirs, target, path, conflict);
+    }
+}
 impl<T> ::daft::Diffable for GenericAllIgnored<T> {
     type Diff<'__daft> = GenericAllIgnoredDiff<'__daft, T> where Self: '__daft;
     fn diff<'__daft>(
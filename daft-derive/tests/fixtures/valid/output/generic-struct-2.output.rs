@@ -51,6 +51,173 @@ where
     <&'a U as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
     <&'a str as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
 {}
+impl<'__daft, 'a: '__daft, T: '__daft, U: '__daft> ::daft::DiffSummary
+for SDiff<'__daft, 'a, T, U>
+where
+    T: Diffable + Eq + 'a,
+    U: Diffable + 'a,
+    <BTreeMap<usize, T> as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <&'a U as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <&'a str as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.a)
+            && ::daft::DiffSummary::is_unchanged(&self.b)
+            && ::daft::DiffSummary::is_unchanged(&self.c)
+            && ::daft::DiffSummary::is_unchanged(&self.d)
+    }
+}
+impl<'__daft, 'a: '__daft, T: '__daft, U: '__daft> ::daft::VisitDiff
+for SDiff<'__daft, 'a, T, U>
+where
+    T: Diffable + Eq + 'a,
+    U: Diffable + 'a,
+    <BTreeMap<usize, T> as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <&'a U as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <&'a str as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.a,
+            &path.join(::daft::PathSegment::Field("a")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.b,
+            &path.join(::daft::PathSegment::Field("b")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.c,
+            &path.join(::daft::PathSegment::Field("c")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.d,
+            &path.join(::daft::PathSegment::Field("d")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft, 'a : '__daft, T : '__daft, U : '__daft >
+    ::daft::__private::serde::Serialize for SDiff < '__daft, 'a, T, U > where T :
+    Diffable + Eq + 'a, U : Diffable + 'a, < BTreeMap < usize, T > as ::daft::Diffable >
+    ::Diff < '__daft > : ::daft::__private::serde::Serialize, < usize as ::daft::Diffable
+    > ::Diff < '__daft > : ::daft::__private::serde::Serialize, < & 'a U as
+    ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize, < & 'a
+    str as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize {
+    fn serialize < __S > (& self, __serializer : __S,) -> ::core::result::Result <
+    __S::Ok, __S::Error > where __S : ::daft::__private::serde::Serializer, { let mut
+    __state = ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(SDiff), 4,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "a", &
+    self.a,) ?; ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "b", & self.b,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "c", &
+    self.c,) ?; ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "d", & self.d,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft, 'a: '__daft, T: '__daft, U: '__daft> ::daft::Invert
+for SDiff<'__daft, 'a, T, U>
+where
+    T: Diffable + Eq + 'a,
+    U: Diffable + 'a,
+    <BTreeMap<usize, T> as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <&'a U as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <&'a str as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            a: ::daft::Invert::invert(self.a),
+            b: ::daft::Invert::invert(self.b),
+            c: ::daft::Invert::invert(self.c),
+            d: ::daft::Invert::invert(self.d),
+        }
+    }
+}
+impl<'__daft, 'a: '__daft, T: '__daft, U: '__daft> ::daft::Patch<S<'a, T, U>>
+for SDiff<'__daft, 'a, T, U>
+where
+    T: Diffable + Eq + 'a,
+    U: Diffable + 'a,
+    <BTreeMap<
+        usize,
+        T,
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<BTreeMap<usize, T>>,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<usize>,
+    <&'a U as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<&'a U>,
+    <&'a str as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<&'a str>,
+{
+    fn apply(
+        &self,
+        target: &mut S<'a, T, U>,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.a, &mut target.a)?;
+        ::daft::Patch::apply(&self.b, &mut target.b)?;
+        ::daft::Patch::apply(&self.c, &mut target.c)?;
+        ::daft::Patch::apply(&self.d, &mut target.d)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft, 'a: '__daft, T: '__daft, U: '__daft> ::daft::Merge<S<'a, T, U>>
+for SDiff<'__daft, 'a, T, U>
+where
+    T: Diffable + Eq + 'a,
+    U: Diffable + 'a,
+    <BTreeMap<
+        usize,
+        T,
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<BTreeMap<usize, T>>,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<usize>,
+    <&'a U as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<&'a U>,
+    <&'a str as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<&'a str>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut S<'a, T, U>,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.a,
+            &theirs.a,
+            &mut target.a,
+            &path.join(::daft::PathSegment::Field("a")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.b,
+            &theirs.b,
+            &mut target.b,
+            &path.join(::daft::PathSegment::Field("b")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.c,
+            &theirs.c,
+            &mut target.c,
+            &path.join(::daft::PathSegment::Field("c")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.d,
+            &theirs.d,
+            &mut target.d,
+            &path.join(::daft::PathSegment::Field("d")),
+            conflict,
+        );
+    }
+}
 impl<'a, T, U> ::daft::Diffable for S<'a, T, U>
 where
     T: Diffable + Eq + 'a,
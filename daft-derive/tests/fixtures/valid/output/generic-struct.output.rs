@@ -46,6 +46,157 @@ where
     <&'d T as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
     <&'e U as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
 {}
+impl<'__daft, 'd: '__daft, 'e: '__daft, T: '__daft, U: '__daft> ::daft::DiffSummary
+for StructWithGenericsDiff<'__daft, 'd, 'e, T, U>
+where
+    T: daft::Diffable + 'd + ?Sized,
+    U: daft::Diffable + 'e + ?Sized,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <&'d T as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <&'e U as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.b)
+            && ::daft::DiffSummary::is_unchanged(&self.c)
+            && ::daft::DiffSummary::is_unchanged(&self.d)
+    }
+}
+impl<'__daft, 'd: '__daft, 'e: '__daft, T: '__daft, U: '__daft> ::daft::VisitDiff
+for StructWithGenericsDiff<'__daft, 'd, 'e, T, U>
+where
+    T: daft::Diffable + 'd + ?Sized,
+    U: daft::Diffable + 'e + ?Sized,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <&'d T as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <&'e U as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.b,
+            &path.join(::daft::PathSegment::Field("b")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.c,
+            &path.join(::daft::PathSegment::Field("c")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.d,
+            &path.join(::daft::PathSegment::Field("d")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft, 'd : '__daft, 'e : '__daft, T : '__daft, U : '__daft >
+    ::daft::__private::serde::Serialize for StructWithGenericsDiff < '__daft, 'd, 'e, T,
+    U > where T : daft::Diffable + 'd + ? Sized, U : daft::Diffable + 'e + ? Sized, <
+    usize as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize,
+    < & 'd T as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, < & 'e U as ::daft::Diffable > ::Diff < '__daft
+    > : ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer
+    : __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(StructWithGenericsDiff), 3,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "b", &
+    self.b,) ?; ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "c", & self.c,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "d", &
+    self.d,) ?; ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft, 'd: '__daft, 'e: '__daft, T: '__daft, U: '__daft> ::daft::Invert
+for StructWithGenericsDiff<'__daft, 'd, 'e, T, U>
+where
+    T: daft::Diffable + 'd + ?Sized,
+    U: daft::Diffable + 'e + ?Sized,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <&'d T as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <&'e U as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            b: ::daft::Invert::invert(self.b),
+            c: ::daft::Invert::invert(self.c),
+            d: ::daft::Invert::invert(self.d),
+        }
+    }
+}
+impl<
+    '__daft,
+    'd: '__daft,
+    'e: '__daft,
+    T: '__daft,
+    U: '__daft,
+> ::daft::Patch<StructWithGenerics<'d, 'e, T, U>>
+for StructWithGenericsDiff<'__daft, 'd, 'e, T, U>
+where
+    T: daft::Diffable + 'd + ?Sized,
+    U: daft::Diffable + 'e + ?Sized,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<usize>,
+    <&'d T as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<&'d T>,
+    <&'e U as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<&'e U>,
+{
+    fn apply(
+        &self,
+        target: &mut StructWithGenerics<'d, 'e, T, U>,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.b, &mut target.b)?;
+        ::daft::Patch::apply(&self.c, &mut target.c)?;
+        ::daft::Patch::apply(&self.d, &mut target.d)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<
+    '__daft,
+    'd: '__daft,
+    'e: '__daft,
+    T: '__daft,
+    U: '__daft,
+> ::daft::Merge<StructWithGenerics<'d, 'e, T, U>>
+for StructWithGenericsDiff<'__daft, 'd, 'e, T, U>
+where
+    T: daft::Diffable + 'd + ?Sized,
+    U: daft::Diffable + 'e + ?Sized,
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<usize>,
+    <&'d T as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<&'d T>,
+    <&'e U as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<&'e U>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut StructWithGenerics<'d, 'e, T, U>,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.b,
+            &theirs.b,
+            &mut target.b,
+            &path.join(::daft::PathSegment::Field("b")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.c,
+            &theirs.c,
+            &mut target.c,
+            &path.join(::daft::PathSegment::Field("c")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.d,
+            &theirs.d,
+            &mut target.d,
+            &path.join(::daft::PathSegment::Field("d")),
+            conflict,
+        );
+    }
+}
 impl<'d, 'e, T, U> ::daft::Diffable for StructWithGenerics<'d, 'e, T, U>
 where
     T: daft::Diffable + 'd + ?Sized,
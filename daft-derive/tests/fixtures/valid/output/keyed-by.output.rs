@@ -0,0 +1,388 @@
+struct ZpoolDiff<'__daft> {
+    id: <u64 as ::daft::Diffable>::Diff<'__daft>,
+    size: <u64 as ::daft::Diffable>::Diff<'__daft>,
+}
+impl<'__daft> ::core::fmt::Debug for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(ZpoolDiff))
+            .field(stringify!(id), &self.id)
+            .field(stringify!(size), &self.size)
+            .finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.size == other.size
+    }
+}
+impl<'__daft> ::core::cmp::Eq for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.id)
+            && ::daft::DiffSummary::is_unchanged(&self.size)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.id,
+            &path.join(::daft::PathSegment::Field("id")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.size,
+            &path.join(::daft::PathSegment::Field("size")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for ZpoolDiff < '__daft > where
+    < u64 as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize,
+    < u64 as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize
+    { fn serialize < __S > (& self, __serializer : __S,) -> ::core::result::Result <
+    __S::Ok, __S::Error > where __S : ::daft::__private::serde::Serializer, { let mut
+    __state = ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(ZpoolDiff), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "id",
+    & self.id,) ?; ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "size", & self.size,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            id: ::daft::Invert::invert(self.id),
+            size: ::daft::Invert::invert(self.size),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Zpool> for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<u64>,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<u64>,
+{
+    fn apply(
+        &self,
+        target: &mut Zpool,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.id, &mut target.id)?;
+        ::daft::Patch::apply(&self.size, &mut target.size)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Zpool> for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<u64>,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<u64>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Zpool,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.id,
+            &theirs.id,
+            &mut target.id,
+            &path.join(::daft::PathSegment::Field("id")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.size,
+            &theirs.size,
+            &mut target.size,
+            &path.join(::daft::PathSegment::Field("size")),
+            conflict,
+        );
+    }
+}
+impl ::daft::Diffable for Zpool {
+    type Diff<'__daft> = ZpoolDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> ZpoolDiff<'__daft> {
+        Self::Diff {
+            id: ::daft::Diffable::diff(&self.id, &other.id),
+            size: ::daft::Diffable::diff(&self.size, &other.size),
+        }
+    }
+}
+struct SledDiff<'__daft> {
+    zpools: ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >,
+    names: ::daft::KeyedDiff<
+        '__daft,
+        String,
+        <<&'__daft Vec<
+            (String, u32),
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >,
+}
+impl<'__daft> ::core::fmt::Debug for SledDiff<'__daft>
+where
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::core::fmt::Debug,
+    ::daft::KeyedDiff<
+        '__daft,
+        String,
+        <<&'__daft Vec<
+            (String, u32),
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(SledDiff))
+            .field(stringify!(zpools), &self.zpools)
+            .field(stringify!(names), &self.names)
+            .finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for SledDiff<'__daft>
+where
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::core::cmp::PartialEq,
+    ::daft::KeyedDiff<
+        '__daft,
+        String,
+        <<&'__daft Vec<
+            (String, u32),
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.zpools == other.zpools && self.names == other.names
+    }
+}
+impl<'__daft> ::core::cmp::Eq for SledDiff<'__daft>
+where
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::core::cmp::Eq,
+    ::daft::KeyedDiff<
+        '__daft,
+        String,
+        <<&'__daft Vec<
+            (String, u32),
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for SledDiff<'__daft>
+where
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::DiffSummary,
+    ::daft::KeyedDiff<
+        '__daft,
+        String,
+        <<&'__daft Vec<
+            (String, u32),
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.zpools)
+            && ::daft::DiffSummary::is_unchanged(&self.names)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for SledDiff<'__daft>
+where
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::VisitDiff,
+    ::daft::KeyedDiff<
+        '__daft,
+        String,
+        <<&'__daft Vec<
+            (String, u32),
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.zpools,
+            &path.join(::daft::PathSegment::Field("zpools")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.names,
+            &path.join(::daft::PathSegment::Field("names")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for SledDiff < '__daft > where
+    ::daft::KeyedDiff < '__daft, u64, < < & '__daft Vec < Zpool > as
+    ::core::iter::IntoIterator > ::Item as ::core::ops::Deref > ::Target, > :
+    ::daft::__private::serde::Serialize, ::daft::KeyedDiff < '__daft, String, < < &
+    '__daft Vec < (String, u32) > as ::core::iter::IntoIterator > ::Item as
+    ::core::ops::Deref > ::Target, > : ::daft::__private::serde::Serialize { fn serialize
+    < __S > (& self, __serializer : __S,) -> ::core::result::Result < __S::Ok, __S::Error
+    > where __S : ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(SledDiff), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "zpools", & self.zpools,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "names", & self.names,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for SledDiff<'__daft>
+where
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::Invert,
+    ::daft::KeyedDiff<
+        '__daft,
+        String,
+        <<&'__daft Vec<
+            (String, u32),
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            zpools: ::daft::Invert::invert(self.zpools),
+            names: ::daft::Invert::invert(self.names),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Sled> for SledDiff<'__daft>
+where
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::Patch<Vec<Zpool>>,
+    ::daft::KeyedDiff<
+        '__daft,
+        String,
+        <<&'__daft Vec<
+            (String, u32),
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::Patch<Vec<(String, u32)>>,
+{
+    fn apply(
+        &self,
+        target: &mut Sled,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.zpools, &mut target.zpools)?;
+        ::daft::Patch::apply(&self.names, &mut target.names)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Sled> for SledDiff<'__daft>
+where
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::Merge<Vec<Zpool>>,
+    ::daft::KeyedDiff<
+        '__daft,
+        String,
+        <<&'__daft Vec<
+            (String, u32),
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::Merge<Vec<(String, u32)>>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Sled,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.zpools,
+            &theirs.zpools,
+            &mut target.zpools,
+            &path.join(::daft::PathSegment::Field("zpools")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.names,
+            &theirs.names,
+            &mut target.names,
+            &path.join(::daft::PathSegment::Field("names")),
+            conflict,
+        );
+    }
+}
+impl ::daft::Diffable for Sled {
+    type Diff<'__daft> = SledDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> SledDiff<'__daft> {
+        Self::Diff {
+            zpools: ::daft::KeyedDiff::new(&self.zpools, &other.zpools, zpool_id),
+            names: ::daft::KeyedDiff::new(&self.names, &other.names, keys::name),
+        }
+    }
+}
@@ -0,0 +1,700 @@
+struct SledDiff<'__daft> {
+    serial: <String as ::daft::Diffable>::Diff<'__daft>,
+    generation: <u64 as ::daft::Diffable>::Diff<'__daft>,
+}
+impl<'__daft> ::core::fmt::Debug for SledDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(SledDiff))
+            .field(stringify!(serial), &self.serial)
+            .field(stringify!(generation), &self.generation)
+            .finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for SledDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.serial == other.serial && self.generation == other.generation
+    }
+}
+impl<'__daft> ::core::cmp::Eq for SledDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for SledDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.serial)
+            && ::daft::DiffSummary::is_unchanged(&self.generation)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for SledDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.serial,
+            &path.join(::daft::PathSegment::Field("serial")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.generation,
+            &path.join(::daft::PathSegment::Field("generation")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for SledDiff < '__daft > where <
+    String as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, < u64 as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(SledDiff), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "serial", & self.serial,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "generation", & self.generation,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for SledDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            serial: ::daft::Invert::invert(self.serial),
+            generation: ::daft::Invert::invert(self.generation),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Sled> for SledDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<String>,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<u64>,
+{
+    fn apply(
+        &self,
+        target: &mut Sled,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.serial, &mut target.serial)?;
+        ::daft::Patch::apply(&self.generation, &mut target.generation)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Sled> for SledDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<String>,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<u64>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Sled,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.serial,
+            &theirs.serial,
+            &mut target.serial,
+            &path.join(::daft::PathSegment::Field("serial")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.generation,
+            &theirs.generation,
+            &mut target.generation,
+            &path.join(::daft::PathSegment::Field("generation")),
+            conflict,
+        );
+    }
+}
+impl ::daft::Diffable for Sled {
+    type Diff<'__daft> = SledDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> SledDiff<'__daft> {
+        Self::Diff {
+            serial: ::daft::Diffable::diff(&self.serial, &other.serial),
+            generation: ::daft::Diffable::diff(&self.generation, &other.generation),
+        }
+    }
+}
+struct SledDiffOwned {
+    serial: <<String as ::daft::Diffable>::Diff<
+        'static,
+    > as ::daft::IntoOwnedDiff>::Owned,
+    generation: <<u64 as ::daft::Diffable>::Diff<
+        'static,
+    > as ::daft::IntoOwnedDiff>::Owned,
+}
+impl ::core::fmt::Debug for SledDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(SledDiffOwned))
+            .field(stringify!(serial), &self.serial)
+            .field(stringify!(generation), &self.generation)
+            .finish()
+    }
+}
+impl ::core::clone::Clone for SledDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            serial: ::core::clone::Clone::clone(&self.serial),
+            generation: ::core::clone::Clone::clone(&self.generation),
+        }
+    }
+}
+impl ::core::cmp::PartialEq for SledDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.serial == other.serial && self.generation == other.generation
+    }
+}
+impl ::core::cmp::Eq for SledDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+{}
+::daft::__private::serde_impl! {
+    impl ::daft::__private::serde::Serialize for SledDiffOwned where for < '__daft > < <
+    String as ::daft::Diffable > ::Diff < '__daft > as ::daft::IntoOwnedDiff > ::Owned :
+    ::daft::__private::serde::Serialize, for < '__daft > < < u64 as ::daft::Diffable >
+    ::Diff < '__daft > as ::daft::IntoOwnedDiff > ::Owned :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(SledDiffOwned), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "serial", & self.serial,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "generation", & self.generation,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl ::daft::Invert for SledDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            serial: ::daft::Invert::invert(self.serial),
+            generation: ::daft::Invert::invert(self.generation),
+        }
+    }
+}
+impl ::daft::Patch<Sled> for SledDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<String>,
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<u64>,
+{
+    fn apply(
+        &self,
+        target: &mut Sled,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.serial, &mut target.serial)?;
+        ::daft::Patch::apply(&self.generation, &mut target.generation)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::IntoOwnedDiff for SledDiff<'__daft> {
+    type Owned = SledDiffOwned;
+    fn into_owned(self) -> Self::Owned {
+        SledDiffOwned {
+            serial: ::daft::IntoOwnedDiff::into_owned(self.serial),
+            generation: ::daft::IntoOwnedDiff::into_owned(self.generation),
+        }
+    }
+}
+struct InventoryDiff<'__daft> {
+    sleds: <BTreeMap<u32, Sled> as ::daft::DiffableMap>::RecursiveDiff<'__daft>,
+    zones: <HashMap<String, u64> as ::daft::DiffableMap>::RecursiveDiff<'__daft>,
+    racks: <BTreeMap<u32, Sled> as ::daft::Diffable>::Diff<'__daft>,
+}
+impl<'__daft> ::core::fmt::Debug for InventoryDiff<'__daft>
+where
+    <BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<'__daft>: ::core::fmt::Debug,
+    <HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<'__daft>: ::core::fmt::Debug,
+    <BTreeMap<u32, Sled> as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(InventoryDiff))
+            .field(stringify!(sleds), &self.sleds)
+            .field(stringify!(zones), &self.zones)
+            .field(stringify!(racks), &self.racks)
+            .finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for InventoryDiff<'__daft>
+where
+    <BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<'__daft>: ::core::cmp::PartialEq,
+    <HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<'__daft>: ::core::cmp::PartialEq,
+    <BTreeMap<u32, Sled> as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.sleds == other.sleds && self.zones == other.zones
+            && self.racks == other.racks
+    }
+}
+impl<'__daft> ::core::cmp::Eq for InventoryDiff<'__daft>
+where
+    <BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<'__daft>: ::core::cmp::Eq,
+    <HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<'__daft>: ::core::cmp::Eq,
+    <BTreeMap<u32, Sled> as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for InventoryDiff<'__daft>
+where
+    <BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<'__daft>: ::daft::DiffSummary,
+    <HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<'__daft>: ::daft::DiffSummary,
+    <BTreeMap<u32, Sled> as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.sleds)
+            && ::daft::DiffSummary::is_unchanged(&self.zones)
+            && ::daft::DiffSummary::is_unchanged(&self.racks)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for InventoryDiff<'__daft>
+where
+    <BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<'__daft>: ::daft::VisitDiff,
+    <HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<'__daft>: ::daft::VisitDiff,
+    <BTreeMap<u32, Sled> as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.sleds,
+            &path.join(::daft::PathSegment::Field("sleds")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.zones,
+            &path.join(::daft::PathSegment::Field("zones")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.racks,
+            &path.join(::daft::PathSegment::Field("racks")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for InventoryDiff < '__daft >
+    where < BTreeMap < u32, Sled > as ::daft::DiffableMap > ::RecursiveDiff < '__daft > :
+    ::daft::__private::serde::Serialize, < HashMap < String, u64 > as ::daft::DiffableMap
+    > ::RecursiveDiff < '__daft > : ::daft::__private::serde::Serialize, < BTreeMap <
+    u32, Sled > as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(InventoryDiff), 3,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "sleds", & self.sleds,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "zones", & self.zones,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "racks", & self.racks,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for InventoryDiff<'__daft>
+where
+    <BTreeMap<u32, Sled> as ::daft::DiffableMap>::RecursiveDiff<'__daft>: ::daft::Invert,
+    <HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<'__daft>: ::daft::Invert,
+    <BTreeMap<u32, Sled> as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            sleds: ::daft::Invert::invert(self.sleds),
+            zones: ::daft::Invert::invert(self.zones),
+            racks: ::daft::Invert::invert(self.racks),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Inventory> for InventoryDiff<'__daft>
+where
+    <BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    >: ::daft::Patch<BTreeMap<u32, Sled>>,
+    <HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    >: ::daft::Patch<HashMap<String, u64>>,
+    <BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<BTreeMap<u32, Sled>>,
+{
+    fn apply(
+        &self,
+        target: &mut Inventory,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.sleds, &mut target.sleds)?;
+        ::daft::Patch::apply(&self.zones, &mut target.zones)?;
+        ::daft::Patch::apply(&self.racks, &mut target.racks)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Inventory> for InventoryDiff<'__daft>
+where
+    <BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    >: ::daft::Merge<BTreeMap<u32, Sled>>,
+    <HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    >: ::daft::Merge<HashMap<String, u64>>,
+    <BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<BTreeMap<u32, Sled>>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Inventory,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.sleds,
+            &theirs.sleds,
+            &mut target.sleds,
+            &path.join(::daft::PathSegment::Field("sleds")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.zones,
+            &theirs.zones,
+            &mut target.zones,
+            &path.join(::daft::PathSegment::Field("zones")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.racks,
+            &theirs.racks,
+            &mut target.racks,
+            &path.join(::daft::PathSegment::Field("racks")),
+            conflict,
+        );
+    }
+}
+impl ::daft::Diffable for Inventory {
+    type Diff<'__daft> = InventoryDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> InventoryDiff<'__daft> {
+        Self::Diff {
+            sleds: ::daft::DiffableMap::recursive_diff(&self.sleds, &other.sleds),
+            zones: ::daft::DiffableMap::recursive_diff(&self.zones, &other.zones),
+            racks: ::daft::Diffable::diff(&self.racks, &other.racks),
+        }
+    }
+}
+struct InventoryDiffOwned {
+    sleds: <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<'static> as ::daft::IntoOwnedDiff>::Owned,
+    zones: <<HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<'static> as ::daft::IntoOwnedDiff>::Owned,
+    racks: <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::Diffable>::Diff<'static> as ::daft::IntoOwnedDiff>::Owned,
+}
+impl ::core::fmt::Debug for InventoryDiffOwned
+where
+    for<'__daft> <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+    for<'__daft> <<HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+    for<'__daft> <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(InventoryDiffOwned))
+            .field(stringify!(sleds), &self.sleds)
+            .field(stringify!(zones), &self.zones)
+            .field(stringify!(racks), &self.racks)
+            .finish()
+    }
+}
+impl ::core::clone::Clone for InventoryDiffOwned
+where
+    for<'__daft> <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+    for<'__daft> <<HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+    for<'__daft> <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            sleds: ::core::clone::Clone::clone(&self.sleds),
+            zones: ::core::clone::Clone::clone(&self.zones),
+            racks: ::core::clone::Clone::clone(&self.racks),
+        }
+    }
+}
+impl ::core::cmp::PartialEq for InventoryDiffOwned
+where
+    for<'__daft> <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+    for<'__daft> <<HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+    for<'__daft> <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.sleds == other.sleds && self.zones == other.zones
+            && self.racks == other.racks
+    }
+}
+impl ::core::cmp::Eq for InventoryDiffOwned
+where
+    for<'__daft> <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+    for<'__daft> <<HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+    for<'__daft> <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+{}
+::daft::__private::serde_impl! {
+    impl ::daft::__private::serde::Serialize for InventoryDiffOwned where for < '__daft >
+    < < BTreeMap < u32, Sled > as ::daft::DiffableMap > ::RecursiveDiff < '__daft > as
+    ::daft::IntoOwnedDiff > ::Owned : ::daft::__private::serde::Serialize, for < '__daft
+    > < < HashMap < String, u64 > as ::daft::DiffableMap > ::RecursiveDiff < '__daft > as
+    ::daft::IntoOwnedDiff > ::Owned : ::daft::__private::serde::Serialize, for < '__daft
+    > < < BTreeMap < u32, Sled > as ::daft::Diffable > ::Diff < '__daft > as
+    ::daft::IntoOwnedDiff > ::Owned : ::daft::__private::serde::Serialize { fn serialize
+    < __S > (& self, __serializer : __S,) -> ::core::result::Result < __S::Ok, __S::Error
+    > where __S : ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(InventoryDiffOwned), 3,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "sleds", & self.sleds,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "zones", & self.zones,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "racks", & self.racks,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl ::daft::Invert for InventoryDiffOwned
+where
+    for<'__daft> <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+    for<'__daft> <<HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+    for<'__daft> <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            sleds: ::daft::Invert::invert(self.sleds),
+            zones: ::daft::Invert::invert(self.zones),
+            racks: ::daft::Invert::invert(self.racks),
+        }
+    }
+}
+impl ::daft::Patch<Inventory> for InventoryDiffOwned
+where
+    for<'__daft> <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<BTreeMap<u32, Sled>>,
+    for<'__daft> <<HashMap<
+        String,
+        u64,
+    > as ::daft::DiffableMap>::RecursiveDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<HashMap<String, u64>>,
+    for<'__daft> <<BTreeMap<
+        u32,
+        Sled,
+    > as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<BTreeMap<u32, Sled>>,
+{
+    fn apply(
+        &self,
+        target: &mut Inventory,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.sleds, &mut target.sleds)?;
+        ::daft::Patch::apply(&self.zones, &mut target.zones)?;
+        ::daft::Patch::apply(&self.racks, &mut target.racks)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::IntoOwnedDiff for InventoryDiff<'__daft> {
+    type Owned = InventoryDiffOwned;
+    fn into_owned(self) -> Self::Owned {
+        InventoryDiffOwned {
+            sleds: ::daft::IntoOwnedDiff::into_owned(self.sleds),
+            zones: ::daft::IntoOwnedDiff::into_owned(self.zones),
+            racks: ::daft::IntoOwnedDiff::into_owned(self.racks),
+        }
+    }
+}
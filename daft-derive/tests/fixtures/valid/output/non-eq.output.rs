@@ -28,6 +28,105 @@ where
     <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
     <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
 {}
+impl<'__daft> ::daft::DiffSummary for InnerDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.a)
+            && ::daft::DiffSummary::is_unchanged(&self.b)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for InnerDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.a,
+            &path.join(::daft::PathSegment::Field("a")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.b,
+            &path.join(::daft::PathSegment::Field("b")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for InnerDiff < '__daft > where
+    < i32 as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize,
+    < i32 as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize
+    { fn serialize < __S > (& self, __serializer : __S,) -> ::core::result::Result <
+    __S::Ok, __S::Error > where __S : ::daft::__private::serde::Serializer, { let mut
+    __state = ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(InnerDiff), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "a", &
+    self.a,) ?; ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "b", & self.b,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for InnerDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            a: ::daft::Invert::invert(self.a),
+            b: ::daft::Invert::invert(self.b),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Inner> for InnerDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<i32>,
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<i32>,
+{
+    fn apply(
+        &self,
+        target: &mut Inner,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.a, &mut target.a)?;
+        ::daft::Patch::apply(&self.b, &mut target.b)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Inner> for InnerDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<i32>,
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<i32>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Inner,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.a,
+            &theirs.a,
+            &mut target.a,
+            &path.join(::daft::PathSegment::Field("a")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.b,
+            &theirs.b,
+            &mut target.b,
+            &path.join(::daft::PathSegment::Field("b")),
+            conflict,
+        );
+    }
+}
 impl ::daft::Diffable for Inner {
     type Diff<'__daft> = InnerDiff<'__daft> where Self: '__daft;
     fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> InnerDiff<'__daft> {
@@ -67,6 +166,106 @@ where
     <Inner as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
     <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
 {}
+impl<'__daft> ::daft::DiffSummary for OuterDiff<'__daft>
+where
+    <Inner as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.inner)
+            && ::daft::DiffSummary::is_unchanged(&self.c)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for OuterDiff<'__daft>
+where
+    <Inner as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.inner,
+            &path.join(::daft::PathSegment::Field("inner")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.c,
+            &path.join(::daft::PathSegment::Field("c")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for OuterDiff < '__daft > where
+    < Inner as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, < i32 as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(OuterDiff), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "inner", & self.inner,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "c", &
+    self.c,) ?; ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for OuterDiff<'__daft>
+where
+    <Inner as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            inner: ::daft::Invert::invert(self.inner),
+            c: ::daft::Invert::invert(self.c),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Outer> for OuterDiff<'__daft>
+where
+    <Inner as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<Inner>,
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<i32>,
+{
+    fn apply(
+        &self,
+        target: &mut Outer,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.inner, &mut target.inner)?;
+        ::daft::Patch::apply(&self.c, &mut target.c)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Outer> for OuterDiff<'__daft>
+where
+    <Inner as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<Inner>,
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<i32>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Outer,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.inner,
+            &theirs.inner,
+            &mut target.inner,
+            &path.join(::daft::PathSegment::Field("inner")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.c,
+            &theirs.c,
+            &mut target.c,
+            &path.join(::daft::PathSegment::Field("c")),
+            conflict,
+        );
+    }
+}
 impl ::daft::Diffable for Outer {
     type Diff<'__daft> = OuterDiff<'__daft> where Self: '__daft;
     fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> OuterDiff<'__daft> {
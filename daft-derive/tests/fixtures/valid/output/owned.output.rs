@@ -0,0 +1,1160 @@
+struct ZpoolDiff<'__daft> {
+    id: <u64 as ::daft::Diffable>::Diff<'__daft>,
+    size: <u64 as ::daft::Diffable>::Diff<'__daft>,
+}
+impl<'__daft> ::core::fmt::Debug for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(ZpoolDiff))
+            .field(stringify!(id), &self.id)
+            .field(stringify!(size), &self.size)
+            .finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.size == other.size
+    }
+}
+impl<'__daft> ::core::cmp::Eq for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.id)
+            && ::daft::DiffSummary::is_unchanged(&self.size)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.id,
+            &path.join(::daft::PathSegment::Field("id")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.size,
+            &path.join(::daft::PathSegment::Field("size")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for ZpoolDiff < '__daft > where
+    < u64 as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize,
+    < u64 as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize
+    { fn serialize < __S > (& self, __serializer : __S,) -> ::core::result::Result <
+    __S::Ok, __S::Error > where __S : ::daft::__private::serde::Serializer, { let mut
+    __state = ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(ZpoolDiff), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "id",
+    & self.id,) ?; ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "size", & self.size,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            id: ::daft::Invert::invert(self.id),
+            size: ::daft::Invert::invert(self.size),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Zpool> for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<u64>,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<u64>,
+{
+    fn apply(
+        &self,
+        target: &mut Zpool,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.id, &mut target.id)?;
+        ::daft::Patch::apply(&self.size, &mut target.size)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Zpool> for ZpoolDiff<'__daft>
+where
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<u64>,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<u64>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Zpool,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.id,
+            &theirs.id,
+            &mut target.id,
+            &path.join(::daft::PathSegment::Field("id")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.size,
+            &theirs.size,
+            &mut target.size,
+            &path.join(::daft::PathSegment::Field("size")),
+            conflict,
+        );
+    }
+}
+impl ::daft::Diffable for Zpool {
+    type Diff<'__daft> = ZpoolDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> ZpoolDiff<'__daft> {
+        Self::Diff {
+            id: ::daft::Diffable::diff(&self.id, &other.id),
+            size: ::daft::Diffable::diff(&self.size, &other.size),
+        }
+    }
+}
+struct ZpoolDiffOwned {
+    id: <<u64 as ::daft::Diffable>::Diff<'static> as ::daft::IntoOwnedDiff>::Owned,
+    size: <<u64 as ::daft::Diffable>::Diff<'static> as ::daft::IntoOwnedDiff>::Owned,
+}
+impl ::core::fmt::Debug for ZpoolDiffOwned
+where
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(ZpoolDiffOwned))
+            .field(stringify!(id), &self.id)
+            .field(stringify!(size), &self.size)
+            .finish()
+    }
+}
+impl ::core::clone::Clone for ZpoolDiffOwned
+where
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            id: ::core::clone::Clone::clone(&self.id),
+            size: ::core::clone::Clone::clone(&self.size),
+        }
+    }
+}
+impl ::core::cmp::PartialEq for ZpoolDiffOwned
+where
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.size == other.size
+    }
+}
+impl ::core::cmp::Eq for ZpoolDiffOwned
+where
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+{}
+::daft::__private::serde_impl! {
+    impl ::daft::__private::serde::Serialize for ZpoolDiffOwned where for < '__daft > < <
+    u64 as ::daft::Diffable > ::Diff < '__daft > as ::daft::IntoOwnedDiff > ::Owned :
+    ::daft::__private::serde::Serialize, for < '__daft > < < u64 as ::daft::Diffable >
+    ::Diff < '__daft > as ::daft::IntoOwnedDiff > ::Owned :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(ZpoolDiffOwned), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state, "id",
+    & self.id,) ?; ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut
+    __state, "size", & self.size,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl ::daft::Invert for ZpoolDiffOwned
+where
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            id: ::daft::Invert::invert(self.id),
+            size: ::daft::Invert::invert(self.size),
+        }
+    }
+}
+impl ::daft::Patch<Zpool> for ZpoolDiffOwned
+where
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<u64>,
+    for<'__daft> <<u64 as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<u64>,
+{
+    fn apply(
+        &self,
+        target: &mut Zpool,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.id, &mut target.id)?;
+        ::daft::Patch::apply(&self.size, &mut target.size)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::IntoOwnedDiff for ZpoolDiff<'__daft> {
+    type Owned = ZpoolDiffOwned;
+    fn into_owned(self) -> Self::Owned {
+        ZpoolDiffOwned {
+            id: ::daft::IntoOwnedDiff::into_owned(self.id),
+            size: ::daft::IntoOwnedDiff::into_owned(self.size),
+        }
+    }
+}
+struct SledDiff<'__daft, T: Clone + Diffable + '__daft> {
+    name: <String as ::daft::Diffable>::Diff<'__daft>,
+    zpools: ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >,
+    boot: ::daft::Leaf<&'__daft Zpool>,
+    primary: <Zpool as ::daft::Diffable>::Diff<'__daft>,
+    extra: <BTreeMap<String, T> as ::daft::Diffable>::Diff<'__daft>,
+    value: ::daft::Leaf<&'__daft T>,
+}
+impl<'__daft, T: Clone + Diffable + '__daft> ::core::fmt::Debug for SledDiff<'__daft, T>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::core::fmt::Debug,
+    ::daft::Leaf<&'__daft Zpool>: ::core::fmt::Debug,
+    <Zpool as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    <BTreeMap<String, T> as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    ::daft::Leaf<&'__daft T>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(SledDiff))
+            .field(stringify!(name), &self.name)
+            .field(stringify!(zpools), &self.zpools)
+            .field(stringify!(boot), &self.boot)
+            .field(stringify!(primary), &self.primary)
+            .field(stringify!(extra), &self.extra)
+            .field(stringify!(value), &self.value)
+            .finish()
+    }
+}
+impl<'__daft, T: Clone + Diffable + '__daft> ::core::cmp::PartialEq
+for SledDiff<'__daft, T>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::core::cmp::PartialEq,
+    ::daft::Leaf<&'__daft Zpool>: ::core::cmp::PartialEq,
+    <Zpool as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    <BTreeMap<String, T> as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    ::daft::Leaf<&'__daft T>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.zpools == other.zpools && self.boot == other.boot
+            && self.primary == other.primary && self.extra == other.extra
+            && self.value == other.value
+    }
+}
+impl<'__daft, T: Clone + Diffable + '__daft> ::core::cmp::Eq for SledDiff<'__daft, T>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::core::cmp::Eq,
+    ::daft::Leaf<&'__daft Zpool>: ::core::cmp::Eq,
+    <Zpool as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    <BTreeMap<String, T> as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    ::daft::Leaf<&'__daft T>: ::core::cmp::Eq,
+{}
+impl<'__daft, T: Clone + Diffable + '__daft> ::daft::DiffSummary for SledDiff<'__daft, T>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::DiffSummary,
+    ::daft::Leaf<&'__daft Zpool>: ::daft::DiffSummary,
+    <Zpool as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <BTreeMap<String, T> as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    ::daft::Leaf<&'__daft T>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.name)
+            && ::daft::DiffSummary::is_unchanged(&self.zpools)
+            && ::daft::DiffSummary::is_unchanged(&self.boot)
+            && ::daft::DiffSummary::is_unchanged(&self.primary)
+            && ::daft::DiffSummary::is_unchanged(&self.extra)
+            && ::daft::DiffSummary::is_unchanged(&self.value)
+    }
+}
+impl<'__daft, T: Clone + Diffable + '__daft> ::daft::VisitDiff for SledDiff<'__daft, T>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::VisitDiff,
+    ::daft::Leaf<&'__daft Zpool>: ::daft::VisitDiff,
+    <Zpool as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <BTreeMap<String, T> as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    ::daft::Leaf<&'__daft T>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.name,
+            &path.join(::daft::PathSegment::Field("name")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.zpools,
+            &path.join(::daft::PathSegment::Field("zpools")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.boot,
+            &path.join(::daft::PathSegment::Field("boot")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.primary,
+            &path.join(::daft::PathSegment::Field("primary")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.extra,
+            &path.join(::daft::PathSegment::Field("extra")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.value,
+            &path.join(::daft::PathSegment::Field("value")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft, T : Clone + Diffable + '__daft > ::daft::__private::serde::Serialize
+    for SledDiff < '__daft, T > where < String as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, ::daft::KeyedDiff < '__daft, u64, < < & '__daft
+    Vec < Zpool > as ::core::iter::IntoIterator > ::Item as ::core::ops::Deref >
+    ::Target, > : ::daft::__private::serde::Serialize, ::daft::Leaf < & '__daft Zpool > :
+    ::daft::__private::serde::Serialize, < Zpool as ::daft::Diffable > ::Diff < '__daft >
+    : ::daft::__private::serde::Serialize, < BTreeMap < String, T > as ::daft::Diffable >
+    ::Diff < '__daft > : ::daft::__private::serde::Serialize, ::daft::Leaf < & '__daft T
+    > : ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer
+    : __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(SledDiff), 6,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "name", & self.name,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "zpools", & self.zpools,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "boot", & self.boot,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "primary", & self.primary,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "extra", & self.extra,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "value", & self.value,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft, T: Clone + Diffable + '__daft> ::daft::Invert for SledDiff<'__daft, T>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::Invert,
+    ::daft::Leaf<&'__daft Zpool>: ::daft::Invert,
+    <Zpool as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <BTreeMap<String, T> as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    ::daft::Leaf<&'__daft T>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            name: ::daft::Invert::invert(self.name),
+            zpools: ::daft::Invert::invert(self.zpools),
+            boot: ::daft::Invert::invert(self.boot),
+            primary: ::daft::Invert::invert(self.primary),
+            extra: ::daft::Invert::invert(self.extra),
+            value: ::daft::Invert::invert(self.value),
+        }
+    }
+}
+impl<'__daft, T: Clone + Diffable + '__daft> ::daft::Patch<Sled<T>>
+for SledDiff<'__daft, T>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<String>,
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::Patch<Vec<Zpool>>,
+    ::daft::Leaf<&'__daft Zpool>: ::daft::Patch<Zpool>,
+    <Zpool as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<Zpool>,
+    <BTreeMap<
+        String,
+        T,
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<BTreeMap<String, T>>,
+    ::daft::Leaf<&'__daft T>: ::daft::Patch<T>,
+{
+    fn apply(
+        &self,
+        target: &mut Sled<T>,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.name, &mut target.name)?;
+        ::daft::Patch::apply(&self.zpools, &mut target.zpools)?;
+        ::daft::Patch::apply(&self.boot, &mut target.boot)?;
+        ::daft::Patch::apply(&self.primary, &mut target.primary)?;
+        ::daft::Patch::apply(&self.extra, &mut target.extra)?;
+        ::daft::Patch::apply(&self.value, &mut target.value)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft, T: Clone + Diffable + '__daft> ::daft::Merge<Sled<T>>
+for SledDiff<'__daft, T>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<String>,
+    ::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    >: ::daft::Merge<Vec<Zpool>>,
+    ::daft::Leaf<&'__daft Zpool>: ::daft::Merge<Zpool>,
+    <Zpool as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<Zpool>,
+    <BTreeMap<
+        String,
+        T,
+    > as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<BTreeMap<String, T>>,
+    ::daft::Leaf<&'__daft T>: ::daft::Merge<T>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Sled<T>,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.name,
+            &theirs.name,
+            &mut target.name,
+            &path.join(::daft::PathSegment::Field("name")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.zpools,
+            &theirs.zpools,
+            &mut target.zpools,
+            &path.join(::daft::PathSegment::Field("zpools")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.boot,
+            &theirs.boot,
+            &mut target.boot,
+            &path.join(::daft::PathSegment::Field("boot")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.primary,
+            &theirs.primary,
+            &mut target.primary,
+            &path.join(::daft::PathSegment::Field("primary")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.extra,
+            &theirs.extra,
+            &mut target.extra,
+            &path.join(::daft::PathSegment::Field("extra")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.value,
+            &theirs.value,
+            &mut target.value,
+            &path.join(::daft::PathSegment::Field("value")),
+            conflict,
+        );
+    }
+}
+impl<T: Clone + Diffable> ::daft::Diffable for Sled<T> {
+    type Diff<'__daft> = SledDiff<'__daft, T> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> SledDiff<'__daft, T> {
+        Self::Diff {
+            name: ::daft::Diffable::diff(&self.name, &other.name),
+            zpools: ::daft::KeyedDiff::new(&self.zpools, &other.zpools, zpool_id),
+            boot: ::daft::Leaf {
+                before: &self.boot,
+                after: &other.boot,
+            },
+            primary: ::daft::Diffable::diff(&self.primary, &other.primary),
+            extra: ::daft::Diffable::diff(&self.extra, &other.extra),
+            value: ::daft::Leaf {
+                before: &self.value,
+                after: &other.value,
+            },
+        }
+    }
+}
+struct SledDiffOwned<T: Clone + Diffable + 'static> {
+    name: <<String as ::daft::Diffable>::Diff<'static> as ::daft::IntoOwnedDiff>::Owned,
+    zpools: <::daft::KeyedDiff<
+        'static,
+        u64,
+        <<&'static Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    > as ::daft::IntoOwnedDiff>::Owned,
+    boot: <::daft::Leaf<&'static Zpool> as ::daft::IntoOwnedDiff>::Owned,
+    primary: <<Zpool as ::daft::Diffable>::Diff<
+        'static,
+    > as ::daft::IntoOwnedDiff>::Owned,
+    extra: <<BTreeMap<
+        String,
+        T,
+    > as ::daft::Diffable>::Diff<'static> as ::daft::IntoOwnedDiff>::Owned,
+    value: <::daft::Leaf<&'static T> as ::daft::IntoOwnedDiff>::Owned,
+}
+impl<T: Clone + Diffable + 'static> ::core::fmt::Debug for SledDiffOwned<T>
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+    for<'__daft> <::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+    for<'__daft> <::daft::Leaf<
+        &'__daft Zpool,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+    for<'__daft> <<Zpool as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+    <<BTreeMap<
+        String,
+        T,
+    > as ::daft::Diffable>::Diff<
+        'static,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+    <::daft::Leaf<&'static T> as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(SledDiffOwned))
+            .field(stringify!(name), &self.name)
+            .field(stringify!(zpools), &self.zpools)
+            .field(stringify!(boot), &self.boot)
+            .field(stringify!(primary), &self.primary)
+            .field(stringify!(extra), &self.extra)
+            .field(stringify!(value), &self.value)
+            .finish()
+    }
+}
+impl<T: Clone + Diffable + 'static> ::core::clone::Clone for SledDiffOwned<T>
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+    for<'__daft> <::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+    for<'__daft> <::daft::Leaf<
+        &'__daft Zpool,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+    for<'__daft> <<Zpool as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+    <<BTreeMap<
+        String,
+        T,
+    > as ::daft::Diffable>::Diff<
+        'static,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+    <::daft::Leaf<&'static T> as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            name: ::core::clone::Clone::clone(&self.name),
+            zpools: ::core::clone::Clone::clone(&self.zpools),
+            boot: ::core::clone::Clone::clone(&self.boot),
+            primary: ::core::clone::Clone::clone(&self.primary),
+            extra: ::core::clone::Clone::clone(&self.extra),
+            value: ::core::clone::Clone::clone(&self.value),
+        }
+    }
+}
+impl<T: Clone + Diffable + 'static> ::core::cmp::PartialEq for SledDiffOwned<T>
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+    for<'__daft> <::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+    for<'__daft> <::daft::Leaf<
+        &'__daft Zpool,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+    for<'__daft> <<Zpool as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+    <<BTreeMap<
+        String,
+        T,
+    > as ::daft::Diffable>::Diff<
+        'static,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+    <::daft::Leaf<&'static T> as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.zpools == other.zpools && self.boot == other.boot
+            && self.primary == other.primary && self.extra == other.extra
+            && self.value == other.value
+    }
+}
+impl<T: Clone + Diffable + 'static> ::core::cmp::Eq for SledDiffOwned<T>
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+    for<'__daft> <::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+    for<'__daft> <::daft::Leaf<
+        &'__daft Zpool,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+    for<'__daft> <<Zpool as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+    <<BTreeMap<
+        String,
+        T,
+    > as ::daft::Diffable>::Diff<
+        'static,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+    <::daft::Leaf<&'static T> as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+{}
+::daft::__private::serde_impl! {
+    impl < T : Clone + Diffable + 'static > ::daft::__private::serde::Serialize for
+    SledDiffOwned < T > where for < '__daft > < < String as ::daft::Diffable > ::Diff <
+    '__daft > as ::daft::IntoOwnedDiff > ::Owned : ::daft::__private::serde::Serialize,
+    for < '__daft > < ::daft::KeyedDiff < '__daft, u64, < < & '__daft Vec < Zpool > as
+    ::core::iter::IntoIterator > ::Item as ::core::ops::Deref > ::Target, > as
+    ::daft::IntoOwnedDiff > ::Owned : ::daft::__private::serde::Serialize, for < '__daft
+    > < ::daft::Leaf < & '__daft Zpool > as ::daft::IntoOwnedDiff > ::Owned :
+    ::daft::__private::serde::Serialize, for < '__daft > < < Zpool as ::daft::Diffable >
+    ::Diff < '__daft > as ::daft::IntoOwnedDiff > ::Owned :
+    ::daft::__private::serde::Serialize, < < BTreeMap < String, T > as ::daft::Diffable >
+    ::Diff < 'static > as ::daft::IntoOwnedDiff > ::Owned :
+    ::daft::__private::serde::Serialize, < ::daft::Leaf < & 'static T > as
+    ::daft::IntoOwnedDiff > ::Owned : ::daft::__private::serde::Serialize { fn serialize
+    < __S > (& self, __serializer : __S,) -> ::core::result::Result < __S::Ok, __S::Error
+    > where __S : ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(SledDiffOwned), 6,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "name", & self.name,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "zpools", & self.zpools,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "boot", & self.boot,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "primary", & self.primary,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "extra", & self.extra,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "value", & self.value,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<T: Clone + Diffable + 'static> ::daft::Invert for SledDiffOwned<T>
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+    for<'__daft> <::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+    for<'__daft> <::daft::Leaf<
+        &'__daft Zpool,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+    for<'__daft> <<Zpool as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+    <<BTreeMap<
+        String,
+        T,
+    > as ::daft::Diffable>::Diff<
+        'static,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+    <::daft::Leaf<&'static T> as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            name: ::daft::Invert::invert(self.name),
+            zpools: ::daft::Invert::invert(self.zpools),
+            boot: ::daft::Invert::invert(self.boot),
+            primary: ::daft::Invert::invert(self.primary),
+            extra: ::daft::Invert::invert(self.extra),
+            value: ::daft::Invert::invert(self.value),
+        }
+    }
+}
+impl<T: Clone + Diffable + 'static> ::daft::Patch<Sled<T>> for SledDiffOwned<T>
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<String>,
+    for<'__daft> <::daft::KeyedDiff<
+        '__daft,
+        u64,
+        <<&'__daft Vec<
+            Zpool,
+        > as ::core::iter::IntoIterator>::Item as ::core::ops::Deref>::Target,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<Vec<Zpool>>,
+    for<'__daft> <::daft::Leaf<
+        &'__daft Zpool,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<Zpool>,
+    for<'__daft> <<Zpool as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<Zpool>,
+    <<BTreeMap<
+        String,
+        T,
+    > as ::daft::Diffable>::Diff<
+        'static,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<BTreeMap<String, T>>,
+    <::daft::Leaf<&'static T> as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<T>,
+{
+    fn apply(
+        &self,
+        target: &mut Sled<T>,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.name, &mut target.name)?;
+        ::daft::Patch::apply(&self.zpools, &mut target.zpools)?;
+        ::daft::Patch::apply(&self.boot, &mut target.boot)?;
+        ::daft::Patch::apply(&self.primary, &mut target.primary)?;
+        ::daft::Patch::apply(&self.extra, &mut target.extra)?;
+        ::daft::Patch::apply(&self.value, &mut target.value)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft, T: Clone + Diffable + '__daft + 'static> ::daft::IntoOwnedDiff
+for SledDiff<'__daft, T> {
+    type Owned = SledDiffOwned<T>;
+    fn into_owned(self) -> Self::Owned {
+        SledDiffOwned {
+            name: ::daft::IntoOwnedDiff::into_owned(self.name),
+            zpools: ::daft::IntoOwnedDiff::into_owned(self.zpools),
+            boot: ::daft::IntoOwnedDiff::into_owned(self.boot),
+            primary: ::daft::IntoOwnedDiff::into_owned(self.primary),
+            extra: ::daft::IntoOwnedDiff::into_owned(self.extra),
+            value: ::daft::IntoOwnedDiff::into_owned(self.value),
+        }
+    }
+}
+struct PairDiff<'__daft>(<String as ::daft::Diffable>::Diff<'__daft>);
+impl<'__daft> ::core::fmt::Debug for PairDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple(stringify!(PairDiff)).field(&self.0).finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for PairDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl<'__daft> ::core::cmp::Eq for PairDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for PairDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.0)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for PairDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.0,
+            &path.join(::daft::PathSegment::TupleIndex(1)),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for PairDiff < '__daft > where <
+    String as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize
+    { fn serialize < __S > (& self, __serializer : __S,) -> ::core::result::Result <
+    __S::Ok, __S::Error > where __S : ::daft::__private::serde::Serializer, {
+    ::daft::__private::serde::Serializer::serialize_newtype_struct(__serializer,
+    stringify!(PairDiff), & self.0,) } }
+}
+impl<'__daft> ::daft::Invert for PairDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            0: ::daft::Invert::invert(self.0),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Pair> for PairDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<String>,
+{
+    fn apply(
+        &self,
+        target: &mut Pair,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.0, &mut target.1)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Pair> for PairDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<String>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Pair,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.0,
+            &theirs.0,
+            &mut target.1,
+            &path.join(::daft::PathSegment::TupleIndex(1)),
+            conflict,
+        );
+    }
+}
+impl ::daft::Diffable for Pair {
+    type Diff<'__daft> = PairDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> PairDiff<'__daft> {
+        Self::Diff {
+            0: ::daft::Diffable::diff(&self.1, &other.1),
+        }
+    }
+}
+struct PairDiffOwned(
+    <<String as ::daft::Diffable>::Diff<'static> as ::daft::IntoOwnedDiff>::Owned,
+);
+impl ::core::fmt::Debug for PairDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple(stringify!(PairDiffOwned)).field(&self.0).finish()
+    }
+}
+impl ::core::clone::Clone for PairDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            0: ::core::clone::Clone::clone(&self.0),
+        }
+    }
+}
+impl ::core::cmp::PartialEq for PairDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for PairDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+{}
+::daft::__private::serde_impl! {
+    impl ::daft::__private::serde::Serialize for PairDiffOwned where for < '__daft > < <
+    String as ::daft::Diffable > ::Diff < '__daft > as ::daft::IntoOwnedDiff > ::Owned :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, {
+    ::daft::__private::serde::Serializer::serialize_newtype_struct(__serializer,
+    stringify!(PairDiffOwned), & self.0,) } }
+}
+impl ::daft::Invert for PairDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            0: ::daft::Invert::invert(self.0),
+        }
+    }
+}
+impl ::daft::Patch<Pair> for PairDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<String>,
+{
+    fn apply(
+        &self,
+        target: &mut Pair,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.0, &mut target.1)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::IntoOwnedDiff for PairDiff<'__daft> {
+    type Owned = PairDiffOwned;
+    fn into_owned(self) -> Self::Owned {
+        PairDiffOwned {
+            0: ::daft::IntoOwnedDiff::into_owned(self.0),
+        }
+    }
+}
+struct EmptyDiff<'__daft> {
+    _phantom: ::core::marker::PhantomData<fn() -> &'__daft Empty>,
+}
+impl<'__daft> ::core::fmt::Debug for EmptyDiff<'__daft> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(EmptyDiff)).finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for EmptyDiff<'__daft> {
+    fn eq(&self, other: &Self) -> bool {
+        true
+    }
+}
+impl<'__daft> ::core::cmp::Eq for EmptyDiff<'__daft> {}
+impl<'__daft> ::daft::DiffSummary for EmptyDiff<'__daft> {
+    fn is_unchanged(&self) -> bool {
+        true
+    }
+}
+impl<'__daft> ::daft::VisitDiff for EmptyDiff<'__daft> {
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        let _ = (path, visitor);
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for EmptyDiff < '__daft > { fn
+    serialize < __S > (& self, __serializer : __S,) -> ::core::result::Result < __S::Ok,
+    __S::Error > where __S : ::daft::__private::serde::Serializer, {
+    ::daft::__private::serde::Serializer::serialize_unit_struct(__serializer,
+    stringify!(EmptyDiff),) } }
+}
+impl<'__daft> ::daft::Invert for EmptyDiff<'__daft> {
+    fn invert(self) -> Self {
+        self
+    }
+}
+impl<'__daft> ::daft::Patch<Empty> for EmptyDiff<'__daft> {
+    fn apply(
+        &self,
+        target: &mut Empty,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        let _ = target;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Empty> for EmptyDiff<'__daft> {
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Empty,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        let _ = (theirs, target, path, conflict);
+    }
+}
+impl ::daft::Diffable for Empty {
+    type Diff<'__daft> = EmptyDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> EmptyDiff<'__daft> {
+        Self::Diff {
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+struct EmptyDiffOwned {
+    _phantom: ::core::marker::PhantomData<fn() -> Empty>,
+}
+impl ::core::fmt::Debug for EmptyDiffOwned {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(EmptyDiffOwned)).finish()
+    }
+}
+impl ::core::clone::Clone for EmptyDiffOwned {
+    fn clone(&self) -> Self {
+        Self {
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+impl ::core::cmp::PartialEq for EmptyDiffOwned {
+    fn eq(&self, other: &Self) -> bool {
+        true
+    }
+}
+impl ::core::cmp::Eq for EmptyDiffOwned {}
+::daft::__private::serde_impl! {
+    impl ::daft::__private::serde::Serialize for EmptyDiffOwned { fn serialize < __S > (&
+    self, __serializer : __S,) -> ::core::result::Result < __S::Ok, __S::Error > where
+    __S : ::daft::__private::serde::Serializer, {
+    ::daft::__private::serde::Serializer::serialize_unit_struct(__serializer,
+    stringify!(EmptyDiffOwned),) } }
+}
+impl ::daft::Invert for EmptyDiffOwned {
+    fn invert(self) -> Self {
+        self
+    }
+}
+impl ::daft::Patch<Empty> for EmptyDiffOwned {
+    fn apply(
+        &self,
+        target: &mut Empty,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        let _ = target;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::IntoOwnedDiff for EmptyDiff<'__daft> {
+    type Owned = EmptyDiffOwned;
+    fn into_owned(self) -> Self::Owned {
+        EmptyDiffOwned {
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
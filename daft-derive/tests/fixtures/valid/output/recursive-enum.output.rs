@@ -0,0 +1,627 @@
+struct ConfigDiff<'__daft> {
+    threads: <usize as ::daft::Diffable>::Diff<'__daft>,
+    verbose: <bool as ::daft::Diffable>::Diff<'__daft>,
+}
+impl<'__daft> ::core::fmt::Debug for ConfigDiff<'__daft>
+where
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    <bool as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(ConfigDiff))
+            .field(stringify!(threads), &self.threads)
+            .field(stringify!(verbose), &self.verbose)
+            .finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for ConfigDiff<'__daft>
+where
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    <bool as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.threads == other.threads && self.verbose == other.verbose
+    }
+}
+impl<'__daft> ::core::cmp::Eq for ConfigDiff<'__daft>
+where
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    <bool as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for ConfigDiff<'__daft>
+where
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <bool as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.threads)
+            && ::daft::DiffSummary::is_unchanged(&self.verbose)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for ConfigDiff<'__daft>
+where
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <bool as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.threads,
+            &path.join(::daft::PathSegment::Field("threads")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.verbose,
+            &path.join(::daft::PathSegment::Field("verbose")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for ConfigDiff < '__daft > where
+    < usize as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, < bool as ::daft::Diffable > ::Diff < '__daft >
+    : ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(ConfigDiff), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "threads", & self.threads,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "verbose", & self.verbose,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for ConfigDiff<'__daft>
+where
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <bool as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            threads: ::daft::Invert::invert(self.threads),
+            verbose: ::daft::Invert::invert(self.verbose),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Config> for ConfigDiff<'__daft>
+where
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<usize>,
+    <bool as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<bool>,
+{
+    fn apply(
+        &self,
+        target: &mut Config,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.threads, &mut target.threads)?;
+        ::daft::Patch::apply(&self.verbose, &mut target.verbose)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Config> for ConfigDiff<'__daft>
+where
+    <usize as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<usize>,
+    <bool as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<bool>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Config,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.threads,
+            &theirs.threads,
+            &mut target.threads,
+            &path.join(::daft::PathSegment::Field("threads")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.verbose,
+            &theirs.verbose,
+            &mut target.verbose,
+            &path.join(::daft::PathSegment::Field("verbose")),
+            conflict,
+        );
+    }
+}
+impl ::daft::Diffable for Config {
+    type Diff<'__daft> = ConfigDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> ConfigDiff<'__daft> {
+        Self::Diff {
+            threads: ::daft::Diffable::diff(&self.threads, &other.threads),
+            verbose: ::daft::Diffable::diff(&self.verbose, &other.verbose),
+        }
+    }
+}
+enum StateDiff<'__daft, 'a: '__daft, T: '__daft> {
+    Idle,
+    Running {
+        pid: <u32 as ::daft::Diffable>::Diff<'__daft>,
+        config: <Config as ::daft::Diffable>::Diff<'__daft>,
+    },
+    Failed(::daft::Leaf<&'__daft &'a T>),
+    Empty {},
+    VariantChanged(::daft::Leaf<&'__daft State<'a, T>>),
+}
+impl<'__daft, 'a: '__daft, T: '__daft> ::core::fmt::Debug for StateDiff<'__daft, 'a, T>
+where
+    <u32 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    <Config as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    ::daft::Leaf<&'__daft &'a T>: ::core::fmt::Debug,
+    ::daft::Leaf<&'__daft State<'a, T>>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Idle {} => f.write_str(stringify!(Idle)),
+            Self::Running { pid: __field_0, config: __field_1 } => {
+                f.debug_struct(stringify!(Running))
+                    .field(stringify!(pid), __field_0)
+                    .field(stringify!(config), __field_1)
+                    .finish()
+            }
+            Self::Failed { 0: __field_0 } => {
+                f.debug_tuple(stringify!(Failed)).field(__field_0).finish()
+            }
+            Self::Empty {} => f.debug_struct(stringify!(Empty)).finish(),
+            Self::VariantChanged(leaf) => {
+                f.debug_tuple(stringify!(VariantChanged)).field(leaf).finish()
+            }
+        }
+    }
+}
+impl<'__daft, 'a: '__daft, T: '__daft> ::core::cmp::PartialEq
+for StateDiff<'__daft, 'a, T>
+where
+    <u32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    <Config as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    ::daft::Leaf<&'__daft &'a T>: ::core::cmp::PartialEq,
+    ::daft::Leaf<&'__daft State<'a, T>>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Idle {}, Self::Idle {}) => true,
+            (
+                Self::Running { pid: __self_0, config: __self_1 },
+                Self::Running { pid: __other_0, config: __other_1 },
+            ) => __self_0 == __other_0 && __self_1 == __other_1,
+            (Self::Failed { 0: __self_0 }, Self::Failed { 0: __other_0 }) => {
+                __self_0 == __other_0
+            }
+            (Self::Empty {}, Self::Empty {}) => true,
+            (Self::VariantChanged(self_leaf), Self::VariantChanged(other_leaf)) => {
+                self_leaf == other_leaf
+            }
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+impl<'__daft, 'a: '__daft, T: '__daft> ::core::cmp::Eq for StateDiff<'__daft, 'a, T>
+where
+    <u32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    <Config as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    ::daft::Leaf<&'__daft &'a T>: ::core::cmp::Eq,
+    ::daft::Leaf<&'__daft State<'a, T>>: ::core::cmp::Eq,
+{}
+impl<'__daft, 'a: '__daft, T: '__daft> ::daft::DiffSummary for StateDiff<'__daft, 'a, T>
+where
+    <u32 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <Config as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    ::daft::Leaf<&'__daft &'a T>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        match self {
+            Self::Idle {} => true,
+            Self::Running { pid: __field_0, config: __field_1 } => {
+                ::daft::DiffSummary::is_unchanged(__field_0)
+                    && ::daft::DiffSummary::is_unchanged(__field_1)
+            }
+            Self::Failed { 0: __field_0 } => ::daft::DiffSummary::is_unchanged(__field_0),
+            Self::Empty {} => true,
+            Self::VariantChanged(_) => false,
+        }
+    }
+}
+impl<'__daft, 'a: '__daft, T: '__daft> ::daft::VisitDiff for StateDiff<'__daft, 'a, T>
+where
+    <u32 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <Config as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    ::daft::Leaf<&'__daft &'a T>: ::daft::VisitDiff,
+    &'__daft State<'a, T>: ::core::fmt::Debug,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        match self {
+            Self::Idle {} => {}
+            Self::Running { pid: __field_0, config: __field_1 } => {
+                let path = path.join(::daft::PathSegment::Variant("Running"));
+                ::daft::VisitDiff::visit_at(
+                    __field_0,
+                    &path.join(::daft::PathSegment::Field("pid")),
+                    visitor,
+                );
+                ::daft::VisitDiff::visit_at(
+                    __field_1,
+                    &path.join(::daft::PathSegment::Field("config")),
+                    visitor,
+                );
+            }
+            Self::Failed { 0: __field_0 } => {
+                let path = path.join(::daft::PathSegment::Variant("Failed"));
+                ::daft::VisitDiff::visit_at(
+                    __field_0,
+                    &path.join(::daft::PathSegment::TupleIndex(1)),
+                    visitor,
+                );
+            }
+            Self::Empty {} => {}
+            Self::VariantChanged(leaf) => {
+                ::daft::DiffVisitor::modified(visitor, path, &leaf.before, &leaf.after);
+            }
+        }
+    }
+}
+impl<'__daft, 'a: '__daft, T: '__daft> ::daft::Invert for StateDiff<'__daft, 'a, T>
+where
+    <u32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <Config as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    ::daft::Leaf<&'__daft &'a T>: ::daft::Invert,
+    ::daft::Leaf<&'__daft State<'a, T>>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        match self {
+            Self::Idle {} => Self::Idle {},
+            Self::Running { pid: __field_0, config: __field_1 } => {
+                Self::Running {
+                    pid: ::daft::Invert::invert(__field_0),
+                    config: ::daft::Invert::invert(__field_1),
+                }
+            }
+            Self::Failed { 0: __field_0 } => {
+                Self::Failed {
+                    0: ::daft::Invert::invert(__field_0),
+                }
+            }
+            Self::Empty {} => Self::Empty {},
+            Self::VariantChanged(leaf) => {
+                Self::VariantChanged(::daft::Invert::invert(leaf))
+            }
+        }
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft, 'a : '__daft, T : '__daft > ::daft::__private::serde::Serialize for
+    StateDiff < '__daft, 'a, T > where < u32 as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, < Config as ::daft::Diffable > ::Diff < '__daft
+    > : ::daft::__private::serde::Serialize, ::daft::Leaf < & '__daft & 'a T > :
+    ::daft::__private::serde::Serialize, ::daft::Leaf < & '__daft State < 'a, T > > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { match self { Self::Idle {} =>
+    ::daft::__private::serde::Serializer::serialize_unit_variant(__serializer,
+    stringify!(StateDiff), 0, "Idle",), Self::Running { pid : __field_0, config :
+    __field_1 } => { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct_variant(__serializer,
+    stringify!(StateDiff), 1, "Running", 2,) ?;
+    ::daft::__private::serde::ser::SerializeStructVariant::serialize_field(& mut __state,
+    "pid", __field_0,) ?;
+    ::daft::__private::serde::ser::SerializeStructVariant::serialize_field(& mut __state,
+    "config", __field_1,) ?;
+    ::daft::__private::serde::ser::SerializeStructVariant::end(__state) }, Self::Failed {
+    0 : __field_0 } =>
+    ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(StateDiff), 2, "Failed", __field_0,), Self::Empty {} => { let mut __state
+    = ::daft::__private::serde::Serializer::serialize_struct_variant(__serializer,
+    stringify!(StateDiff), 3, "Empty", 0,) ?;
+    ::daft::__private::serde::ser::SerializeStructVariant::end(__state) },
+    Self::VariantChanged(leaf) => {
+    ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(StateDiff), 4, stringify!(VariantChanged), leaf,) } } } }
+}
+impl<'a, T> ::daft::Diffable for State<'a, T> {
+    type Diff<'__daft> = StateDiff<'__daft, 'a, T> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> StateDiff<'__daft, 'a, T> {
+        match (self, other) {
+            (Self::Idle { .. }, Self::Idle { .. }) => StateDiff::Idle {},
+            (
+                Self::Running { pid: __daft_before_0, config: __daft_before_1, .. },
+                Self::Running { pid: __daft_after_0, config: __daft_after_1, .. },
+            ) => {
+                StateDiff::Running {
+                    pid: ::daft::Diffable::diff(__daft_before_0, __daft_after_0),
+                    config: ::daft::Diffable::diff(__daft_before_1, __daft_after_1),
+                }
+            }
+            (
+                Self::Failed { 1: __daft_before_0, .. },
+                Self::Failed { 1: __daft_after_0, .. },
+            ) => {
+                StateDiff::Failed {
+                    0: ::daft::Leaf {
+                        before: __daft_before_0,
+                        after: __daft_after_0,
+                    },
+                }
+            }
+            (Self::Empty { .. }, Self::Empty { .. }) => StateDiff::Empty {},
+            #[allow(unreachable_patterns)]
+            _ => {
+                StateDiff::VariantChanged(::daft::Leaf {
+                    before: self,
+                    after: other,
+                })
+            }
+        }
+    }
+}
+enum SingleDiff<'__daft> {
+    Only(<i32 as ::daft::Diffable>::Diff<'__daft>),
+    VariantChanged(::daft::Leaf<&'__daft Single>),
+}
+impl<'__daft> ::core::fmt::Debug for SingleDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    ::daft::Leaf<&'__daft Single>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Only { 0: __field_0 } => {
+                f.debug_tuple(stringify!(Only)).field(__field_0).finish()
+            }
+            Self::VariantChanged(leaf) => {
+                f.debug_tuple(stringify!(VariantChanged)).field(leaf).finish()
+            }
+        }
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for SingleDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    ::daft::Leaf<&'__daft Single>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Only { 0: __self_0 }, Self::Only { 0: __other_0 }) => {
+                __self_0 == __other_0
+            }
+            (Self::VariantChanged(self_leaf), Self::VariantChanged(other_leaf)) => {
+                self_leaf == other_leaf
+            }
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+impl<'__daft> ::core::cmp::Eq for SingleDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    ::daft::Leaf<&'__daft Single>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for SingleDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        match self {
+            Self::Only { 0: __field_0 } => ::daft::DiffSummary::is_unchanged(__field_0),
+            Self::VariantChanged(_) => false,
+        }
+    }
+}
+impl<'__daft> ::daft::VisitDiff for SingleDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    &'__daft Single: ::core::fmt::Debug,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        match self {
+            Self::Only { 0: __field_0 } => {
+                let path = path.join(::daft::PathSegment::Variant("Only"));
+                ::daft::VisitDiff::visit_at(
+                    __field_0,
+                    &path.join(::daft::PathSegment::TupleIndex(0)),
+                    visitor,
+                );
+            }
+            Self::VariantChanged(leaf) => {
+                ::daft::DiffVisitor::modified(visitor, path, &leaf.before, &leaf.after);
+            }
+        }
+    }
+}
+impl<'__daft> ::daft::Invert for SingleDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    ::daft::Leaf<&'__daft Single>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        match self {
+            Self::Only { 0: __field_0 } => {
+                Self::Only {
+                    0: ::daft::Invert::invert(__field_0),
+                }
+            }
+            Self::VariantChanged(leaf) => {
+                Self::VariantChanged(::daft::Invert::invert(leaf))
+            }
+        }
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for SingleDiff < '__daft > where
+    < i32 as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize,
+    ::daft::Leaf < & '__daft Single > : ::daft::__private::serde::Serialize { fn
+    serialize < __S > (& self, __serializer : __S,) -> ::core::result::Result < __S::Ok,
+    __S::Error > where __S : ::daft::__private::serde::Serializer, { match self {
+    Self::Only { 0 : __field_0 } =>
+    ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(SingleDiff), 0, "Only", __field_0,), Self::VariantChanged(leaf) => {
+    ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(SingleDiff), 1, stringify!(VariantChanged), leaf,) } } } }
+}
+impl ::daft::Diffable for Single {
+    type Diff<'__daft> = SingleDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> SingleDiff<'__daft> {
+        match (self, other) {
+            (
+                Self::Only { 0: __daft_before_0, .. },
+                Self::Only { 0: __daft_after_0, .. },
+            ) => {
+                SingleDiff::Only {
+                    0: ::daft::Diffable::diff(__daft_before_0, __daft_after_0),
+                }
+            }
+            #[allow(unreachable_patterns)]
+            _ => {
+                SingleDiff::VariantChanged(::daft::Leaf {
+                    before: self,
+                    after: other,
+                })
+            }
+        }
+    }
+}
+#[non_exhaustive]
+pub enum NonExhaustiveDiff<'__daft> {
+    A { x: <i32 as ::daft::Diffable>::Diff<'__daft> },
+    VariantChanged(::daft::Leaf<&'__daft NonExhaustive>),
+}
+impl<'__daft> ::core::fmt::Debug for NonExhaustiveDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    ::daft::Leaf<&'__daft NonExhaustive>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::A { x: __field_0 } => {
+                f.debug_struct(stringify!(A)).field(stringify!(x), __field_0).finish()
+            }
+            Self::VariantChanged(leaf) => {
+                f.debug_tuple(stringify!(VariantChanged)).field(leaf).finish()
+            }
+        }
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for NonExhaustiveDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    ::daft::Leaf<&'__daft NonExhaustive>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::A { x: __self_0 }, Self::A { x: __other_0 }) => __self_0 == __other_0,
+            (Self::VariantChanged(self_leaf), Self::VariantChanged(other_leaf)) => {
+                self_leaf == other_leaf
+            }
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+impl<'__daft> ::core::cmp::Eq for NonExhaustiveDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    ::daft::Leaf<&'__daft NonExhaustive>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for NonExhaustiveDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        match self {
+            Self::A { x: __field_0 } => ::daft::DiffSummary::is_unchanged(__field_0),
+            Self::VariantChanged(_) => false,
+        }
+    }
+}
+impl<'__daft> ::daft::VisitDiff for NonExhaustiveDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    &'__daft NonExhaustive: ::core::fmt::Debug,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        match self {
+            Self::A { x: __field_0 } => {
+                let path = path.join(::daft::PathSegment::Variant("A"));
+                ::daft::VisitDiff::visit_at(
+                    __field_0,
+                    &path.join(::daft::PathSegment::Field("x")),
+                    visitor,
+                );
+            }
+            Self::VariantChanged(leaf) => {
+                ::daft::DiffVisitor::modified(visitor, path, &leaf.before, &leaf.after);
+            }
+        }
+    }
+}
+impl<'__daft> ::daft::Invert for NonExhaustiveDiff<'__daft>
+where
+    <i32 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    ::daft::Leaf<&'__daft NonExhaustive>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        match self {
+            Self::A { x: __field_0 } => {
+                Self::A {
+                    x: ::daft::Invert::invert(__field_0),
+                }
+            }
+            Self::VariantChanged(leaf) => {
+                Self::VariantChanged(::daft::Invert::invert(leaf))
+            }
+        }
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for NonExhaustiveDiff < '__daft
+    > where < i32 as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, ::daft::Leaf < & '__daft NonExhaustive > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { match self { Self::A { x : __field_0 } => {
+    let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct_variant(__serializer,
+    stringify!(NonExhaustiveDiff), 0, "A", 1,) ?;
+    ::daft::__private::serde::ser::SerializeStructVariant::serialize_field(& mut __state,
+    "x", __field_0,) ?;
+    ::daft::__private::serde::ser::SerializeStructVariant::end(__state) },
+    Self::VariantChanged(leaf) => {
+    ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(NonExhaustiveDiff), 1, stringify!(VariantChanged), leaf,) } } } }
+}
+impl ::daft::Diffable for NonExhaustive {
+    type Diff<'__daft> = NonExhaustiveDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> NonExhaustiveDiff<'__daft> {
+        match (self, other) {
+            (Self::A { x: __daft_before_0, .. }, Self::A { x: __daft_after_0, .. }) => {
+                NonExhaustiveDiff::A {
+                    x: ::daft::Diffable::diff(__daft_before_0, __daft_after_0),
+                }
+            }
+            #[allow(unreachable_patterns)]
+            _ => {
+                NonExhaustiveDiff::VariantChanged(::daft::Leaf {
+                    before: self,
+                    after: other,
+                })
+            }
+        }
+    }
+}
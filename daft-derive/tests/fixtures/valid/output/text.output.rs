@@ -0,0 +1,404 @@
+struct ConfigDiff<'__daft> {
+    name: <String as ::daft::Diffable>::Diff<'__daft>,
+    template: ::daft::TextDiff<'__daft>,
+}
+impl<'__daft> ::core::fmt::Debug for ConfigDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    ::daft::TextDiff<'__daft>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(ConfigDiff))
+            .field(stringify!(name), &self.name)
+            .field(stringify!(template), &self.template)
+            .finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for ConfigDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    ::daft::TextDiff<'__daft>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.template == other.template
+    }
+}
+impl<'__daft> ::core::cmp::Eq for ConfigDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    ::daft::TextDiff<'__daft>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for ConfigDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    ::daft::TextDiff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        ::daft::DiffSummary::is_unchanged(&self.name)
+            && ::daft::DiffSummary::is_unchanged(&self.template)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for ConfigDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    ::daft::TextDiff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.name,
+            &path.join(::daft::PathSegment::Field("name")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.template,
+            &path.join(::daft::PathSegment::Field("template")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for ConfigDiff < '__daft > where
+    < String as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, ::daft::TextDiff < '__daft > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(ConfigDiff), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "name", & self.name,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "template", & self.template,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for ConfigDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    ::daft::TextDiff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            name: ::daft::Invert::invert(self.name),
+            template: ::daft::Invert::invert(self.template),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Config> for ConfigDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<String>,
+    ::daft::TextDiff<'__daft>: ::daft::Patch<String>,
+{
+    fn apply(
+        &self,
+        target: &mut Config,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.name, &mut target.name)?;
+        ::daft::Patch::apply(&self.template, &mut target.template)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Config> for ConfigDiff<'__daft>
+where
+    <String as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<String>,
+    ::daft::TextDiff<'__daft>: ::daft::Merge<String>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Config,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.name,
+            &theirs.name,
+            &mut target.name,
+            &path.join(::daft::PathSegment::Field("name")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.template,
+            &theirs.template,
+            &mut target.template,
+            &path.join(::daft::PathSegment::Field("template")),
+            conflict,
+        );
+    }
+}
+impl ::daft::Diffable for Config {
+    type Diff<'__daft> = ConfigDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> ConfigDiff<'__daft> {
+        Self::Diff {
+            name: ::daft::Diffable::diff(&self.name, &other.name),
+            template: ::daft::TextDiff::new(
+                ::core::convert::AsRef::<str>::as_ref(&self.template),
+                ::core::convert::AsRef::<str>::as_ref(&other.template),
+                ::daft::TextUnit::Line,
+            ),
+        }
+    }
+}
+struct ConfigDiffOwned {
+    name: <<String as ::daft::Diffable>::Diff<'static> as ::daft::IntoOwnedDiff>::Owned,
+    template: <::daft::TextDiff<'static> as ::daft::IntoOwnedDiff>::Owned,
+}
+impl ::core::fmt::Debug for ConfigDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+    for<'__daft> <::daft::TextDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(ConfigDiffOwned))
+            .field(stringify!(name), &self.name)
+            .field(stringify!(template), &self.template)
+            .finish()
+    }
+}
+impl ::core::clone::Clone for ConfigDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+    for<'__daft> <::daft::TextDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::clone::Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            name: ::core::clone::Clone::clone(&self.name),
+            template: ::core::clone::Clone::clone(&self.template),
+        }
+    }
+}
+impl ::core::cmp::PartialEq for ConfigDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+    for<'__daft> <::daft::TextDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.template == other.template
+    }
+}
+impl ::core::cmp::Eq for ConfigDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+    for<'__daft> <::daft::TextDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::core::cmp::Eq,
+{}
+::daft::__private::serde_impl! {
+    impl ::daft::__private::serde::Serialize for ConfigDiffOwned where for < '__daft > <
+    < String as ::daft::Diffable > ::Diff < '__daft > as ::daft::IntoOwnedDiff > ::Owned
+    : ::daft::__private::serde::Serialize, for < '__daft > < ::daft::TextDiff < '__daft >
+    as ::daft::IntoOwnedDiff > ::Owned : ::daft::__private::serde::Serialize { fn
+    serialize < __S > (& self, __serializer : __S,) -> ::core::result::Result < __S::Ok,
+    __S::Error > where __S : ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(ConfigDiffOwned), 2,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "name", & self.name,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "template", & self.template,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl ::daft::Invert for ConfigDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+    for<'__daft> <::daft::TextDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            name: ::daft::Invert::invert(self.name),
+            template: ::daft::Invert::invert(self.template),
+        }
+    }
+}
+impl ::daft::Patch<Config> for ConfigDiffOwned
+where
+    for<'__daft> <<String as ::daft::Diffable>::Diff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<String>,
+    for<'__daft> <::daft::TextDiff<
+        '__daft,
+    > as ::daft::IntoOwnedDiff>::Owned: ::daft::Patch<String>,
+{
+    fn apply(
+        &self,
+        target: &mut Config,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.name, &mut target.name)?;
+        ::daft::Patch::apply(&self.template, &mut target.template)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::IntoOwnedDiff for ConfigDiff<'__daft> {
+    type Owned = ConfigDiffOwned;
+    fn into_owned(self) -> Self::Owned {
+        ConfigDiffOwned {
+            name: ::daft::IntoOwnedDiff::into_owned(self.name),
+            template: ::daft::IntoOwnedDiff::into_owned(self.template),
+        }
+    }
+}
+enum DocumentDiff<'__daft, 'a: '__daft> {
+    Inline(::daft::TextDiff<'__daft>),
+    Empty,
+    VariantChanged(::daft::Leaf<&'__daft Document<'a>>),
+}
+impl<'__daft, 'a: '__daft> ::core::fmt::Debug for DocumentDiff<'__daft, 'a>
+where
+    ::daft::TextDiff<'__daft>: ::core::fmt::Debug,
+    ::daft::Leaf<&'__daft Document<'a>>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Inline { 0: __field_0 } => {
+                f.debug_tuple(stringify!(Inline)).field(__field_0).finish()
+            }
+            Self::Empty {} => f.write_str(stringify!(Empty)),
+            Self::VariantChanged(leaf) => {
+                f.debug_tuple(stringify!(VariantChanged)).field(leaf).finish()
+            }
+        }
+    }
+}
+impl<'__daft, 'a: '__daft> ::core::cmp::PartialEq for DocumentDiff<'__daft, 'a>
+where
+    ::daft::TextDiff<'__daft>: ::core::cmp::PartialEq,
+    ::daft::Leaf<&'__daft Document<'a>>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Inline { 0: __self_0 }, Self::Inline { 0: __other_0 }) => {
+                __self_0 == __other_0
+            }
+            (Self::Empty {}, Self::Empty {}) => true,
+            (Self::VariantChanged(self_leaf), Self::VariantChanged(other_leaf)) => {
+                self_leaf == other_leaf
+            }
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+impl<'__daft, 'a: '__daft> ::core::cmp::Eq for DocumentDiff<'__daft, 'a>
+where
+    ::daft::TextDiff<'__daft>: ::core::cmp::Eq,
+    ::daft::Leaf<&'__daft Document<'a>>: ::core::cmp::Eq,
+{}
+impl<'__daft, 'a: '__daft> ::daft::DiffSummary for DocumentDiff<'__daft, 'a>
+where
+    ::daft::TextDiff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        match self {
+            Self::Inline { 0: __field_0 } => ::daft::DiffSummary::is_unchanged(__field_0),
+            Self::Empty {} => true,
+            Self::VariantChanged(_) => false,
+        }
+    }
+}
+impl<'__daft, 'a: '__daft> ::daft::VisitDiff for DocumentDiff<'__daft, 'a>
+where
+    ::daft::TextDiff<'__daft>: ::daft::VisitDiff,
+    &'__daft Document<'a>: ::core::fmt::Debug,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        match self {
+            Self::Inline { 0: __field_0 } => {
+                let path = path.join(::daft::PathSegment::Variant("Inline"));
+                ::daft::VisitDiff::visit_at(
+                    __field_0,
+                    &path.join(::daft::PathSegment::TupleIndex(0)),
+                    visitor,
+                );
+            }
+            Self::Empty {} => {}
+            Self::VariantChanged(leaf) => {
+                ::daft::DiffVisitor::modified(visitor, path, &leaf.before, &leaf.after);
+            }
+        }
+    }
+}
+impl<'__daft, 'a: '__daft> ::daft::Invert for DocumentDiff<'__daft, 'a>
+where
+    ::daft::TextDiff<'__daft>: ::daft::Invert,
+    ::daft::Leaf<&'__daft Document<'a>>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        match self {
+            Self::Inline { 0: __field_0 } => {
+                Self::Inline {
+                    0: ::daft::Invert::invert(__field_0),
+                }
+            }
+            Self::Empty {} => Self::Empty {},
+            Self::VariantChanged(leaf) => {
+                Self::VariantChanged(::daft::Invert::invert(leaf))
+            }
+        }
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft, 'a : '__daft > ::daft::__private::serde::Serialize for DocumentDiff <
+    '__daft, 'a > where ::daft::TextDiff < '__daft > :
+    ::daft::__private::serde::Serialize, ::daft::Leaf < & '__daft Document < 'a > > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { match self { Self::Inline { 0 : __field_0 }
+    => ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(DocumentDiff), 0, "Inline", __field_0,), Self::Empty {} =>
+    ::daft::__private::serde::Serializer::serialize_unit_variant(__serializer,
+    stringify!(DocumentDiff), 1, "Empty",), Self::VariantChanged(leaf) => {
+    ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(DocumentDiff), 2, stringify!(VariantChanged), leaf,) } } } }
+}
+impl<'a> ::daft::Diffable for Document<'a> {
+    type Diff<'__daft> = DocumentDiff<'__daft, 'a> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> DocumentDiff<'__daft, 'a> {
+        match (self, other) {
+            (
+                Self::Inline { 0: __daft_before_0, .. },
+                Self::Inline { 0: __daft_after_0, .. },
+            ) => {
+                DocumentDiff::Inline {
+                    0: ::daft::TextDiff::new(
+                        ::core::convert::AsRef::<str>::as_ref(__daft_before_0),
+                        ::core::convert::AsRef::<str>::as_ref(__daft_after_0),
+                        ::daft::TextUnit::Line,
+                    ),
+                }
+            }
+            (Self::Empty { .. }, Self::Empty { .. }) => DocumentDiff::Empty {},
+            #[allow(unreachable_patterns)]
+            _ => {
+                DocumentDiff::VariantChanged(::daft::Leaf {
+                    before: self,
+                    after: other,
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,375 @@
+struct ReadingDiff<'__daft> {
+    temperature: <f64 as ::daft::Diffable>::Diff<'__daft>,
+    pressure: ::daft::Leaf<&'__daft f32>,
+    count: <u64 as ::daft::Diffable>::Diff<'__daft>,
+}
+impl<'__daft> ::core::fmt::Debug for ReadingDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    ::daft::Leaf<&'__daft f32>: ::core::fmt::Debug,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct(stringify!(ReadingDiff))
+            .field(stringify!(temperature), &self.temperature)
+            .field(stringify!(pressure), &self.pressure)
+            .field(stringify!(count), &self.count)
+            .finish()
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for ReadingDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    ::daft::Leaf<&'__daft f32>: ::core::cmp::PartialEq,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.temperature == other.temperature && self.pressure == other.pressure
+            && self.count == other.count
+    }
+}
+impl<'__daft> ::core::cmp::Eq for ReadingDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    ::daft::Leaf<&'__daft f32>: ::core::cmp::Eq,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for ReadingDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    ::daft::Leaf<&'__daft f32>: ::daft::DiffSummary,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        (&self.temperature).approx_eq(::daft::Tolerance::from(1e-6))
+            && (&self.pressure).approx_eq(::daft::Tolerance::from(Tolerance::Ulps(4)))
+            && ::daft::DiffSummary::is_unchanged(&self.count)
+    }
+}
+impl<'__daft> ::daft::VisitDiff for ReadingDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    ::daft::Leaf<&'__daft f32>: ::daft::VisitDiff,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        ::daft::VisitDiff::visit_at(
+            &self.temperature,
+            &path.join(::daft::PathSegment::Field("temperature")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.pressure,
+            &path.join(::daft::PathSegment::Field("pressure")),
+            visitor,
+        );
+        ::daft::VisitDiff::visit_at(
+            &self.count,
+            &path.join(::daft::PathSegment::Field("count")),
+            visitor,
+        );
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for ReadingDiff < '__daft >
+    where < f64 as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize, ::daft::Leaf < & '__daft f32 > :
+    ::daft::__private::serde::Serialize, < u64 as ::daft::Diffable > ::Diff < '__daft > :
+    ::daft::__private::serde::Serialize { fn serialize < __S > (& self, __serializer :
+    __S,) -> ::core::result::Result < __S::Ok, __S::Error > where __S :
+    ::daft::__private::serde::Serializer, { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct(__serializer,
+    stringify!(ReadingDiff), 3,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "temperature", & self.temperature,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "pressure", & self.pressure,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::serialize_field(& mut __state,
+    "count", & self.count,) ?;
+    ::daft::__private::serde::ser::SerializeStruct::end(__state) } }
+}
+impl<'__daft> ::daft::Invert for ReadingDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    ::daft::Leaf<&'__daft f32>: ::daft::Invert,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        Self {
+            temperature: ::daft::Invert::invert(self.temperature),
+            pressure: ::daft::Invert::invert(self.pressure),
+            count: ::daft::Invert::invert(self.count),
+        }
+    }
+}
+impl<'__daft> ::daft::Patch<Reading> for ReadingDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<f64>,
+    ::daft::Leaf<&'__daft f32>: ::daft::Patch<f32>,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Patch<u64>,
+{
+    fn apply(
+        &self,
+        target: &mut Reading,
+    ) -> ::core::result::Result<(), ::daft::PatchError> {
+        ::daft::Patch::apply(&self.temperature, &mut target.temperature)?;
+        ::daft::Patch::apply(&self.pressure, &mut target.pressure)?;
+        ::daft::Patch::apply(&self.count, &mut target.count)?;
+        ::core::result::Result::Ok(())
+    }
+}
+impl<'__daft> ::daft::Merge<Reading> for ReadingDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<f64>,
+    ::daft::Leaf<&'__daft f32>: ::daft::Merge<f32>,
+    <u64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Merge<u64>,
+{
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Reading,
+        path: &::daft::DiffPath<'_>,
+        conflict: &mut dyn FnMut(&::daft::DiffPath<'_>, ::daft::MergeConflictKind),
+    ) {
+        ::daft::Merge::merge_at(
+            &self.temperature,
+            &theirs.temperature,
+            &mut target.temperature,
+            &path.join(::daft::PathSegment::Field("temperature")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.pressure,
+            &theirs.pressure,
+            &mut target.pressure,
+            &path.join(::daft::PathSegment::Field("pressure")),
+            conflict,
+        );
+        ::daft::Merge::merge_at(
+            &self.count,
+            &theirs.count,
+            &mut target.count,
+            &path.join(::daft::PathSegment::Field("count")),
+            conflict,
+        );
+    }
+}
+impl ::daft::Diffable for Reading {
+    type Diff<'__daft> = ReadingDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> ReadingDiff<'__daft> {
+        Self::Diff {
+            temperature: ::daft::Diffable::diff(&self.temperature, &other.temperature),
+            pressure: ::daft::Leaf {
+                before: &self.pressure,
+                after: &other.pressure,
+            },
+            count: ::daft::Diffable::diff(&self.count, &other.count),
+        }
+    }
+}
+enum SampleDiff<'__daft> {
+    Scalar(<f64 as ::daft::Diffable>::Diff<'__daft>),
+    Pair {
+        x: <f64 as ::daft::Diffable>::Diff<'__daft>,
+        y: <f64 as ::daft::Diffable>::Diff<'__daft>,
+    },
+    VariantChanged(::daft::Leaf<&'__daft Sample>),
+}
+impl<'__daft> ::core::fmt::Debug for SampleDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::core::fmt::Debug,
+    ::daft::Leaf<&'__daft Sample>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Scalar { 0: __field_0 } => {
+                f.debug_tuple(stringify!(Scalar)).field(__field_0).finish()
+            }
+            Self::Pair { x: __field_0, y: __field_1 } => {
+                f.debug_struct(stringify!(Pair))
+                    .field(stringify!(x), __field_0)
+                    .field(stringify!(y), __field_1)
+                    .finish()
+            }
+            Self::VariantChanged(leaf) => {
+                f.debug_tuple(stringify!(VariantChanged)).field(leaf).finish()
+            }
+        }
+    }
+}
+impl<'__daft> ::core::cmp::PartialEq for SampleDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::PartialEq,
+    ::daft::Leaf<&'__daft Sample>: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Scalar { 0: __self_0 }, Self::Scalar { 0: __other_0 }) => {
+                __self_0 == __other_0
+            }
+            (
+                Self::Pair { x: __self_0, y: __self_1 },
+                Self::Pair { x: __other_0, y: __other_1 },
+            ) => __self_0 == __other_0 && __self_1 == __other_1,
+            (Self::VariantChanged(self_leaf), Self::VariantChanged(other_leaf)) => {
+                self_leaf == other_leaf
+            }
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+impl<'__daft> ::core::cmp::Eq for SampleDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::core::cmp::Eq,
+    ::daft::Leaf<&'__daft Sample>: ::core::cmp::Eq,
+{}
+impl<'__daft> ::daft::DiffSummary for SampleDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        match self {
+            Self::Scalar { 0: __field_0 } => {
+                (__field_0).approx_eq(::daft::Tolerance::from(Tolerance::Relative(1e-3)))
+            }
+            Self::Pair { x: __field_0, y: __field_1 } => {
+                ::daft::DiffSummary::is_unchanged(__field_0)
+                    && ::daft::DiffSummary::is_unchanged(__field_1)
+            }
+            Self::VariantChanged(_) => false,
+        }
+    }
+}
+impl<'__daft> ::daft::VisitDiff for SampleDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::VisitDiff,
+    &'__daft Sample: ::core::fmt::Debug,
+{
+    fn visit_at(
+        &self,
+        path: &::daft::DiffPath<'_>,
+        visitor: &mut dyn ::daft::DiffVisitor,
+    ) {
+        match self {
+            Self::Scalar { 0: __field_0 } => {
+                let path = path.join(::daft::PathSegment::Variant("Scalar"));
+                ::daft::VisitDiff::visit_at(
+                    __field_0,
+                    &path.join(::daft::PathSegment::TupleIndex(0)),
+                    visitor,
+                );
+            }
+            Self::Pair { x: __field_0, y: __field_1 } => {
+                let path = path.join(::daft::PathSegment::Variant("Pair"));
+                ::daft::VisitDiff::visit_at(
+                    __field_0,
+                    &path.join(::daft::PathSegment::Field("x")),
+                    visitor,
+                );
+                ::daft::VisitDiff::visit_at(
+                    __field_1,
+                    &path.join(::daft::PathSegment::Field("y")),
+                    visitor,
+                );
+            }
+            Self::VariantChanged(leaf) => {
+                ::daft::DiffVisitor::modified(visitor, path, &leaf.before, &leaf.after);
+            }
+        }
+    }
+}
+impl<'__daft> ::daft::Invert for SampleDiff<'__daft>
+where
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    <f64 as ::daft::Diffable>::Diff<'__daft>: ::daft::Invert,
+    ::daft::Leaf<&'__daft Sample>: ::daft::Invert,
+{
+    fn invert(self) -> Self {
+        match self {
+            Self::Scalar { 0: __field_0 } => {
+                Self::Scalar {
+                    0: ::daft::Invert::invert(__field_0),
+                }
+            }
+            Self::Pair { x: __field_0, y: __field_1 } => {
+                Self::Pair {
+                    x: ::daft::Invert::invert(__field_0),
+                    y: ::daft::Invert::invert(__field_1),
+                }
+            }
+            Self::VariantChanged(leaf) => {
+                Self::VariantChanged(::daft::Invert::invert(leaf))
+            }
+        }
+    }
+}
+::daft::__private::serde_impl! {
+    impl < '__daft > ::daft::__private::serde::Serialize for SampleDiff < '__daft > where
+    < f64 as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize,
+    < f64 as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize,
+    < f64 as ::daft::Diffable > ::Diff < '__daft > : ::daft::__private::serde::Serialize,
+    ::daft::Leaf < & '__daft Sample > : ::daft::__private::serde::Serialize { fn
+    serialize < __S > (& self, __serializer : __S,) -> ::core::result::Result < __S::Ok,
+    __S::Error > where __S : ::daft::__private::serde::Serializer, { match self {
+    Self::Scalar { 0 : __field_0 } =>
+    ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(SampleDiff), 0, "Scalar", __field_0,), Self::Pair { x : __field_0, y :
+    __field_1 } => { let mut __state =
+    ::daft::__private::serde::Serializer::serialize_struct_variant(__serializer,
+    stringify!(SampleDiff), 1, "Pair", 2,) ?;
+    ::daft::__private::serde::ser::SerializeStructVariant::serialize_field(& mut __state,
+    "x", __field_0,) ?;
+    ::daft::__private::serde::ser::SerializeStructVariant::serialize_field(& mut __state,
+    "y", __field_1,) ?;
+    ::daft::__private::serde::ser::SerializeStructVariant::end(__state) },
+    Self::VariantChanged(leaf) => {
+    ::daft::__private::serde::Serializer::serialize_newtype_variant(__serializer,
+    stringify!(SampleDiff), 2, stringify!(VariantChanged), leaf,) } } } }
+}
+impl ::daft::Diffable for Sample {
+    type Diff<'__daft> = SampleDiff<'__daft> where Self: '__daft;
+    fn diff<'__daft>(&'__daft self, other: &'__daft Self) -> SampleDiff<'__daft> {
+        match (self, other) {
+            (
+                Self::Scalar { 0: __daft_before_0, .. },
+                Self::Scalar { 0: __daft_after_0, .. },
+            ) => {
+                SampleDiff::Scalar {
+                    0: ::daft::Diffable::diff(__daft_before_0, __daft_after_0),
+                }
+            }
+            (
+                Self::Pair { x: __daft_before_0, y: __daft_before_1, .. },
+                Self::Pair { x: __daft_after_0, y: __daft_after_1, .. },
+            ) => {
+                SampleDiff::Pair {
+                    x: ::daft::Diffable::diff(__daft_before_0, __daft_after_0),
+                    y: ::daft::Diffable::diff(__daft_before_1, __daft_after_1),
+                }
+            }
+            #[allow(unreachable_patterns)]
+            _ => {
+                SampleDiff::VariantChanged(::daft::Leaf {
+                    before: self,
+                    after: other,
+                })
+            }
+        }
+    }
+}
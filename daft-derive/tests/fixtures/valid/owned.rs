@@ -0,0 +1,77 @@
+use daft::{Diffable, IntoOwnedDiff, Leaf};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+#[daft(owned)]
+struct Zpool {
+    id: u64,
+    size: u64,
+}
+
+fn zpool_id(zpool: &Zpool) -> &u64 {
+    &zpool.id
+}
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+#[daft(owned)]
+struct Sled<T: Clone + Diffable> {
+    name: String,
+    #[daft(keyed_by = zpool_id, key = u64)]
+    zpools: Vec<Zpool>,
+    #[daft(leaf)]
+    boot: Zpool,
+    // Nested structs with #[daft(owned)] can be converted as well.
+    primary: Zpool,
+    extra: BTreeMap<String, T>,
+    // Fields whose types are type parameters must be leaves.
+    #[daft(leaf)]
+    value: T,
+    #[daft(ignore)]
+    generation: u64,
+}
+
+#[derive(Diffable)]
+#[daft(owned)]
+struct Pair(#[daft(ignore)] u32, String);
+
+#[derive(Diffable)]
+#[daft(owned)]
+struct Empty;
+
+fn main() {
+    let zpool = Zpool { id: 1, size: 10 };
+    let owned: SledDiffOwned<u32> = {
+        let before = Sled {
+            name: "a".to_owned(),
+            zpools: vec![zpool.clone()],
+            boot: zpool.clone(),
+            primary: zpool.clone(),
+            extra: BTreeMap::new(),
+            value: 1,
+            generation: 1,
+        };
+        let after = Sled {
+            name: "b".to_owned(),
+            zpools: vec![],
+            boot: zpool.clone(),
+            primary: Zpool { id: 1, size: 20 },
+            extra: BTreeMap::new(),
+            value: 2,
+            generation: 2,
+        };
+        assert_ne!(before.generation, after.generation);
+        before.diff(&after).into_owned()
+    };
+    assert_eq!(
+        owned.name,
+        Leaf { before: "a".to_owned(), after: "b".to_owned() }
+    );
+    let _: &ZpoolDiffOwned = &owned.primary;
+    let _: &Leaf<Zpool> = &owned.boot;
+    assert_eq!(owned.value, Leaf { before: 1, after: 2 });
+
+    let pair = Pair(1, "x".to_owned());
+    assert_eq!(pair.0, 1);
+    let _: PairDiffOwned = pair.diff(&pair).into_owned();
+    let _: EmptyDiffOwned = Empty.diff(&Empty).into_owned();
+}
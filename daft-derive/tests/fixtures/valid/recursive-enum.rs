@@ -0,0 +1,72 @@
+use daft::{Diffable, Leaf};
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+struct Config {
+    threads: usize,
+    verbose: bool,
+}
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+#[daft(recursive)]
+enum State<'a, T> {
+    Idle,
+    Running {
+        pid: u32,
+        config: Config,
+        #[daft(ignore)]
+        started_at: std::time::Instant,
+    },
+    // The ignored field is skipped in the diff, so the diff variant has a
+    // single field.
+    Failed(#[daft(ignore)] u64, #[daft(leaf)] &'a T),
+    Empty {},
+}
+
+// An enum with a single variant, which doesn't implement Debug or Eq.
+#[derive(Diffable)]
+#[daft(recursive)]
+enum Single {
+    Only(i32),
+}
+
+#[derive(Diffable)]
+#[non_exhaustive]
+#[daft(recursive)]
+pub enum NonExhaustive {
+    A { x: i32 },
+}
+
+fn main() {
+    let now = std::time::Instant::now();
+    let before: State<'_, &str> = State::Running {
+        pid: 1,
+        config: Config { threads: 4, verbose: false },
+        started_at: now,
+    };
+    let after = State::Running {
+        pid: 1,
+        config: Config { threads: 8, verbose: false },
+        started_at: now,
+    };
+    let diff = before.diff(&after);
+    let StateDiff::Running { pid, config } = diff else {
+        panic!("expected StateDiff::Running");
+    };
+    assert!(pid.is_unchanged());
+    assert_eq!(config.threads, Leaf { before: &4, after: &8 });
+
+    let failed = State::Failed(1, &"error");
+    assert_eq!(
+        before.diff(&failed),
+        StateDiff::VariantChanged(Leaf { before: &before, after: &failed })
+    );
+    assert_eq!(
+        failed.diff(&State::Failed(2, &"other error")),
+        StateDiff::Failed(Leaf { before: &&"error", after: &&"other error" })
+    );
+
+    let SingleDiff::Only(diff) = Single::Only(1).diff(&Single::Only(2)) else {
+        panic!("expected SingleDiff::Only");
+    };
+    assert_eq!(diff, Leaf { before: &1, after: &2 });
+}
@@ -0,0 +1,44 @@
+use daft::{DiffSummary, Diffable, IntoOwnedDiff, Patch};
+
+#[derive(Clone, Debug, PartialEq, Eq, Diffable)]
+#[daft(owned)]
+struct Config {
+    name: String,
+    #[daft(text)]
+    template: String,
+}
+
+#[derive(Diffable)]
+#[daft(recursive)]
+enum Document<'a> {
+    Inline(#[daft(text)] &'a str),
+    Empty,
+}
+
+fn main() {
+    let before = Config {
+        name: "a".to_owned(),
+        template: "line 1\nline 2\n".to_owned(),
+    };
+    let after = Config {
+        name: "a".to_owned(),
+        template: "line 1\nline two\n".to_owned(),
+    };
+    let diff = before.diff(&after);
+    assert_eq!(
+        diff.template.unified().to_string(),
+        "@@ -1,2 +1,2 @@\n line 1\n-line 2\n+line two\n",
+    );
+
+    let mut target = before.clone();
+    diff.apply(&mut target).unwrap();
+    assert_eq!(target, after);
+
+    let owned = diff.into_owned();
+    assert!(owned.template.is_modified());
+
+    let before = Document::Inline("a\nb\n");
+    let after = Document::Inline("a\nc\n");
+    assert!(before.diff(&after).is_modified());
+    assert!(Document::Empty.diff(&Document::Empty).is_unchanged());
+}
@@ -0,0 +1,27 @@
+use daft::{DiffSummary, Diffable, Tolerance};
+
+#[derive(Diffable)]
+struct Reading {
+    #[daft(tolerance = 1e-6)]
+    temperature: f64,
+    #[daft(leaf, tolerance = Tolerance::Ulps(4))]
+    pressure: f32,
+    count: u64,
+}
+
+#[derive(Diffable)]
+#[daft(recursive)]
+enum Sample {
+    Scalar(#[daft(tolerance = Tolerance::Relative(1e-3))] f64),
+    Pair { x: f64, y: f64 },
+}
+
+fn main() {
+    let before = Reading { temperature: 20.0, pressure: 1.0, count: 1 };
+    let after = Reading { temperature: 20.000_000_1, pressure: 1.0, count: 1 };
+    assert!(before.diff(&after).is_unchanged());
+
+    let before = Sample::Scalar(1000.0);
+    let after = Sample::Scalar(1000.5);
+    assert!(before.diff(&after).is_unchanged());
+}
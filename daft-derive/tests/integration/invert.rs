@@ -0,0 +1,72 @@
+use daft::{Diffable, IntoOwnedDiff, Invert};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+#[daft(owned)]
+struct Zone {
+    name: String,
+    addresses: BTreeSet<u32>,
+    properties: BTreeMap<String, String>,
+    #[daft(leaf)]
+    image: Option<String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+enum Policy {
+    Allow { zones: BTreeSet<String> },
+    Deny(u32, String),
+    Unset,
+}
+
+fn zones() -> (Zone, Zone) {
+    let before = Zone {
+        name: "zone0".to_owned(),
+        addresses: [1, 2].into(),
+        properties: [("a".to_owned(), "1".to_owned())].into(),
+        image: None,
+    };
+    let after = Zone {
+        name: "zone1".to_owned(),
+        addresses: [2, 3].into(),
+        properties: [
+            ("a".to_owned(), "2".to_owned()),
+            ("b".to_owned(), "3".to_owned()),
+        ]
+        .into(),
+        image: Some("img".to_owned()),
+    };
+    (before, after)
+}
+
+#[test]
+fn test_invert_struct() {
+    let (before, after) = zones();
+    let diff = before.diff(&after).invert();
+    assert_eq!(diff, after.diff(&before));
+    assert_eq!(diff.addresses.added, [&1].into());
+    assert_eq!(diff.addresses.removed, [&3].into());
+
+    // Inverting twice produces the original diff.
+    assert_eq!(diff.invert(), before.diff(&after));
+
+    assert_eq!(
+        before.diff(&after).into_owned().invert(),
+        after.diff(&before).into_owned()
+    );
+}
+
+#[test]
+fn test_invert_enum() {
+    let before = Policy::Allow { zones: ["a".to_owned()].into() };
+    let after = Policy::Allow { zones: ["b".to_owned()].into() };
+    assert_eq!(before.diff(&after).invert(), after.diff(&before));
+
+    let before = Policy::Deny(1, "x".to_owned());
+    let after = Policy::Deny(2, "x".to_owned());
+    assert_eq!(before.diff(&after).invert(), after.diff(&before));
+
+    // Variant changes are inverted as leaves.
+    let after = Policy::Unset;
+    assert_eq!(before.diff(&after).invert(), after.diff(&before));
+    assert_eq!(after.diff(&after).invert(), after.diff(&after));
+}
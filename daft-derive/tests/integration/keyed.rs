@@ -0,0 +1,64 @@
+use daft::{Diffable, Leaf};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+struct Zpool {
+    #[daft(leaf)]
+    id: Uuid,
+    size: u64,
+}
+
+fn zpool_id(zpool: &Zpool) -> &Uuid {
+    &zpool.id
+}
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+struct Sled {
+    generation: u64,
+    #[daft(keyed_by = zpool_id, key = Uuid)]
+    zpools: Vec<Zpool>,
+}
+
+#[test]
+fn test_keyed_by() {
+    let pools: Vec<_> = (0..4)
+        .map(|size| Zpool { id: Uuid::new_v4(), size: size * 100 })
+        .collect();
+
+    let before = Sled { generation: 1, zpools: pools[..3].to_vec() };
+
+    // Drop pool 0, grow pool 1, move pool 2 to the front, and add pool 3.
+    let mut grown = pools[1].clone();
+    grown.size += 50;
+    let after = Sled {
+        generation: 2,
+        zpools: vec![pools[2].clone(), grown.clone(), pools[3].clone()],
+    };
+
+    let diff = before.diff(&after);
+    assert_eq!(diff.generation, Leaf { before: &1, after: &2 });
+
+    let zpools = &diff.zpools;
+    assert_eq!(
+        zpools.entries.removed.keys().copied().collect::<Vec<_>>(),
+        [&pools[0].id]
+    );
+    assert_eq!(
+        zpools.entries.added.keys().copied().collect::<Vec<_>>(),
+        [&pools[3].id]
+    );
+    assert_eq!(
+        zpools.entries.unchanged_keys().collect::<Vec<_>>(),
+        [&pools[2].id]
+    );
+
+    // The modified pool can be diffed recursively.
+    let (id, pool_diff) = zpools.entries.modified_diff().next().unwrap();
+    assert_eq!(id, &pools[1].id);
+    assert_eq!(pool_diff.size, Leaf { before: &100, after: &150 });
+
+    // Pools 1 and 2 swapped their relative order.
+    assert_eq!(zpools.positions[&pools[1].id], Leaf { before: 1, after: 1 });
+    assert_eq!(zpools.positions[&pools[2].id], Leaf { before: 2, after: 0 });
+    assert_eq!(zpools.moved.len(), 1);
+}
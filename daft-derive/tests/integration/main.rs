@@ -6,3 +6,13 @@
 mod basic;
 mod default_field_values;
 mod generics;
+mod invert;
+mod keyed;
+mod merge;
+mod owned;
+mod patch;
+mod recursive_enum;
+#[cfg(feature = "serde-tests")]
+mod serialize;
+mod summary;
+mod visit;
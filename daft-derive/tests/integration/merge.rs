@@ -0,0 +1,116 @@
+use daft::{Diffable, Merge, MergeConflictKind};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+struct Limits {
+    cpu: u32,
+    memory: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+struct Service {
+    name: String,
+    tenants: BTreeMap<String, Limits>,
+    tags: BTreeSet<String>,
+    #[daft(leaf)]
+    owner: Option<String>,
+    // Ignored fields are left as they are in `ours`.
+    #[daft(ignore)]
+    revision: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+struct Pair<T: Diffable>(#[daft(ignore)] u32, T);
+
+fn base() -> Service {
+    Service {
+        name: "dns".to_owned(),
+        tenants: [
+            ("a".to_owned(), Limits { cpu: 1, memory: 10 }),
+            ("b".to_owned(), Limits { cpu: 2, memory: 20 }),
+        ]
+        .into_iter()
+        .collect(),
+        tags: ["prod".to_owned()].into_iter().collect(),
+        owner: None,
+        revision: 1,
+    }
+}
+
+#[test]
+fn test_merge() {
+    let base = base();
+
+    let mut ours = base.clone();
+    ours.tenants.get_mut("a").unwrap().cpu = 4;
+    ours.tags.insert("east".to_owned());
+    ours.revision = 2;
+
+    let mut theirs = base.clone();
+    theirs.name = "dns2".to_owned();
+    theirs.tenants.get_mut("a").unwrap().memory = 40;
+    theirs.tenants.remove("b");
+    theirs.tags.remove("prod");
+    theirs.owner = Some("ops".to_owned());
+    theirs.revision = 3;
+
+    let mut merged = ours.clone();
+    base.diff(&ours).merge(&base.diff(&theirs), &mut merged).unwrap();
+    assert_eq!(
+        merged,
+        Service {
+            name: "dns2".to_owned(),
+            // Changes to different fields of the same map value are merged.
+            tenants: [("a".to_owned(), Limits { cpu: 4, memory: 40 })]
+                .into_iter()
+                .collect(),
+            tags: ["east".to_owned()].into_iter().collect(),
+            owner: Some("ops".to_owned()),
+            revision: 2,
+        }
+    );
+
+    let pair = Pair(1, 'a');
+    let mut merged = Pair(2, 'a');
+    pair.diff(&Pair(3, 'a'))
+        .merge(&pair.diff(&Pair(4, 'b')), &mut merged)
+        .unwrap();
+    assert_eq!(merged, Pair(2, 'b'));
+}
+
+#[test]
+fn test_merge_conflicts() {
+    let base = base();
+
+    let mut ours = base.clone();
+    ours.name = "ours".to_owned();
+    ours.tenants.get_mut("a").unwrap().cpu = 4;
+    ours.tenants.get_mut("b").unwrap().cpu = 5;
+    ours.tenants.insert("c".to_owned(), Limits { cpu: 1, memory: 1 });
+    ours.owner = Some("ops".to_owned());
+
+    let mut theirs = base.clone();
+    theirs.name = "theirs".to_owned();
+    theirs.tenants.get_mut("a").unwrap().cpu = 8;
+    theirs.tenants.remove("b");
+    theirs.tenants.insert("c".to_owned(), Limits { cpu: 2, memory: 2 });
+    // The same change on both sides doesn't conflict.
+    theirs.owner = Some("ops".to_owned());
+
+    let mut merged = ours.clone();
+    let error =
+        base.diff(&ours).merge(&base.diff(&theirs), &mut merged).unwrap_err();
+    assert_eq!(merged, ours);
+
+    let conflicts: Vec<_> =
+        error.conflicts().iter().map(|c| (c.path.as_str(), c.kind)).collect();
+    assert_eq!(
+        conflicts,
+        [
+            ("name", MergeConflictKind::BothModified),
+            (r#"tenants["b"]"#, MergeConflictKind::RemovedAndModified),
+            (r#"tenants["a"].cpu"#, MergeConflictKind::BothModified),
+            (r#"tenants["c"]"#, MergeConflictKind::BothAdded),
+        ]
+    );
+}
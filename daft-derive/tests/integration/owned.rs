@@ -0,0 +1,90 @@
+use daft::{BTreeMapDiffOwned, Diffable, IntoOwnedDiff, Leaf};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+#[daft(owned)]
+struct Disk {
+    serial: String,
+    slot: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+#[daft(owned)]
+struct Sled {
+    name: String,
+    disks: BTreeMap<u32, Disk>,
+    boot: Disk,
+    #[daft(leaf)]
+    spare: Disk,
+    // Ignored fields aren't part of the owned diff.
+    #[daft(ignore)]
+    last_seen: u64,
+}
+
+fn disk(serial: &str, slot: u32) -> Disk {
+    Disk { serial: serial.to_owned(), slot }
+}
+
+fn sled(generation: u32) -> Sled {
+    Sled {
+        name: format!("sled{generation}"),
+        disks: [(0, disk("abc", 0)), (generation, disk("def", generation))]
+            .into_iter()
+            .collect(),
+        boot: disk("abc", generation),
+        spare: disk("ghi", 0),
+        last_seen: u64::from(generation),
+    }
+}
+
+#[test]
+fn test_owned_history() {
+    // Keep a history of diffs after the values being diffed are dropped.
+    let history: Vec<SledDiffOwned> = (1..3)
+        .map(|generation| {
+            let before = sled(generation);
+            let after = sled(generation + 1);
+            before.diff(&after).into_owned()
+        })
+        .collect();
+
+    assert_eq!(history.len(), 2);
+    let diff = &history[1];
+    assert_eq!(
+        diff.name,
+        Leaf { before: "sled2".to_owned(), after: "sled3".to_owned() }
+    );
+    assert_eq!(
+        diff.disks,
+        BTreeMapDiffOwned {
+            common: [(
+                0,
+                Leaf { before: disk("abc", 0), after: disk("abc", 0) }
+            )]
+            .into_iter()
+            .collect(),
+            added: [(3, disk("def", 3))].into_iter().collect(),
+            removed: [(2, disk("def", 2))].into_iter().collect(),
+        }
+    );
+    assert_eq!(
+        diff.boot,
+        DiskDiffOwned {
+            serial: Leaf { before: "abc".to_owned(), after: "abc".to_owned() },
+            slot: Leaf { before: 2, after: 3 },
+        }
+    );
+    assert_eq!(
+        diff.spare,
+        Leaf { before: disk("ghi", 0), after: disk("ghi", 0) }
+    );
+}
+
+#[test]
+fn test_owned_send() {
+    let diff = sled(1).diff(&sled(2)).into_owned();
+
+    // Owned diffs can be sent to other threads.
+    let handle = std::thread::spawn(move || diff.boot.slot);
+    assert_eq!(handle.join().unwrap(), Leaf { before: 1, after: 2 });
+}
@@ -0,0 +1,86 @@
+use daft::{Diffable, IntoOwnedDiff, Patch, PatchError};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+#[daft(owned)]
+struct Disk {
+    serial: String,
+    slot: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+#[daft(owned)]
+struct Sled {
+    name: String,
+    disks: BTreeMap<u32, Disk>,
+    boot: Disk,
+    tags: BTreeSet<String>,
+    #[daft(leaf)]
+    spare: Option<Disk>,
+    // Ignored fields are left unchanged.
+    #[daft(ignore)]
+    last_seen: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+struct Pair<T: Diffable>(#[daft(ignore)] u32, T);
+
+fn disk(serial: &str, slot: u32) -> Disk {
+    Disk { serial: serial.to_owned(), slot }
+}
+
+fn sleds() -> (Sled, Sled) {
+    let before = Sled {
+        name: "sled0".to_owned(),
+        disks: [(0, disk("abc", 0)), (1, disk("def", 1))].into_iter().collect(),
+        boot: disk("abc", 0),
+        tags: ["a".to_owned()].into_iter().collect(),
+        spare: None,
+        last_seen: 1,
+    };
+    let after = Sled {
+        name: "sled1".to_owned(),
+        disks: [(0, disk("abc", 5)), (2, disk("ghi", 2))].into_iter().collect(),
+        boot: disk("ghi", 2),
+        tags: ["b".to_owned()].into_iter().collect(),
+        spare: Some(disk("jkl", 3)),
+        last_seen: 2,
+    };
+    (before, after)
+}
+
+#[test]
+fn test_patch() {
+    let (before, after) = sleds();
+    let diff = before.diff(&after);
+
+    let mut target = before.clone();
+    diff.apply(&mut target).unwrap();
+    assert_eq!(target, Sled { last_seen: before.last_seen, ..after.clone() });
+
+    // The target no longer matches the before side of the diff.
+    assert_eq!(diff.apply(&mut target), Err(PatchError::Mismatch));
+
+    let pair = Pair(1, 'a');
+    let mut target = Pair(2, 'a');
+    pair.diff(&Pair(3, 'b')).apply(&mut target).unwrap();
+    assert_eq!(target, Pair(2, 'b'));
+}
+
+#[test]
+fn test_patch_owned() {
+    let (before, after) = sleds();
+    let diff = before.diff(&after).into_owned();
+
+    // Owned diffs can be applied to copies of the before value after the
+    // original values are gone, e.g. on another machine.
+    let mut target = before.clone();
+    drop(before);
+    diff.apply(&mut target).unwrap();
+    assert_eq!(target.disks, after.disks);
+    assert_eq!(target.spare, after.spare);
+
+    let (mut target, _) = sleds();
+    target.disks.remove(&1);
+    assert_eq!(diff.apply(&mut target), Err(PatchError::MissingEntry));
+}
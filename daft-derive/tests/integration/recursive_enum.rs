@@ -0,0 +1,88 @@
+use daft::{Diffable, Leaf};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+struct Payload {
+    generation: u64,
+    settings: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Diffable)]
+#[daft(recursive)]
+enum Reconfigurator {
+    Idle,
+    Planning(Payload),
+    Executing {
+        step: usize,
+        payload: Payload,
+        #[daft(leaf)]
+        owner: Option<String>,
+    },
+}
+
+fn make_payload(generation: u64, settings: &[(&str, &str)]) -> Payload {
+    Payload {
+        generation,
+        settings: settings
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+    }
+}
+
+#[test]
+fn test_recursive_enum_same_variant() {
+    let before = Reconfigurator::Executing {
+        step: 3,
+        payload: make_payload(1, &[("a", "1"), ("b", "2")]),
+        owner: None,
+    };
+    let after = Reconfigurator::Executing {
+        step: 3,
+        payload: make_payload(2, &[("a", "1"), ("b", "3")]),
+        owner: Some("nexus".to_owned()),
+    };
+
+    let diff = before.diff(&after);
+    println!("{diff:#?}");
+
+    let ReconfiguratorDiff::Executing { step, payload, owner } = diff else {
+        panic!("expected ReconfiguratorDiff::Executing, found {diff:?}");
+    };
+    assert!(step.is_unchanged());
+    assert_eq!(payload.generation, Leaf { before: &1, after: &2 });
+    assert_eq!(
+        payload.settings.modified_keys().collect::<Vec<_>>(),
+        ["b"].map(String::from).iter().collect::<Vec<_>>()
+    );
+    assert_eq!(owner.before, &None);
+    assert_eq!(owner.after.as_deref(), Some("nexus"));
+
+    let diff = Reconfigurator::Idle.diff(&Reconfigurator::Idle);
+    assert_eq!(diff, ReconfiguratorDiff::Idle);
+    assert_eq!(format!("{diff:?}"), "Idle");
+
+    let before = Reconfigurator::Planning(make_payload(1, &[]));
+    let after = Reconfigurator::Planning(make_payload(1, &[("a", "1")]));
+    let ReconfiguratorDiff::Planning(diff) = before.diff(&after) else {
+        panic!("expected ReconfiguratorDiff::Planning");
+    };
+    assert!(diff.generation.is_unchanged());
+    assert_eq!(diff.settings.added.len(), 1);
+}
+
+#[test]
+fn test_recursive_enum_variant_changed() {
+    let before = Reconfigurator::Idle;
+    let after = Reconfigurator::Planning(make_payload(1, &[]));
+
+    let diff = before.diff(&after);
+    assert_eq!(
+        diff,
+        ReconfiguratorDiff::VariantChanged(Leaf {
+            before: &before,
+            after: &after,
+        })
+    );
+    assert_ne!(diff, ReconfiguratorDiff::Idle);
+}
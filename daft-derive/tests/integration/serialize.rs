@@ -0,0 +1,112 @@
+use daft::Diffable;
+use serde::Serialize;
+use serde_json::json;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Eq, PartialEq, Serialize, Diffable)]
+#[daft(recursive)]
+enum Policy {
+    Disabled,
+    Enabled { limit: u32 },
+    Custom(String),
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize, Diffable)]
+struct Disk {
+    slot: u32,
+}
+
+#[derive(Diffable)]
+struct Sled {
+    r#type: &'static str,
+    disks: BTreeMap<&'static str, Disk>,
+    policy: Policy,
+    #[daft(ignore)]
+    generation: u64,
+}
+
+#[derive(Diffable)]
+struct Wrapper(u32);
+
+#[derive(Diffable)]
+struct Pair(u32, #[daft(ignore)] u32, char);
+
+#[derive(Diffable)]
+struct Unit;
+
+#[test]
+fn test_serialize_struct() {
+    let before = Sled {
+        r#type: "gimlet",
+        disks: [("a", Disk { slot: 0 }), ("b", Disk { slot: 1 })]
+            .into_iter()
+            .collect(),
+        policy: Policy::Enabled { limit: 5 },
+        generation: 1,
+    };
+    let after = Sled {
+        r#type: "cosmo",
+        disks: [("a", Disk { slot: 2 })].into_iter().collect(),
+        policy: Policy::Enabled { limit: 6 },
+        generation: 2,
+    };
+    assert_ne!(before.generation, after.generation, "ignored fields differ");
+
+    assert_eq!(
+        serde_json::to_value(before.diff(&after)).unwrap(),
+        json!({
+            "type": { "before": "gimlet", "after": "cosmo" },
+            "disks": {
+                "common": {
+                    "a": { "before": { "slot": 0 }, "after": { "slot": 2 } },
+                },
+                "added": {},
+                "removed": { "b": { "slot": 1 } },
+            },
+            "policy": {
+                "Enabled": { "limit": { "before": 5, "after": 6 } },
+            },
+        }),
+    );
+}
+
+#[test]
+fn test_serialize_tuple_and_unit() {
+    assert_eq!(
+        serde_json::to_value(Wrapper(1).diff(&Wrapper(2))).unwrap(),
+        json!({ "before": 1, "after": 2 }),
+    );
+    let (before, after) = (Pair(1, 2, 'a'), Pair(1, 3, 'b'));
+    assert_ne!(before.1, after.1, "ignored fields differ");
+    assert_eq!(
+        serde_json::to_value(before.diff(&after)).unwrap(),
+        json!([
+            { "before": 1, "after": 1 },
+            { "before": "a", "after": "b" },
+        ]),
+    );
+    assert_eq!(serde_json::to_value(Unit.diff(&Unit)).unwrap(), json!(null));
+}
+
+#[test]
+fn test_serialize_enum() {
+    let disabled = Policy::Disabled;
+    let custom = Policy::Custom("x".to_owned());
+    assert_eq!(
+        serde_json::to_value(disabled.diff(&disabled)).unwrap(),
+        json!("Disabled"),
+    );
+    assert_eq!(
+        serde_json::to_value(custom.diff(&custom)).unwrap(),
+        json!({ "Custom": { "before": "x", "after": "x" } }),
+    );
+    assert_eq!(
+        serde_json::to_value(disabled.diff(&custom)).unwrap(),
+        json!({
+            "VariantChanged": {
+                "before": "Disabled",
+                "after": { "Custom": "x" },
+            },
+        }),
+    );
+}
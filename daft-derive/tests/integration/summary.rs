@@ -0,0 +1,81 @@
+use daft::{DiffSummary, Diffable};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+struct Disk {
+    serial: String,
+    slot: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+#[daft(recursive)]
+enum Role {
+    Spare,
+    Active { disks: BTreeMap<u32, Disk> },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+struct Sled {
+    name: String,
+    role: Role,
+    tags: BTreeSet<String>,
+    boot: (Disk, Option<u32>),
+    // Changes to ignored fields aren't reported.
+    #[daft(ignore)]
+    last_seen: u64,
+}
+
+#[derive(Debug, Diffable)]
+struct Empty {}
+
+fn sled() -> Sled {
+    let disk = Disk { serial: "abc".to_owned(), slot: 0 };
+    Sled {
+        name: "sled0".to_owned(),
+        role: Role::Active { disks: [(0, disk.clone())].into_iter().collect() },
+        tags: ["a".to_owned()].into_iter().collect(),
+        boot: (disk, None),
+        last_seen: 0,
+    }
+}
+
+#[test]
+fn test_summary_unchanged() {
+    let before = sled();
+    let mut after = sled();
+    after.last_seen = 10;
+
+    let diff = before.diff(&after);
+    assert!(diff.is_unchanged());
+    assert!(!diff.is_modified());
+
+    assert!(Empty {}.diff(&Empty {}).is_unchanged());
+    assert!(Role::Spare.diff(&Role::Spare).is_unchanged());
+}
+
+#[test]
+fn test_summary_modified() {
+    let before = sled();
+
+    // Each of these changes is nested at a different depth.
+    let changes: Vec<fn(&mut Sled)> = vec![
+        |sled| sled.name.push('!'),
+        |sled| sled.role = Role::Spare,
+        |sled| {
+            let Role::Active { disks } = &mut sled.role else { unreachable!() };
+            disks.get_mut(&0).unwrap().slot = 1;
+        },
+        |sled| {
+            sled.tags.insert("b".to_owned());
+        },
+        |sled| sled.boot.0.serial.push('d'),
+        |sled| sled.boot.1 = Some(1),
+    ];
+
+    for (i, change) in changes.into_iter().enumerate() {
+        let mut after = sled();
+        change(&mut after);
+        let diff = before.diff(&after);
+        assert!(diff.is_modified(), "change {i} should be reported: {diff:?}");
+    }
+}
@@ -0,0 +1,195 @@
+use daft::{DiffPath, DiffRenderer, DiffVisitor, Diffable, VisitDiff};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+struct Zpool {
+    size: u64,
+    health: (u8, bool),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+#[daft(recursive)]
+enum Policy {
+    Disabled,
+    Enabled { limit: u32 },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Diffable)]
+struct Sled {
+    zpools: BTreeMap<String, Zpool>,
+    tags: BTreeSet<&'static str>,
+    policy: Policy,
+    #[daft(ignore)]
+    last_seen: u64,
+    #[daft(leaf)]
+    r#type: Kind,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Kind {
+    Gimlet,
+    Cosmo,
+}
+
+#[derive(Debug, Diffable)]
+struct Rack(#[daft(ignore)] u32, Sled);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Diffable)]
+struct Register {
+    value: u32,
+    enabled: bool,
+}
+
+#[derive(Diffable)]
+struct RegisterMap {
+    registers: [Register; 4],
+}
+
+/// Records changes as lines in an audit log.
+#[derive(Default)]
+struct AuditLog(Vec<String>);
+
+impl DiffVisitor for AuditLog {
+    fn added(&mut self, path: &DiffPath<'_>, value: &dyn fmt::Debug) {
+        self.0.push(format!("{path}: + {value:?}"));
+    }
+
+    fn removed(&mut self, path: &DiffPath<'_>, value: &dyn fmt::Debug) {
+        self.0.push(format!("{path}: - {value:?}"));
+    }
+
+    fn modified(
+        &mut self,
+        path: &DiffPath<'_>,
+        before: &dyn fmt::Debug,
+        after: &dyn fmt::Debug,
+    ) {
+        self.0.push(format!("{path}: {before:?} -> {after:?}"));
+    }
+}
+
+fn sled() -> Sled {
+    Sled {
+        zpools: [
+            ("abc".to_owned(), Zpool { size: 10, health: (0, true) }),
+            ("def".to_owned(), Zpool { size: 30, health: (0, true) }),
+        ]
+        .into_iter()
+        .collect(),
+        tags: ["a"].into_iter().collect(),
+        policy: Policy::Enabled { limit: 5 },
+        last_seen: 0,
+        r#type: Kind::Gimlet,
+    }
+}
+
+#[test]
+fn test_visit_paths() {
+    let before = sled();
+    let mut after = sled();
+    after.last_seen = 1;
+    let abc = after.zpools.get_mut("abc").unwrap();
+    abc.size = 20;
+    abc.health.1 = false;
+    after.zpools.remove("def");
+    after
+        .zpools
+        .insert("ghi".to_owned(), Zpool { size: 40, health: (0, true) });
+    after.tags.insert("b");
+    after.policy = Policy::Enabled { limit: 6 };
+    after.r#type = Kind::Cosmo;
+
+    let mut log = AuditLog::default();
+    before.diff(&after).visit(&mut log);
+    assert_eq!(
+        log.0,
+        [
+            r#"zpools["def"]: - Zpool { size: 30, health: (0, true) }"#,
+            r#"zpools["abc"].size: 10 -> 20"#,
+            r#"zpools["abc"].health.1: true -> false"#,
+            r#"zpools["ghi"]: + Zpool { size: 40, health: (0, true) }"#,
+            r#"tags: + "b""#,
+            "policy::Enabled.limit: 5 -> 6",
+            "type: Gimlet -> Cosmo",
+        ]
+    );
+
+    // A variant change is reported as a modification of the enum.
+    let mut log = AuditLog::default();
+    Policy::Disabled.diff(&Policy::Enabled { limit: 1 }).visit(&mut log);
+    assert_eq!(log.0, [": Disabled -> Enabled { limit: 1 }"]);
+
+    // Paths use the indexes of the original fields, and can be rooted.
+    let mut sled_after = sled();
+    sled_after.tags.clear();
+    let mut log = AuditLog::default();
+    let root = DiffPath::root();
+    let root = root.join(daft::PathSegment::Field("rack"));
+    let (rack_before, rack_after) = (Rack(0, sled()), Rack(1, sled_after));
+    assert_ne!(rack_before.0, rack_after.0, "ignored fields differ");
+    rack_before.diff(&rack_after).visit_at(&root, &mut log);
+    assert_eq!(log.0, [r#"rack.1.tags: - "a""#]);
+
+    // Unchanged diffs don't report anything.
+    let mut log = AuditLog::default();
+    before.diff(&before).visit(&mut log);
+    assert!(log.0.is_empty());
+}
+
+#[test]
+fn test_visit_arrays() {
+    let reg = Register { value: 0, enabled: false };
+    let before = RegisterMap { registers: [reg; 4] };
+    let mut after = RegisterMap { registers: [reg; 4] };
+    after.registers[1].value = 5;
+    after.registers[3].enabled = true;
+
+    // Arrays are diffed per index.
+    let mut log = AuditLog::default();
+    before.diff(&after).visit(&mut log);
+    assert_eq!(
+        log.0,
+        ["registers[1].value: 0 -> 5", "registers[3].enabled: false -> true"]
+    );
+}
+
+#[test]
+fn test_render() {
+    let before = sled();
+    let mut after = sled();
+    after.zpools.get_mut("def").unwrap().health = (1, false);
+    after.policy = Policy::Enabled { limit: 6 };
+
+    let diff = before.diff(&after);
+    assert_eq!(
+        strip_styles(&DiffRenderer::new().render(&diff).to_string()),
+        r#"zpools:
+  "def":
+    health:
+      0: 0 -> 1
+      1: true -> false
+policy:
+  Enabled:
+    limit: 5 -> 6
+"#,
+    );
+}
+
+/// Remove ANSI styles from rendered output. With daft's `color` feature,
+/// output is styled by default if standard output is a terminal.
+fn strip_styles(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip the rest of the escape sequence, up to and including `m`.
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -1,10 +1,13 @@
 //! Implementations for types from the `alloc` crate.
 
-use crate::{Diffable, Leaf};
+use crate::{
+    DiffPath, Diffable, Leaf, Merge, MergeConflictKind, Patch, PatchError,
+    merge::merge_leaf,
+};
 use alloc::{
     borrow::{Cow, ToOwned},
     boxed::Box,
-    collections::{BTreeMap, BTreeSet},
+    collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
     rc::Rc,
     string::String,
     sync::Arc,
@@ -109,7 +112,7 @@ map_diff!(
     /// assert_eq!(modified, [(&2, Leaf { before: &"dolor", after: &"sit" })]);
     /// # }
     /// ```
-    BTreeMap, Ord
+    BTreeMap, Ord, remove
 );
 set_diff!(
     /// A diff of two [`BTreeSet`] instances.
@@ -141,12 +144,14 @@ set_diff!(
     /// assert_eq!(changes, expected);
     /// # }
     /// ```
-    BTreeSet, Ord
+    BTreeSet, Ord, remove
 );
 
 /// Treat Vecs as Leafs
+///
+/// For an ordered diff, use [`Leaf::seq_diff`] on the result.
 //
-// We plan to add opt in diff functionality: set-like, reordered, etc...
+// We plan to add more opt in diff functionality: set-like, etc...
 impl<T: Diffable> Diffable for Vec<T> {
     type Diff<'daft>
         = Leaf<&'daft [T]>
@@ -158,6 +163,60 @@ impl<T: Diffable> Diffable for Vec<T> {
     }
 }
 
+/// Treat `VecDeque`s as leaf nodes, like vectors.
+///
+/// An ordered diff can be obtained by calling `Leaf::seq_diff` on the result.
+impl<T: Diffable> Diffable for VecDeque<T> {
+    type Diff<'daft>
+        = Leaf<&'daft VecDeque<T>>
+    where
+        T: 'daft;
+
+    fn diff<'daft>(&'daft self, other: &'daft Self) -> Self::Diff<'daft> {
+        Leaf { before: self, after: other }
+    }
+}
+
+/// Treat `LinkedList`s as leaf nodes, like vectors.
+///
+/// An ordered diff can be obtained by calling `Leaf::seq_diff` on the result.
+impl<T: Diffable> Diffable for LinkedList<T> {
+    type Diff<'daft>
+        = Leaf<&'daft LinkedList<T>>
+    where
+        T: 'daft;
+
+    fn diff<'daft>(&'daft self, other: &'daft Self) -> Self::Diff<'daft> {
+        Leaf { before: self, after: other }
+    }
+}
+
+impl<T: Clone + PartialEq> Patch<Vec<T>> for Leaf<&[T]> {
+    fn apply(&self, target: &mut Vec<T>) -> Result<(), PatchError> {
+        if target.as_slice() != self.before {
+            return Err(PatchError::Mismatch);
+        }
+        target.clear();
+        target.extend_from_slice(self.after);
+        Ok(())
+    }
+}
+
+impl<T: Clone + PartialEq> Merge<Vec<T>> for Leaf<&[T]> {
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Vec<T>,
+        path: &DiffPath<'_>,
+        conflict: &mut dyn FnMut(&DiffPath<'_>, MergeConflictKind),
+    ) {
+        if merge_leaf(self, theirs, path, conflict) {
+            target.clear();
+            target.extend_from_slice(theirs.after);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -220,4 +279,70 @@ mod tests {
             [&K(0), &K(2)]
         );
     }
+
+    #[test]
+    fn btree_map_recursive_diff() {
+        use crate::{DiffableMap, IntoOwnedDiff, Invert};
+
+        type Nested = BTreeMap<&'static str, u32>;
+        let a: BTreeMap<u32, Nested> =
+            [(0, [("x", 1)].into()), (1, [("x", 1)].into())].into();
+        let b: BTreeMap<u32, Nested> =
+            [(0, [("x", 2)].into()), (2, Nested::new())].into();
+
+        let diff = a.recursive_diff(&b);
+        assert_eq!(diff, a.diff(&b).into_recursive());
+        assert_eq!(
+            diff.common[&0].common[&"x"],
+            Leaf { before: &1, after: &2 }
+        );
+        assert_eq!(diff.get_modified(&0), diff.common.get(&0));
+        assert!(diff.is_modified(&0));
+        assert_eq!(diff.unchanged_keys().count(), 0);
+
+        let mut target = a.clone();
+        diff.apply(&mut target).unwrap();
+        assert_eq!(target, b);
+        a.recursive_diff(&b).invert().apply(&mut target).unwrap();
+        assert_eq!(target, a);
+        let owned = a.recursive_diff(&b).into_owned();
+        owned.apply(&mut target).unwrap();
+        assert_eq!(target, b);
+        assert!(owned.invert().common[&0].common["x"].is_modified());
+
+        // Removing an entry that the other side modified is a conflict.
+        let c: BTreeMap<u32, Nested> = [(1, [("x", 1)].into())].into();
+        let theirs = a.recursive_diff(&c);
+        let mut target = b.clone();
+        let error = diff.merge(&theirs, &mut target).unwrap_err();
+        assert_eq!(error.conflicts()[0].path, "[0]");
+        assert_eq!(
+            error.conflicts()[0].kind,
+            MergeConflictKind::RemovedAndModified
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_diffs() {
+        let a: BTreeMap<_, _> = [("x", 1), ("y", 1)].into_iter().collect();
+        let b: BTreeMap<_, _> = [("x", 2), ("z", 1)].into_iter().collect();
+        assert_eq!(
+            serde_json::to_string(&a.diff(&b)).unwrap(),
+            r#"{"common":{"x":{"before":1,"after":2}},"added":{"z":1},"removed":{"y":1}}"#,
+        );
+
+        let a: BTreeSet<_> = [1, 2].into_iter().collect();
+        let b: BTreeSet<_> = [2, 3].into_iter().collect();
+        assert_eq!(
+            serde_json::to_string(&a.diff(&b)).unwrap(),
+            r#"{"common":[2],"added":[3],"removed":[1]}"#,
+        );
+
+        let diff = crate::SeqDiff::new(&['a', 'b'], &['b']);
+        assert_eq!(
+            serde_json::to_string(&diff).unwrap(),
+            r#"{"before":["a","b"],"after":["b"],"edits":[{"kind":"Delete","before":{"start":0,"end":1},"after":{"start":0,"end":0}},{"kind":"Equal","before":{"start":1,"end":2},"after":{"start":0,"end":1}}]}"#,
+        );
+    }
 }
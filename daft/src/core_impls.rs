@@ -1,6 +1,9 @@
 //! Implementations for core types.
 
-use crate::{Diffable, Leaf};
+use crate::{
+    DiffPath, DiffSummary, DiffVisitor, Diffable, IntoOwnedDiff, Invert, Leaf,
+    Merge, MergeConflictKind, Patch, PatchError, PathSegment, VisitDiff,
+};
 use core::{
     cell::RefCell,
     marker::PhantomData,
@@ -10,11 +13,14 @@ use core::{
         NonZeroIsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64,
         NonZeroU128, NonZeroUsize,
     },
+    time::Duration,
 };
 
-leaf! { i64, i32, i16, i8, u64, u32, u16, u8, char, bool, isize, usize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroUsize, () }
+leaf! { i128, i64, i32, i16, i8, u128, u64, u32, u16, u8, char, bool, isize, usize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroUsize, () }
+leaf! { f32, f64 }
 leaf! { IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6 }
 leaf! { str }
+leaf! { Duration }
 
 impl<T> Diffable for Option<T> {
     type Diff<'daft>
@@ -81,6 +87,9 @@ impl<T: ?Sized> Diffable for PhantomData<T> {
 }
 
 /// Treat slices as leaf nodes.
+///
+/// With the `alloc` feature, an ordered diff can be obtained by calling
+/// `Leaf::seq_diff` on the result.
 impl<T: Diffable> Diffable for [T] {
     type Diff<'daft>
         = Leaf<&'daft [T]>
@@ -92,6 +101,77 @@ impl<T: Diffable> Diffable for [T] {
     }
 }
 
+/// Diff arrays element by element.
+///
+/// Since both sides always have the same length, the diff is an array of the
+/// diffs of each pair of elements.
+impl<T: Diffable, const N: usize> Diffable for [T; N] {
+    type Diff<'daft>
+        = [T::Diff<'daft>; N]
+    where
+        T: 'daft;
+
+    fn diff<'daft>(&'daft self, other: &'daft Self) -> Self::Diff<'daft> {
+        core::array::from_fn(|ix| self[ix].diff(&other[ix]))
+    }
+}
+
+impl<T: DiffSummary, const N: usize> DiffSummary for [T; N] {
+    fn is_unchanged(&self) -> bool {
+        self.iter().all(DiffSummary::is_unchanged)
+    }
+}
+
+impl<T: IntoOwnedDiff, const N: usize> IntoOwnedDiff for [T; N] {
+    type Owned = [T::Owned; N];
+
+    fn into_owned(self) -> Self::Owned {
+        self.map(IntoOwnedDiff::into_owned)
+    }
+}
+
+impl<T: Invert, const N: usize> Invert for [T; N] {
+    fn invert(self) -> Self {
+        self.map(Invert::invert)
+    }
+}
+
+impl<T: Patch<U>, U, const N: usize> Patch<[U; N]> for [T; N] {
+    fn apply(&self, target: &mut [U; N]) -> Result<(), PatchError> {
+        for (diff, value) in self.iter().zip(target) {
+            diff.apply(value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Merge<U>, U, const N: usize> Merge<[U; N]> for [T; N] {
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut [U; N],
+        path: &DiffPath<'_>,
+        conflict: &mut dyn FnMut(&DiffPath<'_>, MergeConflictKind),
+    ) {
+        for (ix, value) in target.iter_mut().enumerate() {
+            self[ix].merge_at(
+                &theirs[ix],
+                value,
+                &path.join(PathSegment::Index(ix)),
+                conflict,
+            );
+        }
+    }
+}
+
+impl<T: VisitDiff, const N: usize> VisitDiff for [T; N] {
+    fn visit_at(&self, path: &DiffPath<'_>, visitor: &mut dyn DiffVisitor) {
+        for (ix, diff) in self.iter().enumerate() {
+            diff.visit_at(&path.join(PathSegment::Index(ix)), visitor);
+        }
+    }
+}
+
 macro_rules! tuple_diffable {
     ($(($($name:ident $ix:tt),+)),+) => {
         $(
@@ -104,6 +184,71 @@ macro_rules! tuple_diffable {
                     ($(self.$ix.diff(&other.$ix),)+)
                 }
             }
+
+            impl<$($name: DiffSummary),+> DiffSummary for ($($name,)+) {
+                fn is_unchanged(&self) -> bool {
+                    $(self.$ix.is_unchanged())&&+
+                }
+            }
+
+            impl<$($name: IntoOwnedDiff),+> IntoOwnedDiff for ($($name,)+) {
+                type Owned = ($($name::Owned,)+);
+
+                fn into_owned(self) -> Self::Owned {
+                    ($(self.$ix.into_owned(),)+)
+                }
+            }
+
+            impl<$($name: Invert),+> Invert for ($($name,)+) {
+                fn invert(self) -> Self {
+                    ($(self.$ix.invert(),)+)
+                }
+            }
+
+            paste::paste! {
+                impl<$($name, [<$name Target>]),+> Patch<($([<$name Target>],)+)> for ($($name,)+)
+                where
+                    $($name: Patch<[<$name Target>]>,)+
+                {
+                    fn apply(
+                        &self,
+                        target: &mut ($([<$name Target>],)+),
+                    ) -> Result<(), PatchError> {
+                        $(self.$ix.apply(&mut target.$ix)?;)+
+                        Ok(())
+                    }
+                }
+
+                impl<$($name, [<$name Target>]),+> Merge<($([<$name Target>],)+)> for ($($name,)+)
+                where
+                    $($name: Merge<[<$name Target>]>,)+
+                {
+                    fn merge_at(
+                        &self,
+                        theirs: &Self,
+                        target: &mut ($([<$name Target>],)+),
+                        path: &DiffPath<'_>,
+                        conflict: &mut dyn FnMut(&DiffPath<'_>, MergeConflictKind),
+                    ) {
+                        $(
+                            self.$ix.merge_at(
+                                &theirs.$ix,
+                                &mut target.$ix,
+                                &path.join(PathSegment::TupleIndex($ix)),
+                                conflict,
+                            );
+                        )+
+                    }
+                }
+            }
+
+            impl<$($name: VisitDiff),+> VisitDiff for ($($name,)+) {
+                fn visit_at(&self, path: &DiffPath<'_>, visitor: &mut dyn DiffVisitor) {
+                    $(
+                        self.$ix.visit_at(&path.join(PathSegment::TupleIndex($ix)), visitor);
+                    )+
+                }
+            }
         )+
     }
 }
@@ -137,6 +282,24 @@ mod tests {
         assert_eq!(diff.after, ******after);
     }
 
+    #[test]
+    fn array_diffable() {
+        let before = [(1_u32, 'a'), (2, 'b'), (3, 'c')];
+        let mut after = before;
+        assert!(before.diff(&after).is_unchanged());
+
+        after[1].1 = 'x';
+        let diff = before.diff(&after);
+        assert!(diff[0].is_unchanged());
+        assert_eq!(diff[1].1, Leaf { before: &'b', after: &'x' });
+        assert!(diff.is_modified());
+        assert_eq!(diff.invert(), after.diff(&before));
+
+        let mut target = before;
+        diff.into_owned().apply(&mut target).unwrap();
+        assert_eq!(target, after);
+    }
+
     #[test]
     fn tuple_diffable() {
         let before = (1usize, 2usize, 3usize);
@@ -0,0 +1,326 @@
+//! Signed differences between before and after values.
+
+use crate::Leaf;
+use core::{
+    cmp::Ordering,
+    fmt,
+    num::{
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128,
+        NonZeroIsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64,
+        NonZeroU128, NonZeroUsize,
+    },
+    time::Duration,
+};
+#[cfg(feature = "std")]
+use std::time::{Instant, SystemTime};
+
+/// The signed difference between a before and an after value, stored as a
+/// direction and an unsigned magnitude.
+///
+/// Returned by [`Leaf::delta`] for integers, [`NonZero`](core::num::NonZero)
+/// integers, [`Duration`], [`Instant`] and [`SystemTime`]. For signed integer
+/// types, the magnitude is the corresponding unsigned type, so the delta
+/// never overflows: for example, the delta from `i8::MIN` to `i8::MAX` is
+/// `Delta::Increased(255_u8)`.
+///
+/// With a [`Display`](fmt::Display) magnitude, a delta is displayed with a
+/// leading sign, for example `+12` or `-3`.
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+/// use daft::{Delta, Diffable};
+///
+/// let before = Duration::from_secs(5);
+/// let after = Duration::from_secs(3);
+/// let delta = before.diff(&after).delta();
+/// assert_eq!(delta, Delta::Decreased(Duration::from_secs(2)));
+/// assert_eq!(delta.invert(), Delta::Increased(Duration::from_secs(2)));
+///
+/// let delta = 48_u64.diff(&60).delta();
+/// assert_eq!(delta, Delta::Increased(12));
+/// assert_eq!(delta.to_string(), "+12");
+/// ```
+///
+/// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+/// [`SystemTime`]: https://doc.rust-lang.org/std/time/struct.SystemTime.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Delta<T> {
+    /// The after value is greater than the before value, by this amount.
+    Increased(T),
+
+    /// The after value is less than the before value, by this amount.
+    Decreased(T),
+
+    /// The before and after values are equal.
+    Unchanged,
+}
+
+impl<T> Delta<T> {
+    /// Return true if the before and after values are equal.
+    #[inline]
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+
+    /// Return the direction of the change.
+    #[inline]
+    pub fn direction(&self) -> Direction {
+        match self {
+            Self::Increased(_) => Direction::Increased,
+            Self::Decreased(_) => Direction::Decreased,
+            Self::Unchanged => Direction::Unchanged,
+        }
+    }
+
+    /// Return the magnitude of the change, or `None` if the values are equal.
+    #[inline]
+    pub fn magnitude(&self) -> Option<&T> {
+        match self {
+            Self::Increased(magnitude) | Self::Decreased(magnitude) => {
+                Some(magnitude)
+            }
+            Self::Unchanged => None,
+        }
+    }
+
+    /// Return the delta from the after value to the before value.
+    #[inline]
+    pub fn invert(self) -> Self {
+        match self {
+            Self::Increased(magnitude) => Self::Decreased(magnitude),
+            Self::Decreased(magnitude) => Self::Increased(magnitude),
+            Self::Unchanged => Self::Unchanged,
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Delta<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Increased(magnitude) => write!(f, "+{magnitude}"),
+            Self::Decreased(magnitude) => write!(f, "-{magnitude}"),
+            Self::Unchanged => write!(f, "0"),
+        }
+    }
+}
+
+/// The direction in which a value changed, returned by [`Delta::direction`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Direction {
+    /// The after value is greater than the before value.
+    Increased,
+
+    /// The after value is less than the before value.
+    Decreased,
+
+    /// The before and after values are equal.
+    Unchanged,
+}
+
+macro_rules! int_delta {
+    ($($ty:ty => $magnitude:ty),*) => {
+        $(
+            impl Leaf<&$ty> {
+                /// Return the signed difference from before to after.
+                ///
+                /// This never overflows, since the magnitude is unsigned.
+                #[inline]
+                pub fn delta(&self) -> Delta<$magnitude> {
+                    let (before, after) = (*self.before, *self.after);
+                    match after.cmp(&before) {
+                        Ordering::Greater => {
+                            Delta::Increased(after.abs_diff(before))
+                        }
+                        Ordering::Less => {
+                            Delta::Decreased(after.abs_diff(before))
+                        }
+                        Ordering::Equal => Delta::Unchanged,
+                    }
+                }
+
+                /// Return the change from before to after as a percentage of
+                /// the magnitude of before, or `None` if before is zero.
+                ///
+                /// For example, a change from 50 to 60 is `Some(20.0)`, and a
+                /// change from -50 to -60 is `Some(-20.0)`.
+                #[inline]
+                pub fn percent_change(&self) -> Option<f64> {
+                    if *self.before == 0 {
+                        return None;
+                    }
+                    let base = (*self.before as f64).abs();
+                    let percent = match self.delta() {
+                        Delta::Increased(magnitude) => magnitude as f64 / base,
+                        Delta::Decreased(magnitude) => {
+                            -(magnitude as f64) / base
+                        }
+                        Delta::Unchanged => 0.0,
+                    };
+                    Some(percent * 100.0)
+                }
+            }
+        )*
+    }
+}
+
+int_delta! {
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128,
+    usize => usize, i8 => u8, i16 => u16, i32 => u32, i64 => u64,
+    i128 => u128, isize => usize
+}
+
+macro_rules! non_zero_delta {
+    ($($ty:ty => $magnitude:ty),*) => {
+        $(
+            impl Leaf<&$ty> {
+                /// Return the signed difference from before to after.
+                ///
+                /// This never overflows, since the magnitude is unsigned.
+                #[inline]
+                pub fn delta(&self) -> Delta<$magnitude> {
+                    self.map(|value| value.get()).as_ref().delta()
+                }
+
+                /// Return the change from before to after as a percentage of
+                /// the magnitude of before.
+                ///
+                /// This always returns `Some`, since before is never zero.
+                #[inline]
+                pub fn percent_change(&self) -> Option<f64> {
+                    self.map(|value| value.get()).as_ref().percent_change()
+                }
+            }
+        )*
+    }
+}
+
+non_zero_delta! {
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32,
+    NonZeroU64 => u64, NonZeroU128 => u128, NonZeroUsize => usize,
+    NonZeroI8 => u8, NonZeroI16 => u16, NonZeroI32 => u32,
+    NonZeroI64 => u64, NonZeroI128 => u128, NonZeroIsize => usize
+}
+
+impl Leaf<&Duration> {
+    /// Return the signed difference from before to after.
+    #[inline]
+    pub fn delta(&self) -> Delta<Duration> {
+        let (before, after) = (*self.before, *self.after);
+        if after > before {
+            Delta::Increased(after - before)
+        } else if after < before {
+            Delta::Decreased(before - after)
+        } else {
+            Delta::Unchanged
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Leaf<&Instant> {
+    /// Return the signed difference from before to after.
+    #[inline]
+    pub fn delta(&self) -> Delta<Duration> {
+        match self.after.checked_duration_since(*self.before) {
+            Some(elapsed) if elapsed.is_zero() => Delta::Unchanged,
+            Some(elapsed) => Delta::Increased(elapsed),
+            None => Delta::Decreased(*self.before - *self.after),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Leaf<&SystemTime> {
+    /// Return the signed difference from before to after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use daft::{Delta, Diffable};
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let before = SystemTime::UNIX_EPOCH;
+    /// let after = before + Duration::from_secs(60);
+    /// let delta = before.diff(&after).delta();
+    /// assert_eq!(delta, Delta::Increased(Duration::from_secs(60)));
+    /// ```
+    #[inline]
+    pub fn delta(&self) -> Delta<Duration> {
+        match self.after.duration_since(*self.before) {
+            Ok(elapsed) if elapsed.is_zero() => Delta::Unchanged,
+            Ok(elapsed) => Delta::Increased(elapsed),
+            Err(error) => Delta::Decreased(error.duration()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Diffable;
+    #[cfg(feature = "alloc")]
+    use alloc::string::ToString;
+
+    #[test]
+    fn duration_delta() {
+        let secs = Duration::from_secs;
+        assert_eq!(secs(1).diff(&secs(4)).delta(), Delta::Increased(secs(3)));
+        assert_eq!(secs(4).diff(&secs(1)).delta(), Delta::Decreased(secs(3)));
+        assert_eq!(secs(4).diff(&secs(4)).delta(), Delta::Unchanged);
+        assert_eq!(
+            Duration::ZERO.diff(&Duration::MAX).delta().magnitude(),
+            Some(&Duration::MAX)
+        );
+    }
+
+    #[test]
+    fn int_delta() {
+        assert_eq!(i8::MIN.diff(&i8::MAX).delta(), Delta::Increased(u8::MAX));
+        assert_eq!(i8::MAX.diff(&i8::MIN).delta(), Delta::Decreased(u8::MAX));
+        assert_eq!(
+            0_u128.diff(&u128::MAX).delta(),
+            Delta::Increased(u128::MAX)
+        );
+        assert_eq!(
+            i128::MAX.diff(&i128::MIN).delta(),
+            Delta::Decreased(u128::MAX)
+        );
+        assert_eq!(7_u32.diff(&7).delta(), Delta::Unchanged);
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(7_u32.diff(&7).delta().to_string(), "0");
+            assert_eq!(5_i32.diff(&-3).delta().to_string(), "-8");
+        }
+        assert_eq!(5_i32.diff(&-3).delta().direction(), Direction::Decreased);
+
+        assert_eq!(50_u64.diff(&60).percent_change(), Some(20.0));
+        assert_eq!(50_u64.diff(&25).percent_change(), Some(-50.0));
+        assert_eq!((-50_i64).diff(&-60).percent_change(), Some(-20.0));
+        assert_eq!((-50_i64).diff(&50).percent_change(), Some(200.0));
+        assert_eq!(0_i64.diff(&1).percent_change(), None);
+        assert_eq!(i128::MIN.diff(&i128::MAX).percent_change(), Some(200.0));
+
+        let one = NonZeroU64::MIN;
+        let max = NonZeroU64::MAX;
+        assert_eq!(one.diff(&max).delta(), Delta::Increased(u64::MAX - 1));
+        assert_eq!(max.diff(&max).percent_change(), Some(0.0));
+        let neg = NonZeroI8::new(-4).unwrap();
+        let pos = NonZeroI8::new(2).unwrap();
+        assert_eq!(neg.diff(&pos).delta(), Delta::Increased(6));
+        assert_eq!(neg.diff(&pos).percent_change(), Some(150.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn system_time_delta() {
+        let before = SystemTime::UNIX_EPOCH + Duration::from_millis(1500);
+        let after = SystemTime::UNIX_EPOCH;
+        let delta = before.diff(&after).delta();
+        assert_eq!(delta, Delta::Decreased(Duration::from_millis(1500)));
+        assert!(after.diff(&after).delta().is_unchanged());
+    }
+}
@@ -0,0 +1,124 @@
+//! Inverting diffs.
+
+use crate::Leaf;
+use core::mem;
+
+/// A diff that can be inverted by swapping its before and after sides.
+///
+/// Inverting a diff from `a` to `b` produces the diff from `b` to `a`. For
+/// map and set diffs, this swaps the `added` and `removed` entries. This is
+/// useful for previewing a rollback: given the diff for a change, the
+/// inverted diff shows what undoing the change would do.
+///
+/// This trait is implemented for [`Leaf`], for all of the map, set and
+/// sequence diff types in this crate, for tuples and arrays of diffs, and for
+/// the owned forms of all of these. The [`Diffable`](macro@crate::Diffable)
+/// derive macro implements it for generated diff types, by inverting the diff
+/// of each field that isn't ignored.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use daft::{Diffable, Invert};
+/// use std::collections::BTreeSet;
+///
+/// let before: BTreeSet<u32> = [1, 2].into();
+/// let after: BTreeSet<u32> = [2, 3].into();
+///
+/// // Inverting a diff is the same as diffing in the other direction.
+/// assert_eq!(before.diff(&after).invert(), after.diff(&before));
+/// # }
+/// ```
+pub trait Invert {
+    /// Invert this diff, swapping its before and after sides.
+    fn invert(self) -> Self;
+}
+
+impl<T> Invert for Leaf<T> {
+    #[inline]
+    fn invert(mut self) -> Self {
+        mem::swap(&mut self.before, &mut self.after);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Diffable;
+
+    #[test]
+    fn leaf_and_tuple_invert() {
+        let before = (1_u32, Some('a'), "x");
+        let after = (2, None, "x");
+        assert_eq!(before.diff(&after).invert(), after.diff(&before));
+
+        // Owned diffs of `&str` require `alloc`.
+        #[cfg(feature = "alloc")]
+        {
+            use crate::IntoOwnedDiff;
+
+            assert_eq!(
+                before.diff(&after).into_owned().invert(),
+                after.diff(&before).into_owned()
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn collections_invert() {
+        use crate::{IntoOwnedDiff, KeyedDiff, SeqDiff, SeqEditKind};
+        use std::collections::{BTreeMap, HashSet};
+
+        let before: BTreeMap<u32, &str> = [(1, "a"), (2, "b")].into();
+        let after: BTreeMap<u32, &str> = [(2, "c"), (3, "d")].into();
+        assert_eq!(before.diff(&after).invert(), after.diff(&before));
+        assert_eq!(
+            before.diff(&after).into_owned().invert(),
+            after.diff(&before).into_owned()
+        );
+
+        let before: HashSet<u32> = [1, 2].into();
+        let after: HashSet<u32> = [2, 3].into();
+        assert_eq!(before.diff(&after).invert(), after.diff(&before));
+
+        // Applying the inverted edit script to `after` reconstructs `before`.
+        let before = ["a", "b", "c", "d"];
+        let after = ["a", "x", "c", "e", "f"];
+        let inverted = SeqDiff::new(&before, &after).invert();
+        assert_eq!(inverted.before, after.iter().collect::<Vec<_>>());
+        let mut rebuilt = Vec::new();
+        for edit in &inverted.edits {
+            match edit.kind {
+                SeqEditKind::Equal => {
+                    assert_eq!(
+                        inverted.before_items(edit),
+                        inverted.after_items(edit)
+                    );
+                    rebuilt.extend_from_slice(inverted.before_items(edit));
+                }
+                SeqEditKind::Insert | SeqEditKind::Replace => {
+                    rebuilt.extend_from_slice(inverted.after_items(edit));
+                }
+                SeqEditKind::Delete => {}
+            }
+        }
+        assert_eq!(rebuilt, before.iter().collect::<Vec<_>>());
+
+        let before = [(1, 'a'), (2, 'b'), (3, 'c')];
+        let after = [(3, 'c'), (1, 'x'), (4, 'd')];
+        fn key((k, _): &(u32, char)) -> &u32 {
+            k
+        }
+        assert_eq!(
+            KeyedDiff::new(&before, &after, key).invert(),
+            KeyedDiff::new(&after, &before, key)
+        );
+        assert_eq!(
+            KeyedDiff::new(&before, &after, key).into_owned().invert(),
+            KeyedDiff::new(&after, &before, key).into_owned()
+        );
+    }
+}
@@ -0,0 +1,351 @@
+//! Keyed diffs of sequences.
+
+use crate::{
+    BTreeMapDiff, BTreeMapDiffOwned, DiffPath, DiffSummary, DiffVisitor,
+    Diffable, IntoOwnedDiff, Invert, Leaf, VisitDiff, seq::compute_edits,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use core::fmt;
+
+/// A diff of two sequences of records, treating each sequence as a map from
+/// a key to an element.
+///
+/// Many vectors are really collections of records with a stable identifier.
+/// For such vectors, neither a [`Leaf`] nor an ordered [`SeqDiff`] is very
+/// useful: what matters is which records were added, removed or changed,
+/// matched up by their identifier. `KeyedDiff` extracts a key from each
+/// element, and diffs the two sides as if they were [`BTreeMap`]s from key to
+/// element.
+///
+/// The diff contains three elements:
+///
+/// - `entries`: A [`BTreeMapDiff`] from key to element. All of the methods on
+///   map diffs, such as [`modified`][BTreeMapDiff::modified] and
+///   [`modified_diff`][BTreeMapDiff::modified_diff], are available through it.
+/// - `positions`: For entries common to both sides, the index of the element
+///   in the before and after sequences.
+/// - `moved`: The keys of common entries whose order relative to other common
+///   entries has changed. Entries that only shift because of additions or
+///   removals elsewhere are not considered to have moved.
+///
+/// If several elements on the same side have the same key, the last one
+/// wins, as with collecting into a [`BTreeMap`].
+///
+/// `KeyedDiff` can be created directly with [`KeyedDiff::new`], from the
+/// [`Leaf`] produced by diffing a `Vec` or slice with [`Leaf::keyed_diff`], or
+/// for a struct field with the `#[daft(keyed_by = path, key = Type)]`
+/// attribute on the [`Diffable`](macro@crate::Diffable) derive macro.
+///
+/// [`SeqDiff`]: crate::SeqDiff
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use daft::{KeyedDiff, Leaf};
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct Rule {
+///     id: u32,
+///     action: &'static str,
+/// }
+///
+/// fn rule_id(rule: &Rule) -> &u32 {
+///     &rule.id
+/// }
+///
+/// let before = vec![
+///     Rule { id: 1, action: "allow" },
+///     Rule { id: 2, action: "deny" },
+///     Rule { id: 3, action: "allow" },
+/// ];
+/// let after = vec![
+///     Rule { id: 2, action: "deny" },
+///     Rule { id: 1, action: "deny" },
+///     Rule { id: 4, action: "allow" },
+/// ];
+///
+/// let diff: KeyedDiff<'_, u32, Rule> = KeyedDiff::new(&before, &after, rule_id);
+///
+/// assert_eq!(diff.entries.added.keys().copied().collect::<Vec<_>>(), [&4]);
+/// assert_eq!(diff.entries.removed.keys().copied().collect::<Vec<_>>(), [&3]);
+/// assert_eq!(diff.entries.modified_keys().collect::<Vec<_>>(), [&1]);
+///
+/// // Rules 1 and 2 swapped places.
+/// assert_eq!(diff.positions[&1], Leaf { before: 0, after: 1 });
+/// assert_eq!(diff.positions[&2], Leaf { before: 1, after: 0 });
+/// assert_eq!(diff.moved.len(), 1);
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct KeyedDiff<'daft, K: Ord + Eq, V> {
+    /// The diff of the two sides, as maps from key to element.
+    pub entries: BTreeMapDiff<'daft, K, V>,
+
+    /// The positions of entries common to both sides.
+    pub positions: BTreeMap<&'daft K, Leaf<usize>>,
+
+    /// Keys of common entries whose relative order has changed.
+    pub moved: BTreeSet<&'daft K>,
+}
+
+impl<'daft, K: Ord + Eq, V> KeyedDiff<'daft, K, V> {
+    /// Compute a keyed diff of two sequences, using `key` to extract the key
+    /// for each element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use daft::KeyedDiff;
+    ///
+    /// let before = [("a", 1), ("b", 2)];
+    /// let after = [("b", 3), ("c", 4)];
+    ///
+    /// let diff = KeyedDiff::new(&before, &after, |(k, _)| k);
+    /// assert_eq!(diff.entries.added.len(), 1);
+    /// assert_eq!(diff.entries.removed.len(), 1);
+    /// assert_eq!(diff.entries.modified().count(), 1);
+    /// # }
+    /// ```
+    pub fn new<B, A, F>(before: B, after: A, mut key: F) -> Self
+    where
+        B: IntoIterator<Item = &'daft V>,
+        A: IntoIterator<Item = &'daft V>,
+        F: FnMut(&'daft V) -> &'daft K,
+    {
+        let before: BTreeMap<&'daft K, (usize, &'daft V)> = before
+            .into_iter()
+            .enumerate()
+            .map(|(ix, v)| (key(v), (ix, v)))
+            .collect();
+        let after: BTreeMap<&'daft K, (usize, &'daft V)> = after
+            .into_iter()
+            .enumerate()
+            .map(|(ix, v)| (key(v), (ix, v)))
+            .collect();
+
+        let mut diff = Self::default();
+        for (k, &(before_ix, v)) in &before {
+            if let Some(&(after_ix, other_v)) = after.get(k) {
+                diff.entries
+                    .common
+                    .insert(k, Leaf { before: v, after: other_v });
+                diff.positions
+                    .insert(k, Leaf { before: before_ix, after: after_ix });
+            } else {
+                diff.entries.removed.insert(k, v);
+            }
+        }
+        for (k, &(_, v)) in &after {
+            if !before.contains_key(k) {
+                diff.entries.added.insert(k, v);
+            }
+        }
+
+        diff.moved = moved_keys(&diff.positions).into_iter().copied().collect();
+        diff
+    }
+}
+
+// Note: not deriving Default here because we don't want to require K or V to
+// be Default.
+impl<'daft, K: Ord + Eq, V> Default for KeyedDiff<'daft, K, V> {
+    fn default() -> Self {
+        Self {
+            entries: BTreeMapDiff::new(),
+            positions: BTreeMap::new(),
+            moved: BTreeSet::new(),
+        }
+    }
+}
+
+/// A keyed diff is unchanged if no entries were added, removed or modified,
+/// and no entries moved relative to each other.
+impl<K: Ord + Eq, V: Eq> DiffSummary for KeyedDiff<'_, K, V> {
+    fn is_unchanged(&self) -> bool {
+        DiffSummary::is_unchanged(&self.entries) && self.moved.is_empty()
+    }
+}
+
+/// Entries are visited as for [`BTreeMapDiff`]. Moved entries are not
+/// reported; use [`KeyedDiff::moved`] to find them.
+impl<'daft, K, V> VisitDiff for KeyedDiff<'daft, K, V>
+where
+    K: Ord + Eq + fmt::Debug,
+    V: Diffable + fmt::Debug,
+    V::Diff<'daft>: VisitDiff,
+{
+    fn visit_at(&self, path: &DiffPath<'_>, visitor: &mut dyn DiffVisitor) {
+        self.entries.visit_at(path, visitor);
+    }
+}
+
+/// An owned version of [`KeyedDiff`], created with
+/// [`IntoOwnedDiff::into_owned`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct KeyedDiffOwned<K: Ord + Eq, V> {
+    /// The diff of the two sides, as maps from key to element.
+    pub entries: BTreeMapDiffOwned<K, V>,
+
+    /// The positions of entries common to both sides.
+    pub positions: BTreeMap<K, Leaf<usize>>,
+
+    /// Keys of common entries whose relative order has changed.
+    pub moved: BTreeSet<K>,
+}
+
+impl<K: Ord + Eq + Clone, V: Clone> IntoOwnedDiff for KeyedDiff<'_, K, V> {
+    type Owned = KeyedDiffOwned<K, V>;
+
+    fn into_owned(self) -> Self::Owned {
+        KeyedDiffOwned {
+            entries: self.entries.into_owned(),
+            positions: self
+                .positions
+                .into_iter()
+                .map(|(k, pos)| (k.clone(), pos))
+                .collect(),
+            moved: self.moved.into_iter().cloned().collect(),
+        }
+    }
+}
+
+/// The keys of moved entries are recomputed from the inverted positions.
+impl<K: Ord + Eq, V> Invert for KeyedDiff<'_, K, V> {
+    fn invert(self) -> Self {
+        let positions: BTreeMap<_, _> = self
+            .positions
+            .into_iter()
+            .map(|(k, pos)| (k, pos.invert()))
+            .collect();
+        let moved = moved_keys(&positions).into_iter().copied().collect();
+        Self { entries: self.entries.invert(), positions, moved }
+    }
+}
+
+/// The keys of moved entries are recomputed from the inverted positions.
+impl<K: Ord + Eq + Clone, V> Invert for KeyedDiffOwned<K, V> {
+    fn invert(self) -> Self {
+        let positions: BTreeMap<_, _> = self
+            .positions
+            .into_iter()
+            .map(|(k, pos)| (k, pos.invert()))
+            .collect();
+        let moved = moved_keys(&positions).into_iter().cloned().collect();
+        Self { entries: self.entries.invert(), positions, moved }
+    }
+}
+
+impl<'daft, T> Leaf<&'daft [T]> {
+    /// Perform a keyed diff of [`before`][Self::before] and
+    /// [`after`][Self::after], returning a [`KeyedDiff`].
+    ///
+    /// The [`Diffable`](crate::Diffable) implementations for `Vec<T>` and
+    /// `[T]` return a `Leaf<&[T]>`; this method treats both sides as maps
+    /// from `key(element)` to the element. See [`KeyedDiff`] for more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use daft::Diffable;
+    ///
+    /// let before: Vec<(char, u32)> = vec![('a', 1), ('b', 2)];
+    /// let after: Vec<(char, u32)> = vec![('b', 2), ('a', 1)];
+    ///
+    /// let diff = before.diff(&after).keyed_diff(|(k, _)| k);
+    /// assert_eq!(diff.entries.unchanged().count(), 2);
+    /// assert_eq!(diff.moved.len(), 1);
+    /// # }
+    /// ```
+    pub fn keyed_diff<K, F>(self, key: F) -> KeyedDiff<'daft, K, T>
+    where
+        K: Ord + Eq,
+        F: FnMut(&'daft T) -> &'daft K,
+    {
+        KeyedDiff::new(self.before, self.after, key)
+    }
+}
+
+/// Find the keys whose relative order changed, i.e. the common keys that are
+/// not part of a longest common subsequence of the before and after orders.
+fn moved_keys<K: Ord>(positions: &BTreeMap<K, Leaf<usize>>) -> Vec<&K> {
+    let mut before_order: Vec<_> = positions.iter().collect();
+    before_order.sort_by_key(|(_, pos)| pos.before);
+    let mut after_order: Vec<_> = positions.iter().collect();
+    after_order.sort_by_key(|(_, pos)| pos.after);
+
+    let edits = compute_edits(before_order.len(), after_order.len(), |i, j| {
+        before_order[i].0 == after_order[j].0
+    });
+    edits
+        .iter()
+        .filter(|edit| edit.kind != crate::SeqEditKind::Equal)
+        .flat_map(|edit| before_order[edit.before.clone()].iter())
+        .map(|(k, _)| *k)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Diffable;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Record {
+        id: u32,
+        value: &'static str,
+    }
+    leaf!(Record);
+
+    fn id(record: &Record) -> &u32 {
+        &record.id
+    }
+
+    fn records(items: &[(u32, &'static str)]) -> Vec<Record> {
+        items.iter().map(|&(id, value)| Record { id, value }).collect()
+    }
+
+    #[test]
+    fn keyed_diff_basic() {
+        let before = records(&[(1, "a"), (2, "b"), (3, "c")]);
+        let after = records(&[(1, "a"), (3, "C"), (4, "d")]);
+
+        let diff = before.diff(&after).keyed_diff(id);
+        assert_eq!(diff.entries.unchanged_keys().collect::<Vec<_>>(), [&1]);
+        assert_eq!(diff.entries.modified_keys().collect::<Vec<_>>(), [&3]);
+        assert_eq!(
+            diff.entries.added.values().copied().collect::<Vec<_>>(),
+            [&Record { id: 4, value: "d" }]
+        );
+        assert_eq!(
+            diff.entries.removed.values().copied().collect::<Vec<_>>(),
+            [&Record { id: 2, value: "b" }]
+        );
+
+        // 3 shifted down because 2 was removed, but it didn't move relative to
+        // 1.
+        assert_eq!(diff.positions[&3], Leaf { before: 2, after: 1 });
+        assert!(diff.moved.is_empty());
+    }
+
+    #[test]
+    fn keyed_diff_moved() {
+        let before = records(&[(1, "a"), (2, "b"), (3, "c"), (4, "d")]);
+        let after = records(&[(2, "b"), (3, "c"), (4, "d"), (1, "a")]);
+
+        let diff = before.diff(&after).keyed_diff(id);
+        assert_eq!(diff.entries.modified().count(), 0);
+        assert_eq!(diff.moved.into_iter().collect::<Vec<_>>(), [&1]);
+
+        let empty: Vec<Record> = Vec::new();
+        let diff = empty.diff(&empty).keyed_diff(id);
+        assert_eq!(diff, KeyedDiff::default());
+    }
+}
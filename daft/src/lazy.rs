@@ -0,0 +1,291 @@
+//! Lazy diffs of sorted maps.
+
+use crate::Leaf;
+use core::{
+    cmp::Ordering,
+    fmt,
+    iter::{FusedIterator, Peekable},
+};
+
+/// A lazy diff of two maps, computed by walking both maps in key order.
+///
+/// The eager map diffs such as [`BTreeMapDiff`](crate::BTreeMapDiff) build
+/// three new maps for every diff. For large maps where only a few entries
+/// change, that's a lot of work. `LazyMapDiff` is an iterator that instead
+/// walks both maps in a single pass, yielding a [`MapDiffItem`] for each key
+/// in ascending order. It doesn't allocate, so it is available without the
+/// `alloc` feature.
+///
+/// `LazyMapDiff` works with any two iterators over `(&K, &V)` pairs that are
+/// sorted by key with no duplicate keys, such as `&BTreeMap<K, V>` or a
+/// sorted slice of pairs. If the iterators aren't sorted, the items are
+/// unspecified, though the iterator will still terminate.
+///
+/// Use [`LazyMapDiff::changes`] to skip over unchanged entries.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use daft::{LazyMapDiff, Leaf, MapDiffItem};
+/// use std::collections::BTreeMap;
+///
+/// let before: BTreeMap<u32, &str> =
+///     (0..1000).map(|i| (i, "same")).chain([(1000, "old")]).collect();
+/// let mut after = before.clone();
+/// after.insert(1000, "new");
+/// after.insert(1001, "added");
+///
+/// let changes: Vec<_> = LazyMapDiff::new(&before, &after).changes().collect();
+/// assert_eq!(
+///     changes,
+///     [
+///         MapDiffItem::Common(&1000, Leaf { before: &"old", after: &"new" }),
+///         MapDiffItem::Added(&1001, &"added"),
+///     ],
+/// );
+/// # }
+/// ```
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct LazyMapDiff<B: Iterator, A: Iterator> {
+    before: Peekable<B>,
+    after: Peekable<A>,
+}
+
+impl<'daft, K, V, B, A> LazyMapDiff<B, A>
+where
+    K: Ord + 'daft,
+    V: 'daft,
+    B: Iterator<Item = (&'daft K, &'daft V)>,
+    A: Iterator<Item = (&'daft K, &'daft V)>,
+{
+    /// Create a lazy diff of two maps, each given as an iterator over
+    /// `(&K, &V)` pairs sorted by key.
+    pub fn new<BI, AI>(before: BI, after: AI) -> Self
+    where
+        BI: IntoIterator<IntoIter = B>,
+        AI: IntoIterator<IntoIter = A>,
+    {
+        Self {
+            before: before.into_iter().peekable(),
+            after: after.into_iter().peekable(),
+        }
+    }
+
+    /// Return an iterator over the entries that were added, removed, or
+    /// modified, skipping over unchanged entries.
+    pub fn changes(self) -> impl Iterator<Item = MapDiffItem<'daft, K, V>>
+    where
+        V: Eq,
+    {
+        self.filter(|item| !item.is_unchanged())
+    }
+}
+
+impl<'daft, K, V, B, A> Iterator for LazyMapDiff<B, A>
+where
+    K: Ord + 'daft,
+    V: 'daft,
+    B: Iterator<Item = (&'daft K, &'daft V)>,
+    A: Iterator<Item = (&'daft K, &'daft V)>,
+{
+    type Item = MapDiffItem<'daft, K, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let order = match (self.before.peek(), self.after.peek()) {
+            (None, None) => return None,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some((before, _)), Some((after, _))) => before.cmp(after),
+        };
+        match order {
+            Ordering::Less => {
+                let (key, value) = self.before.next()?;
+                Some(MapDiffItem::Removed(key, value))
+            }
+            Ordering::Greater => {
+                let (key, value) = self.after.next()?;
+                Some(MapDiffItem::Added(key, value))
+            }
+            Ordering::Equal => {
+                let (key, before) = self.before.next()?;
+                let (_, after) = self.after.next()?;
+                Some(MapDiffItem::Common(key, Leaf { before, after }))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (before_lo, before_hi) = self.before.size_hint();
+        let (after_lo, after_hi) = self.after.size_hint();
+        let hi = match (before_hi, after_hi) {
+            (Some(before), Some(after)) => before.checked_add(after),
+            _ => None,
+        };
+        (before_lo.max(after_lo), hi)
+    }
+}
+
+impl<'daft, K, V, B, A> FusedIterator for LazyMapDiff<B, A>
+where
+    K: Ord + 'daft,
+    V: 'daft,
+    B: FusedIterator<Item = (&'daft K, &'daft V)>,
+    A: FusedIterator<Item = (&'daft K, &'daft V)>,
+{
+}
+
+// Implemented by hand, since `Peekable` also requires the items to be `Clone`
+// and `Debug`.
+impl<B, A> Clone for LazyMapDiff<B, A>
+where
+    B: Iterator<Item: Clone> + Clone,
+    A: Iterator<Item: Clone> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self { before: self.before.clone(), after: self.after.clone() }
+    }
+}
+
+impl<B, A> fmt::Debug for LazyMapDiff<B, A>
+where
+    B: Iterator<Item: fmt::Debug> + fmt::Debug,
+    A: Iterator<Item: fmt::Debug> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyMapDiff")
+            .field("before", &self.before)
+            .field("after", &self.after)
+            .finish()
+    }
+}
+
+/// An entry in a [`LazyMapDiff`].
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum MapDiffItem<'daft, K, V> {
+    /// An entry present in `after`, but not in `before`.
+    Added(&'daft K, &'daft V),
+
+    /// An entry present in `before`, but not in `after`.
+    Removed(&'daft K, &'daft V),
+
+    /// An entry present in both maps, with its values stored as a [`Leaf`].
+    Common(&'daft K, Leaf<&'daft V>),
+}
+
+impl<'daft, K, V> MapDiffItem<'daft, K, V> {
+    /// Return the key of this entry.
+    #[inline]
+    pub fn key(&self) -> &'daft K {
+        match self {
+            Self::Added(key, _)
+            | Self::Removed(key, _)
+            | Self::Common(key, _) => key,
+        }
+    }
+
+    /// Return true if this entry is present in both maps with equal values.
+    #[inline]
+    pub fn is_unchanged(&self) -> bool
+    where
+        V: Eq,
+    {
+        match self {
+            Self::Common(_, leaf) => leaf.is_unchanged(),
+            Self::Added(..) | Self::Removed(..) => false,
+        }
+    }
+}
+
+// Implemented by hand, since the entries are references and don't need to be
+// `Clone` or `Copy` themselves.
+impl<K, V> Clone for MapDiffItem<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V> Copy for MapDiffItem<'_, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(
+        map: &[(u32, char)],
+    ) -> impl Iterator<Item = (&u32, &char)> + Clone {
+        map.iter().map(|(k, v)| (k, v))
+    }
+
+    #[test]
+    fn lazy_map_diff() {
+        // Sorted slices of pairs work without allocation.
+        let before = [(1, 'a'), (2, 'b'), (4, 'd'), (5, 'e')];
+        let after = [(0, 'z'), (2, 'b'), (4, 'x'), (6, 'f')];
+
+        let mut diff = LazyMapDiff::new(pairs(&before), pairs(&after));
+        assert_eq!(diff.size_hint(), (4, Some(8)));
+        assert_eq!(diff.next(), Some(MapDiffItem::Added(&0, &'z')));
+        assert_eq!(diff.next(), Some(MapDiffItem::Removed(&1, &'a')));
+        let common = diff.next().unwrap();
+        assert_eq!(common.key(), &2);
+        assert!(common.is_unchanged());
+        assert_eq!(
+            diff.next(),
+            Some(MapDiffItem::Common(&4, Leaf { before: &'d', after: &'x' }))
+        );
+        assert_eq!(diff.next(), Some(MapDiffItem::Removed(&5, &'e')));
+        assert_eq!(diff.next(), Some(MapDiffItem::Added(&6, &'f')));
+        assert_eq!(diff.next(), None);
+        assert_eq!(diff.next(), None);
+
+        let diff = LazyMapDiff::new(pairs(&before), pairs(&after));
+        assert_eq!(diff.clone().changes().count(), 5);
+        assert!(diff.changes().all(|item| *item.key() != 2));
+        let diff = LazyMapDiff::new(pairs(&before), pairs(&before));
+        assert_eq!(diff.changes().count(), 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn lazy_map_diff_matches_eager() {
+        use crate::Diffable;
+        use alloc::{collections::BTreeMap, vec::Vec};
+
+        let before: BTreeMap<u32, u32> = (0..100).map(|i| (i, i)).collect();
+        let after: BTreeMap<u32, u32> = (50..150)
+            .map(|i| (i, if i % 7 == 0 { i + 1 } else { i }))
+            .collect();
+        let eager = before.diff(&after);
+
+        let lazy: Vec<_> = LazyMapDiff::new(&before, &after).collect();
+        let added: Vec<_> = lazy
+            .iter()
+            .filter_map(|item| match item {
+                MapDiffItem::Added(k, v) => Some((*k, *v)),
+                _ => None,
+            })
+            .collect();
+        let removed: Vec<_> = lazy
+            .iter()
+            .filter_map(|item| match item {
+                MapDiffItem::Removed(k, v) => Some((*k, *v)),
+                _ => None,
+            })
+            .collect();
+        let modified: Vec<_> = lazy
+            .iter()
+            .filter(|item| matches!(item, MapDiffItem::Common(..)))
+            .filter(|item| !item.is_unchanged())
+            .map(|item| *item.key())
+            .collect();
+        assert_eq!(
+            modified,
+            eager.modified_keys().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(added, eager.added.into_iter().collect::<Vec<_>>());
+        assert_eq!(removed, eager.removed.into_iter().collect::<Vec<_>>());
+    }
+}
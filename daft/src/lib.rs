@@ -54,7 +54,7 @@
 //!
 //! This crate assigns one side the name *before*, and the other side *after*.
 //! These labels are arbitrary: if *before* and *after* are swapped, the diff is
-//! reversed.
+//! reversed. An existing diff can also be reversed with [`Invert`].
 //!
 //! ## Diff types
 //!
@@ -66,12 +66,21 @@
 //! point at which diffing stops. [`Leaf`] instances are used for:
 //!
 //! * *Scalar* or *primitive types* like `i32`, `String`, `bool`, etc.
-//! * *Enums*, since diffing across variants is usually not meaningful.
+//! * Time types like [`Duration`](core::time::Duration) and `SystemTime`.
+//! * *Enums*, since diffing across variants is usually not meaningful. (A
+//!   variant-aware recursive diff is available as an opt-in: see
+//!   [*Enum diffs*](#enum-diffs) below.)
 //! * Vector and slice types, since there are several reasonable ways to diff
 //!   vectors (e.g. set-like, ordered, etc.) and we don't want to make assumptions.
+//!   (An ordered diff is available as an opt-in: see
+//!   [*Sequence diffs*](#sequence-diffs) below.)
 //! * As an opt-in mechanism for struct fields: see
 //!   [*Recursive diffs*](#recursive-diffs) below for more.
 //!
+//! For integers and time types, [`Leaf::delta`] returns the signed difference
+//! from before to after as a [`Delta`]. Integer leaves also have a
+//! `percent_change` method.
+//!
 //! #### Example
 //!
 //! A contrived example for integers:
@@ -125,6 +134,67 @@
 //! # }
 //! ```
 //!
+//! ### Sequence diffs
+//!
+//! For an ordered diff of vectors and slices, call [`Leaf::seq_diff`] on the
+//! leaf to get a [`SeqDiff`]. A `SeqDiff` is an edit script made up of runs of
+//! equal, inserted, deleted, and replaced elements, with index ranges on both
+//! sides. [`VecDeque`] and [`LinkedList`] are also diffed as leaves, and have
+//! the same `seq_diff` method.
+//!
+//! #### Example
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use daft::{Diffable, SeqDiff, SeqEditKind};
+//!
+//! let before = vec![1, 2, 3, 4];
+//! let after = vec![1, 3, 4, 5];
+//! let diff: SeqDiff<'_, i32> = before.diff(&after).seq_diff();
+//!
+//! let kinds = diff.edits.iter().map(|edit| edit.kind).collect::<Vec<_>>();
+//! assert_eq!(
+//!     kinds,
+//!     [
+//!         SeqEditKind::Equal,
+//!         SeqEditKind::Delete,
+//!         SeqEditKind::Equal,
+//!         SeqEditKind::Insert,
+//!     ],
+//! );
+//!
+//! // Deleted elements are at index 1 on the before side.
+//! let deleted = &diff.edits[1];
+//! assert_eq!(deleted.before, 1..2);
+//! assert_eq!(diff.before_items(deleted), [&2]);
+//! # }
+//! ```
+//!
+//! ### Text diffs
+//!
+//! Strings are also diffed as leaves. For a line-level diff of long strings,
+//! call [`Leaf::text_diff`] on the leaf to get a [`TextDiff`], or annotate a
+//! struct field with `#[daft(text)]`. Word and character-level diffs can be
+//! created with [`TextDiff::new`]. A `TextDiff` can be grouped into hunks,
+//! and rendered in the unified diff format with [`TextDiff::unified`].
+//!
+//! #### Example
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use daft::Diffable;
+//!
+//! let before = "alpha\nbeta\ngamma\n".to_owned();
+//! let after = "alpha\nbeta\ndelta\n".to_owned();
+//! let diff = before.diff(&after).text_diff();
+//!
+//! assert_eq!(
+//!     diff.unified().context(1).to_string(),
+//!     "@@ -2,2 +2,2 @@\n beta\n-gamma\n+delta\n",
+//! );
+//! # }
+//! ```
+//!
 //! ### Map diffs
 //!
 //! For [`BTreeMap`] and [`HashMap`], daft has corresponding [`BTreeMapDiff`]
@@ -183,6 +253,20 @@
 //! # }
 //! ```
 //!
+//! For large maps where only a few entries change, building these maps can be
+//! expensive. [`LazyMapDiff`] instead walks two sorted maps, such as
+//! [`BTreeMap`]s, in a single pass, and yields a [`MapDiffItem`] for each key
+//! without allocating. Use [`LazyMapDiff::changes`] to skip unchanged entries.
+//! `LazyMapDiff` does not require the `alloc` feature.
+//!
+//! To store the diffs of common values rather than [`Leaf`]s, use
+//! [`DiffableMap::recursive_diff`], or annotate a struct field with
+//! `#[daft(map_values = "recursive")]`.
+//!
+//! With the `indexmap` feature, `IndexMap` and `IndexSet` are supported as
+//! well. Since their order is meaningful, their diffs also have an *order*
+//! field, an [`OrderDiff`] that describes how common entries were reordered.
+//!
 //! ### Set diffs
 //!
 //! For [`BTreeSet`] and [`HashSet`], daft has corresponding [`BTreeSetDiff`]
@@ -208,6 +292,30 @@
 //! # }
 //! ```
 //!
+//! ### Multiset diffs
+//!
+//! [`BinaryHeap`] is diffed as a multiset, since its iteration order is
+//! arbitrary. The resulting [`MultisetDiff`] has *common*, *added*, and
+//! *removed* maps from elements to their number of occurrences. Other
+//! collections can be diffed as multisets with [`MultisetDiff::new`].
+//!
+//! #### Example
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use daft::{Diffable, MultisetDiff};
+//! use std::collections::BinaryHeap;
+//!
+//! let before: BinaryHeap<&str> = ["a", "b", "b"].into();
+//! let after: BinaryHeap<&str> = ["b", "b", "b", "c"].into();
+//!
+//! let diff: MultisetDiff<'_, &str> = before.diff(&after);
+//! assert_eq!(diff.common, [(&"b", 2)].into());
+//! assert_eq!(diff.added, [(&"b", 1), (&"c", 1)].into());
+//! assert_eq!(diff.removed, [(&"a", 1)].into());
+//! # }
+//! ```
+//!
 //! ### Tuple diffs
 //!
 //! For a tuple like `(A, B, C)`, the [`Diffable`] implementation is recursive:
@@ -239,6 +347,47 @@
 //! # }
 //! ```
 //!
+//! ### Array diffs
+//!
+//! For a fixed-size array `[T; N]`, both sides always have the same length, so
+//! the [`Diffable`] implementation is recursive: the diff resolves to
+//! `[T::Diff; N]`, with the diff of each pair of elements. Array diffs don't
+//! require allocation.
+//!
+//! #### Example
+//!
+//! ```rust
+//! use daft::{Diffable, Leaf};
+//!
+//! let before = [(1, 'a'), (2, 'b'), (3, 'c')];
+//! let after = [(1, 'a'), (2, 'x'), (3, 'c')];
+//!
+//! let diff = before.diff(&after);
+//! assert_eq!(diff[0].1, Leaf { before: &'a', after: &'a' });
+//! assert_eq!(diff[1].1, Leaf { before: &'b', after: &'x' });
+//! ```
+//!
+//! ### Shared pointer diffs
+//!
+//! The [`Diffable`] implementations for [`Arc`](alloc::sync::Arc) and
+//! [`Rc`](alloc::rc::Rc) diff the values they point to. For structurally
+//! shared data, wrap the pointer in [`Shared`]: its diff is a [`SharedDiff`],
+//! which skips diffing the value entirely if both sides point to the same
+//! allocation.
+//!
+//! #### Example
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use daft::{Diffable, Shared};
+//! use std::sync::Arc;
+//!
+//! let before = Shared(Arc::new(vec![1, 2, 3]));
+//! let after = before.clone();
+//! assert!(before.diff(&after).is_identical());
+//! # }
+//! ```
+//!
 //! ### Struct diffs
 //!
 //! For structs, the [`Diffable`][macro@Diffable] derive macro generates
@@ -253,6 +402,23 @@
 //! * `#[daft(leaf)]`: if a **struct** is annotated with this, the [`Diffable`]
 //!   implementation for the struct will be a [`Leaf`] instead of a recursive
 //!   diff.
+//! * `#[daft(owned)]`: also generate a `FooDiffOwned` struct, which doesn't
+//!   borrow from the values being diffed, and implement [`IntoOwnedDiff`] for
+//!   `FooDiff`. See [*Owned diffs*](#owned-diffs) below.
+//! * `#[daft(crate = "path::to::daft")]`: use this path to refer to the daft
+//!   crate in generated code, rather than `::daft`. This is useful if daft is
+//!   re-exported by another crate. Enums and unions accept this option as well.
+//! * `#[daft(name = "Name")]`: name the generated struct `Name` rather than
+//!   `FooDiff`, e.g. to avoid a collision with an existing type. The owned diff
+//!   struct, if any, is named `NameOwned`.
+//! * `#[daft(vis = "pub(crate)")]`: the visibility of the generated structs,
+//!   which is the same as `Foo`'s by default. The diff struct appears in the
+//!   [`Diffable`] implementation for `Foo`, so it can't be less visible than
+//!   `Foo`.
+//! * `#[daft(derive(Trait, ...))]` and `#[daft(attr(...))]`: add
+//!   `#[derive(Trait, ...)]` and `#[...]` attributes to the generated diff
+//!   struct. `Debug`, `PartialEq`, `Eq`, and `Serialize` can't be derived,
+//!   since the diff struct already implements them.
 //!
 //! #### Field options
 //!
@@ -261,6 +427,30 @@
 //!   `Diff` type (or even whether it implements [`Diffable`] at all).
 //! * `#[daft(ignore)]`: the generated struct's corresponding field is not included
 //!   in the diff.
+//! * `#[daft(diff_fn = path, diff_ty = Type)]`: the generated struct's
+//!   corresponding field will be of type `Type`, computed by calling `path`, a
+//!   function `fn(&T, &T) -> Type` where `T` is the type of the field. Elided
+//!   lifetimes in `Type`, including `'_`, refer to the lifetime of the values
+//!   being diffed. This is useful for types that don't implement [`Diffable`],
+//!   or for diffing a field differently from its [`Diffable`] implementation.
+//! * `#[daft(keyed_by = path, key = Type)]`: for a field that is a sequence of
+//!   records (e.g. a `Vec<T>`), the generated struct's corresponding field will
+//!   be a [`KeyedDiff`], which treats the sequence as a map from key to element.
+//!   `path` is a function `fn(&T) -> &Type` that extracts the key from each
+//!   element.
+//! * `#[daft(map_values = "recursive")]`: for a map field (e.g. a
+//!   `BTreeMap<K, V>`), the generated struct's corresponding field will be a
+//!   map diff that stores `V::Diff` for common entries, such as a
+//!   [`BTreeMapRecursiveDiff`]. See [`DiffableMap`].
+//! * `#[daft(text)]`: for a `String` or `&str` field, the generated struct's
+//!   corresponding field will be a line-level [`TextDiff`].
+//! * `#[daft(tolerance = expr)]`: for an `f32` or `f64` field, the generated
+//!   [`DiffSummary`] implementation treats the field as unchanged if the before
+//!   and after values are equal within `expr`, using [`Leaf::approx_eq`].
+//!   `expr` is a [`Tolerance`], or an `f64` absolute tolerance.
+//! * `#[daft(with = "module")]`: shorthand for
+//!   `#[daft(diff_fn = module::diff, diff_ty = module::Diff<'_>)]`, where
+//!   `module` defines a `Diff<'daft>` type and a `diff` function.
 //!
 //! #### Example
 //!
@@ -361,6 +551,63 @@
 //! # }
 //! ```
 //!
+//! ### Enum diffs
+//!
+//! By default, the [`Diffable`][macro@Diffable] derive macro treats enums as
+//! [`Leaf`]s. For enums with large payloads, such as state machines, it can be
+//! more useful to know which fields within a variant changed. With the
+//! `#[daft(recursive)]` attribute, the derive macro generates a diff enum
+//! instead.
+//!
+//! An enum `Foo` annotated with `#[daft(recursive)]` gets a corresponding
+//! `FooDiff` enum, with:
+//!
+//! * For each variant of `Foo`, a variant with the same name, containing a
+//!   recursive diff of each field. This variant is returned if both sides have
+//!   that variant.
+//! * A `VariantChanged(Leaf<&Foo>)` variant, returned if the two sides have
+//!   different variants.
+//!
+//! Fields of variants accept the same [field options](#field-options) as
+//! struct fields, and enums annotated with `#[daft(recursive)]` accept the
+//! `name`, `vis`, `derive`, and `attr` [struct options](#struct-options) for
+//! the diff enum.
+//!
+//! #### Example
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")] {
+//! use daft::{Diffable, Leaf};
+//!
+//! #[derive(Debug, PartialEq, Eq, Diffable)]
+//! #[daft(recursive)]
+//! enum State {
+//!     Stopped,
+//!     Running { pid: u32, restarts: u32 },
+//! }
+//!
+//! let before = State::Running { pid: 1, restarts: 0 };
+//! let after = State::Running { pid: 1, restarts: 1 };
+//!
+//! // Both sides have the same variant, so its fields are diffed.
+//! let diff = before.diff(&after);
+//! assert_eq!(
+//!     diff,
+//!     StateDiff::Running {
+//!         pid: Leaf { before: &1, after: &1 },
+//!         restarts: Leaf { before: &0, after: &1 },
+//!     },
+//! );
+//!
+//! // The two sides have different variants.
+//! let diff = before.diff(&State::Stopped);
+//! assert_eq!(
+//!     diff,
+//!     StateDiff::VariantChanged(Leaf { before: &before, after: &State::Stopped }),
+//! );
+//! # }
+//! ```
+//!
 //! ### Custom diff types
 //!
 //! The [`Diffable`] trait can also be implemented manually for custom behavior.
@@ -392,6 +639,268 @@
 //! # }
 //! ```
 //!
+//! ## Checking for changes
+//!
+//! The [`DiffSummary`] trait reports whether anything changed in a diff. It is
+//! implemented for [`Leaf`], map and set diffs, tuples and arrays of diffs,
+//! and the diff types generated by the [`Diffable`][macro@Diffable] derive
+//! macro, where it recursively checks every field that isn't ignored.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "std", feature = "derive"))] {
+//! use daft::{DiffSummary, Diffable};
+//! use std::collections::BTreeSet;
+//!
+//! #[derive(Diffable)]
+//! struct Sled {
+//!     name: &'static str,
+//!     zpools: BTreeSet<u32>,
+//! }
+//!
+//! let before = Sled { name: "sled0", zpools: [1, 2].into_iter().collect() };
+//! let after = Sled { name: "sled0", zpools: [1, 2, 3].into_iter().collect() };
+//!
+//! let diff = before.diff(&after);
+//! assert!(diff.is_modified());
+//! assert!(DiffSummary::is_unchanged(&diff.name));
+//! # }
+//! ```
+//!
+//! ## Visiting changes
+//!
+//! The [`VisitDiff`] trait walks a diff and calls a [`DiffVisitor`] for each
+//! added, removed, modified, and moved value, along with a structured
+//! [`DiffPath`] to it. Paths are made up of field names, map keys, tuple
+//! indexes, and so on, and are displayed like `zpools["abc"].size`.
+//! `VisitDiff` is implemented for the same types as [`DiffSummary`].
+//!
+//! ```rust
+//! # #[cfg(all(feature = "std", feature = "derive"))] {
+//! use daft::{DiffPath, DiffVisitor, Diffable, VisitDiff};
+//! use std::{collections::BTreeMap, fmt};
+//!
+//! #[derive(Debug, Eq, PartialEq, Diffable)]
+//! struct Zpool {
+//!     size: u64,
+//! }
+//!
+//! #[derive(Diffable)]
+//! struct Sled {
+//!     zpools: BTreeMap<&'static str, Zpool>,
+//! }
+//!
+//! struct Printer;
+//!
+//! impl DiffVisitor for Printer {
+//!     fn modified(
+//!         &mut self,
+//!         path: &DiffPath<'_>,
+//!         before: &dyn fmt::Debug,
+//!         after: &dyn fmt::Debug,
+//!     ) {
+//!         // Prints `zpools["abc"].size: 10 -> 20`.
+//!         println!("{path}: {before:?} -> {after:?}");
+//!     }
+//! }
+//!
+//! let before = Sled { zpools: [("abc", Zpool { size: 10 })].into_iter().collect() };
+//! let after = Sled { zpools: [("abc", Zpool { size: 20 })].into_iter().collect() };
+//! before.diff(&after).visit(&mut Printer);
+//! # }
+//! ```
+//!
+//! ## Rendering diffs
+//!
+//! With the `alloc` feature, a [`DiffRenderer`] renders any diff that
+//! implements [`VisitDiff`] as an indented tree of the parts that changed.
+//! Modified values are shown as `before -> after`, and entries removed from or
+//! added to maps and sets are marked with `-` and `+`. This is usually much
+//! easier to read than the `Debug` output of a diff, which includes every
+//! unchanged field and map entry.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "std", feature = "derive"))] {
+//! use daft::{DiffRenderer, Diffable};
+//! use std::collections::BTreeMap;
+//!
+//! #[derive(Diffable)]
+//! struct Config {
+//!     name: &'static str,
+//!     limits: BTreeMap<&'static str, u32>,
+//! }
+//!
+//! let before = Config { name: "a", limits: [("cpu", 4), ("mem", 8)].into_iter().collect() };
+//! let after = Config { name: "b", limits: [("cpu", 8)].into_iter().collect() };
+//!
+//! let diff = before.diff(&after);
+//! assert_eq!(
+//!     DiffRenderer::new().render(&diff).to_string(),
+//!     r#"name: "a" -> "b"
+//! limits:
+//!   - "mem": 8
+//!   "cpu": 4 -> 8
+//! "#,
+//! );
+//! # }
+//! ```
+//!
+//! ## Owned diffs
+//!
+//! Diffs borrow from the values being diffed, so they can't outlive them. The
+//! [`IntoOwnedDiff`] trait converts a diff into an owned form by cloning the
+//! values it refers to. This makes it possible to keep a history of diffs, or
+//! to send them to other threads.
+//!
+//! [`IntoOwnedDiff`] is implemented for [`Leaf`]s, map and set diffs, and
+//! tuples and arrays of diffs. For structs annotated with `#[daft(owned)]`, the
+//! [`Diffable`][macro@Diffable] derive macro generates a `FooDiffOwned`
+//! struct with the owned form of each field's diff. Every field must have a
+//! diff that can be converted into an owned form, and fields whose types are
+//! type parameters must be annotated with `#[daft(leaf)]`.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "std", feature = "derive"))] {
+//! use daft::{Diffable, IntoOwnedDiff, Leaf};
+//! use std::collections::BTreeMap;
+//!
+//! #[derive(Diffable)]
+//! #[daft(owned)]
+//! struct Config {
+//!     name: String,
+//!     limits: BTreeMap<String, u32>,
+//! }
+//!
+//! let mut history: Vec<ConfigDiffOwned> = Vec::new();
+//! {
+//!     let before = Config { name: "a".to_owned(), limits: BTreeMap::new() };
+//!     let after = Config { name: "b".to_owned(), limits: BTreeMap::new() };
+//!     history.push(before.diff(&after).into_owned());
+//! }
+//!
+//! // The owned diff outlives `before` and `after`.
+//! assert_eq!(history[0].name, Leaf { before: "a".to_owned(), after: "b".to_owned() });
+//! # }
+//! ```
+//!
+//! ## Applying diffs
+//!
+//! The [`Patch`] trait applies a diff to a copy of its before value, turning it
+//! into the after value. This makes it possible to replicate changes by sending
+//! diffs rather than full values. Applying a diff returns a [`PatchError`] if the
+//! target doesn't match the before side of the diff.
+//!
+//! [`Patch`] is implemented for [`Leaf`]s, map and set diffs, tuples and arrays
+//! of diffs, and their owned forms. The [`Diffable`][macro@Diffable] derive macro
+//! implements it for generated diff structs (including owned ones), by patching
+//! each field that isn't ignored.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "std", feature = "derive"))] {
+//! use daft::{Diffable, IntoOwnedDiff, Patch};
+//! use std::collections::BTreeMap;
+//!
+//! #[derive(Clone, Debug, PartialEq, Eq, Diffable)]
+//! #[daft(owned)]
+//! struct Config {
+//!     name: String,
+//!     limits: BTreeMap<String, u32>,
+//! }
+//!
+//! let before = Config { name: "a".to_owned(), limits: BTreeMap::new() };
+//! let after = Config {
+//!     name: "b".to_owned(),
+//!     limits: [("cpu".to_owned(), 4)].into_iter().collect(),
+//! };
+//! let diff: ConfigDiffOwned = before.diff(&after).into_owned();
+//!
+//! let mut replica = before.clone();
+//! diff.apply(&mut replica).unwrap();
+//! assert_eq!(replica, after);
+//! # }
+//! ```
+//!
+//! ## Inverting diffs
+//!
+//! The [`Invert`] trait swaps the before and after sides of a diff, turning the
+//! diff from `a` to `b` into the diff from `b` to `a`. For map and set diffs,
+//! the `added` and `removed` entries are swapped. An inverted diff previews what
+//! rolling back a change would do.
+//!
+//! [`Invert`] is implemented for [`Leaf`]s, map, set and sequence diffs, tuples
+//! and arrays of diffs, and their owned forms. The [`Diffable`][macro@Diffable] derive
+//! macro implements it for generated diff structs and enums.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "std", feature = "derive"))] {
+//! use daft::{Diffable, Invert};
+//! use std::collections::BTreeSet;
+//!
+//! #[derive(Debug, PartialEq, Eq, Diffable)]
+//! struct Firewall {
+//!     enabled: bool,
+//!     ports: BTreeSet<u16>,
+//! }
+//!
+//! let before = Firewall { enabled: false, ports: [22].into() };
+//! let after = Firewall { enabled: true, ports: [22, 443].into() };
+//!
+//! let rollback = before.diff(&after).invert();
+//! assert_eq!(rollback.enabled.before, &true);
+//! assert_eq!(rollback.ports.removed, [&443].into());
+//! assert_eq!(rollback, after.diff(&before));
+//! # }
+//! ```
+//!
+//! ## Merging diffs
+//!
+//! The [`Merge`] trait performs three-way merges. Given a `base` value and two
+//! values derived from it, `ours` and `theirs`, [`Merge::merge`] combines
+//! `base.diff(&ours)` and `base.diff(&theirs)` by applying the changes from
+//! `theirs` to a copy of `ours`. This makes it possible to safely combine
+//! concurrent edits to the same value.
+//!
+//! Changes made on only one side are kept, and map entries are merged key by
+//! key. If both sides make different changes to the same value, the value is
+//! left as it is in `ours` and a conflict is reported, along with the path to
+//! the value.
+//!
+//! [`Merge`] is implemented for [`Leaf`]s, map and set diffs, and tuples and
+//! arrays of diffs. The [`Diffable`][macro@Diffable] derive macro implements it for
+//! generated diff structs, by merging each field that isn't ignored.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "std", feature = "derive"))] {
+//! use daft::{Diffable, Merge, MergeConflictKind};
+//! use std::collections::BTreeMap;
+//!
+//! #[derive(Clone, Debug, PartialEq, Eq, Diffable)]
+//! struct Config {
+//!     name: String,
+//!     limits: BTreeMap<String, u32>,
+//! }
+//!
+//! let base = Config { name: "a".to_owned(), limits: BTreeMap::new() };
+//! let ours = Config { name: "b".to_owned(), ..base.clone() };
+//! let theirs = Config {
+//!     limits: [("cpu".to_owned(), 4)].into_iter().collect(),
+//!     ..base.clone()
+//! };
+//!
+//! let mut merged = ours.clone();
+//! base.diff(&ours).merge(&base.diff(&theirs), &mut merged).unwrap();
+//! assert_eq!(merged.name, "b");
+//! assert_eq!(merged.limits, theirs.limits);
+//!
+//! // Conflicting changes are reported with their paths.
+//! let theirs = Config { name: "c".to_owned(), ..base.clone() };
+//! let mut merged = ours.clone();
+//! let error =
+//!     base.diff(&ours).merge(&base.diff(&theirs), &mut merged).unwrap_err();
+//! assert_eq!(error.conflicts()[0].path, "name");
+//! assert_eq!(error.conflicts()[0].kind, MergeConflictKind::BothModified);
+//! # }
+//! ```
+//!
 //! ## Type and lifetime parameters
 //!
 //! If a type parameter is specified, the [`Diffable`][macro@Diffable] derive
@@ -431,6 +940,11 @@
 //! # Optional features
 //!
 //! * `derive`: Enable the `Diffable` derive macro: **disabled** by default.
+//! * `color`: Enable ANSI-colored output for [`DiffRenderer`] when standard
+//!   output is a terminal, respecting [`NO_COLOR`](https://no-color.org/):
+//!   **disabled** by default.
+//! * `serde`: Implement [`serde::Serialize`] for diff types, including the
+//!   ones generated by the `Diffable` derive macro: **disabled** by default.
 //!
 //! Implementations for standard library types, all **enabled** by default:
 //!
@@ -444,6 +958,10 @@
 //! * `uuid1`: Enable diffing for [`uuid::Uuid`].
 //! * `oxnet01`: Enable diffing for network types from the [`oxnet`] crate.
 //! * `newtype-uuid1`: Enable diffing for [`newtype_uuid::TypedUuid`].
+//! * `chrono04`: Enable diffing for date and time types from the [`chrono`]
+//!   crate.
+//! * `jiff02`: Enable diffing for date and time types from the [`jiff`] crate.
+//! * `time03`: Enable diffing for date and time types from the [`time`] crate.
 //!
 //! # Minimum supported Rust version (MSRV)
 //!
@@ -473,7 +991,8 @@
 //!   functionality forgoes the need for `Eq` entirely.
 //!
 //!   For a primitive scalar like `f64`, you'll get a `Leaf` struct which you can
-//!   compare with whatever notion of equality you want.
+//!   compare with whatever notion of equality you want, such as the
+//!   tolerance-based [`Leaf::approx_eq`].
 //!
 //! * Daft uses a [generic associated type (GAT)][GAT] so that the `Diffable`
 //!   trait no longer needs a lifetime parameter. This leads to simpler usage.
@@ -489,6 +1008,9 @@
 //! [`BTreeSet`]: std::collections::BTreeSet
 //! [`HashMap`]: std::collections::HashMap
 //! [`HashSet`]: std::collections::HashSet
+//! [`VecDeque`]: std::collections::VecDeque
+//! [`LinkedList`]: std::collections::LinkedList
+//! [`BinaryHeap`]: std::collections::BinaryHeap
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -499,11 +1021,36 @@ mod macros;
 #[cfg(feature = "alloc")]
 mod alloc_impls;
 mod core_impls;
+mod delta;
 mod diffable;
+mod invert;
+#[cfg(feature = "alloc")]
+mod keyed;
+mod lazy;
 mod leaf;
+mod merge;
+#[cfg(feature = "alloc")]
+mod multiset;
+#[cfg(feature = "alloc")]
+mod order;
+mod owned;
+mod patch;
+#[cfg(feature = "alloc")]
+mod recursive;
+#[cfg(feature = "alloc")]
+mod render;
+#[cfg(feature = "alloc")]
+mod seq;
+#[cfg(feature = "alloc")]
+mod shared;
 #[cfg(feature = "std")]
 mod std_impls;
+mod summary;
+#[cfg(feature = "alloc")]
+mod text;
 mod third_party;
+mod tolerance;
+mod visit;
 
 #[cfg(feature = "alloc")]
 pub use alloc_impls::*;
@@ -514,15 +1061,85 @@ pub use alloc_impls::*;
 /// - For **structs**, this macro generates a corresponding recursive (eager)
 ///   diff type by default. A non-recursive (lazy) diff can be generated by
 ///   annotating the struct overall with `#[daft(leaf)]`.
-/// - For **enums** and **unions**, this macro generates a non-recursive (lazy)
-///   diff.
+/// - For **enums**, this macro generates a non-recursive (lazy) diff by
+///   default. A diff enum with a recursive diff for each variant can be
+///   generated by annotating the enum with `#[daft(recursive)]`.
+/// - For **unions**, this macro generates a non-recursive (lazy) diff.
 ///
 /// For more information, see the [crate-level documentation](crate).
 #[cfg(feature = "derive")]
 pub use daft_derive::Diffable;
+pub use delta::*;
 pub use diffable::*;
+pub use invert::*;
+#[cfg(feature = "alloc")]
+pub use keyed::*;
+pub use lazy::*;
 pub use leaf::*;
+pub use merge::*;
+#[cfg(feature = "alloc")]
+pub use multiset::*;
+#[cfg(feature = "alloc")]
+pub use order::*;
+pub use owned::*;
+pub use patch::*;
+#[cfg(feature = "alloc")]
+pub use recursive::*;
+#[cfg(feature = "alloc")]
+pub use render::*;
+#[cfg(feature = "alloc")]
+pub use seq::*;
+#[cfg(feature = "alloc")]
+pub use shared::*;
 #[cfg(feature = "std")]
 pub use std_impls::*;
+pub use summary::*;
+#[cfg(feature = "alloc")]
+pub use text::*;
 #[cfg(feature = "indexmap")]
 pub use third_party::indexmap::*;
+pub use tolerance::*;
+pub use visit::*;
+
+/// Implementation details for the derive macro. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use serde;
+
+    /// Emits its input if the `serde` feature is enabled, and nothing
+    /// otherwise.
+    ///
+    /// The derive macro can't tell which features of this crate are enabled,
+    /// so it wraps generated `Serialize` implementations in this macro.
+    #[cfg(feature = "serde")]
+    #[macro_export]
+    #[doc(hidden)]
+    macro_rules! __serde_impl {
+        ($($tt:tt)*) => { $($tt)* };
+    }
+
+    #[cfg(not(feature = "serde"))]
+    #[macro_export]
+    #[doc(hidden)]
+    macro_rules! __serde_impl {
+        ($($tt:tt)*) => {};
+    }
+
+    pub use crate::__serde_impl as serde_impl;
+
+    /// Names the type of a custom field diff, specified with
+    /// `#[daft(diff_fn = ..., diff_ty = ...)]` or `#[daft(with = "...")]`.
+    ///
+    /// `<T as CustomDiff<'daft>>::Output` is always `T`. Naming it this way
+    /// means that the diff struct always uses its `'daft` lifetime, and that
+    /// bounds on the type are only checked where the diff struct's trait
+    /// implementations are used, as with other field diff types.
+    pub trait CustomDiff<'daft> {
+        type Output;
+    }
+
+    impl<T> CustomDiff<'_> for T {
+        type Output = T;
+    }
+}
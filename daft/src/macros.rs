@@ -31,19 +31,284 @@ macro_rules! leaf_deref {
                     }
                 }
             }
+
+            impl $crate::Patch<$typ> for $crate::Leaf<&$target> {
+                fn apply(&self, target: &mut $typ) -> Result<(), $crate::PatchError> {
+                    if &**target != self.before {
+                        return Err($crate::PatchError::Mismatch);
+                    }
+                    *target = alloc::borrow::ToOwned::to_owned(self.after);
+                    Ok(())
+                }
+            }
+
+            impl $crate::Merge<$typ> for $crate::Leaf<&$target> {
+                fn merge_at(
+                    &self,
+                    theirs: &Self,
+                    target: &mut $typ,
+                    path: &$crate::DiffPath<'_>,
+                    conflict: &mut dyn FnMut(&$crate::DiffPath<'_>, $crate::MergeConflictKind),
+                ) {
+                    if $crate::merge::merge_leaf(self, theirs, path, conflict) {
+                        *target = alloc::borrow::ToOwned::to_owned(theirs.after);
+                    }
+                }
+            }
         )*
     };
 }
 
+/// Apply the removed, common, and added entries of a map diff to `$target`.
+///
+/// `$remove` is the name of the method used to remove entries from the map.
+/// For ordered maps, `$order` is the diff's `OrderDiff`: common entries are
+/// moved into their after order, and added entries are inserted at their
+/// after positions.
+#[cfg(feature = "alloc")]
+macro_rules! patch_map {
+    (
+        $target:ident,
+        $remove:ident,
+        removed = $removed:expr,
+        common = $common:expr,
+        added = $added:expr $(,)?
+    ) => {{
+        patch_map!(@existing $target, $remove, $removed, $common);
+        for (k, v) in $added {
+            if $target.contains_key(k) {
+                return Err($crate::PatchError::ExistingEntry);
+            }
+            $target.insert(k.clone(), v.clone());
+        }
+        Ok(())
+    }};
+    (
+        $target:ident,
+        $remove:ident,
+        removed = $removed:expr,
+        common = $common:expr,
+        added = $added:expr,
+        order = $order:expr $(,)?
+    ) => {{
+        let order: &$crate::OrderDiff<_> = $order;
+        patch_map!(@existing $target, $remove, $removed, $common);
+        reorder_common!(
+            $target,
+            IntoIterator::into_iter($common).map(|(k, _)| k),
+            order,
+        );
+        let mut positions = order.added.iter();
+        for (k, v) in $added {
+            if $target.contains_key(k) {
+                return Err($crate::PatchError::ExistingEntry);
+            }
+            let index = insert_index!($target, positions)?;
+            $target.shift_insert(index, k.clone(), v.clone());
+        }
+        if positions.next().is_some() {
+            return Err($crate::PatchError::Mismatch);
+        }
+        Ok(())
+    }};
+    (@existing $target:ident, $remove:ident, $removed:expr, $common:expr) => {
+        for (k, v) in $removed {
+            match $target.get(k) {
+                Some(value) if value == v => {}
+                Some(_) => return Err($crate::PatchError::Mismatch),
+                None => return Err($crate::PatchError::MissingEntry),
+            }
+            $target.$remove(k);
+        }
+        for (k, leaf) in $common {
+            let value =
+                $target.get_mut(k).ok_or($crate::PatchError::MissingEntry)?;
+            $crate::Patch::apply(leaf, value)?;
+        }
+    };
+}
+
+/// Apply the removed, common, and added entries of a set diff to `$target`.
+///
+/// `$remove` is the name of the method used to remove entries from the set.
+/// For ordered sets, `$order` is the diff's `OrderDiff`: common entries are
+/// moved into their after order, and added entries are inserted at their
+/// after positions.
+#[cfg(feature = "alloc")]
+macro_rules! patch_set {
+    (
+        $target:ident,
+        $remove:ident,
+        removed = $removed:expr,
+        common = $common:expr,
+        added = $added:expr $(,)?
+    ) => {{
+        patch_set!(@existing $target, $remove, $removed, $common);
+        for k in $added {
+            if $target.contains(k) {
+                return Err($crate::PatchError::ExistingEntry);
+            }
+            $target.insert(k.clone());
+        }
+        Ok(())
+    }};
+    (
+        $target:ident,
+        $remove:ident,
+        removed = $removed:expr,
+        common = $common:expr,
+        added = $added:expr,
+        order = $order:expr $(,)?
+    ) => {{
+        let order: &$crate::OrderDiff<_> = $order;
+        patch_set!(@existing $target, $remove, $removed, $common);
+        reorder_common!($target, IntoIterator::into_iter($common), order);
+        let mut positions = order.added.iter();
+        for k in $added {
+            if $target.contains(k) {
+                return Err($crate::PatchError::ExistingEntry);
+            }
+            let index = insert_index!($target, positions)?;
+            $target.shift_insert(index, k.clone());
+        }
+        if positions.next().is_some() {
+            return Err($crate::PatchError::Mismatch);
+        }
+        Ok(())
+    }};
+    (@existing $target:ident, $remove:ident, $removed:expr, $common:expr) => {
+        for k in $removed {
+            if !$target.$remove(k) {
+                return Err($crate::PatchError::MissingEntry);
+            }
+        }
+        for k in $common {
+            if !$target.contains(k) {
+                return Err($crate::PatchError::MissingEntry);
+            }
+        }
+    };
+}
+
+/// Move the common entries of an ordered map or set `$target`, whose keys are
+/// `$keys`, into their after order.
+///
+/// `$order` is the diff's `OrderDiff`. The common entries are rearranged
+/// within the positions they already occupy, so other entries stay where they
+/// are.
+#[cfg(feature = "indexmap")]
+macro_rules! reorder_common {
+    ($target:ident, $keys:expr, $order:expr $(,)?) => {{
+        // The common keys were checked to be present when patching them.
+        let mut common: alloc::vec::Vec<_> = $keys
+            .map(|k| {
+                let ix =
+                    $target.get_index_of(k).expect("common keys are present");
+                (ix, k)
+            })
+            .collect();
+        common.sort_unstable_by_key(|&(ix, _)| ix);
+        let (slots, keys): (alloc::vec::Vec<_>, alloc::vec::Vec<_>) =
+            common.into_iter().unzip();
+        let keys = $order.after_order(keys)?;
+        fill_slots!($target, slots, keys);
+    }};
+}
+
+/// Move the entries of an ordered map or set `$target` with keys `$keys` into
+/// `$slots`, a sorted list of the positions they occupy, in order.
+#[cfg(feature = "indexmap")]
+macro_rules! fill_slots {
+    ($target:ident, $slots:expr, $keys:expr) => {
+        // Each slot is filled in turn, and the entry it held is swapped into
+        // a later slot.
+        for (slot, k) in $slots.into_iter().zip($keys) {
+            let ix = $target.get_index_of(k).expect("keys are present");
+            $target.swap_indices(ix, slot);
+        }
+    };
+}
+
+/// Merge their changes to the relative order of the common entries of an
+/// ordered map or set into `$target`.
+///
+/// `$ours` and `$theirs` are the `OrderDiff`s of both sides, and `$keys`
+/// iterates over the keys of their common entries in base order. If only
+/// theirs reordered entries, the entries of `$target` that they reordered are
+/// moved into their order, within the positions they already occupy. If both
+/// sides reordered entries and ours isn't already in their order, a
+/// `BothReordered` conflict is reported at `$path`.
+#[cfg(feature = "indexmap")]
+macro_rules! merge_order {
+    (
+        $target:ident,
+        $path:ident,
+        $conflict:ident,
+        ours = $ours:expr,
+        theirs = $theirs:expr,
+        keys = $keys:expr $(,)?
+    ) => {{
+        let ours: &$crate::OrderDiff<_> = $ours;
+        let theirs: &$crate::OrderDiff<_> = $theirs;
+        // A malformed order can't be applied, so it is ignored.
+        let after = if theirs.is_empty() {
+            alloc::vec::Vec::new()
+        } else {
+            theirs.after_order($keys).unwrap_or_default()
+        };
+        // The entries of the target that theirs has in common with the base,
+        // with their positions in the target, in their after order.
+        let mut common: alloc::vec::Vec<_> = after
+            .into_iter()
+            .filter_map(|k| $target.get_index_of(k).map(|ix| (ix, k)))
+            .collect();
+        if !common.is_sorted_by_key(|&(ix, _)| ix) {
+            if ours.is_empty() {
+                let keys: alloc::vec::Vec<_> =
+                    common.iter().map(|&(_, k)| k).collect();
+                common.sort_unstable_by_key(|&(ix, _)| ix);
+                fill_slots!(
+                    $target,
+                    common.into_iter().map(|(ix, _)| ix),
+                    keys
+                );
+            } else {
+                $conflict($path, $crate::MergeConflictKind::BothReordered);
+            }
+        }
+    }};
+}
+
+/// Return the position at which the next added entry of an ordered map or
+/// set `$target` is inserted, from `$positions`, an iterator over
+/// `OrderDiff::added`.
+///
+/// Added entries are inserted in after order, so every entry before the
+/// returned position is already in place.
+#[cfg(feature = "indexmap")]
+macro_rules! insert_index {
+    ($target:ident, $positions:ident) => {
+        match $positions.next() {
+            Some(position) if position.index <= $target.len() => {
+                Ok(position.index)
+            }
+            _ => Err($crate::PatchError::Mismatch),
+        }
+    };
+}
+
 /// Create a type `<MapType>Diff` and `impl Diffable` on it.
 ///
-/// This is supported for `BTreeMap` and `HashMap`
+/// This is supported for `BTreeMap`, `HashMap` and `IndexMap`. `$remove` is
+/// the name of the method used to remove entries from the map. For ordered
+/// maps, `$order` is `OrderDiff`, which adds an `order` field to the diff.
 #[cfg(feature = "alloc")]
 macro_rules! map_diff {
-    ($(#[$doc:meta])* $typ:ident, $key_constraint:ident) => {
+    ($(#[$doc:meta])* $typ:ident, $key_constraint:ident, $remove:ident $(, $order:ident)?) => {
          paste::paste! {
             $(#[$doc])*
             #[derive(Debug, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize))]
             pub struct [<$typ Diff>]<'daft, K: $key_constraint + Eq, V> {
                 /// Entries common to both maps.
                 ///
@@ -55,6 +320,11 @@ macro_rules! map_diff {
 
                 /// Entries present in the `before` map, but not in `after`.
                 pub removed: $typ<&'daft K, &'daft V>,
+
+                $(
+                /// Changes to the relative order of common entries.
+                pub order: $crate::$order<&'daft K>,
+                )?
             }
 
             impl<'daft, K: $key_constraint + Eq, V> [<$typ Diff>]<'daft, K, V> {
@@ -64,6 +334,7 @@ macro_rules! map_diff {
                         common: $typ::new(),
                         added: $typ::new(),
                         removed: $typ::new(),
+                        $(order: $crate::$order::new(),)?
                     }
                 }
             }
@@ -174,6 +445,234 @@ macro_rules! map_diff {
                 }
             }
 
+            impl<'daft, K, V> $crate::VisitDiff for [<$typ Diff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq + ::core::fmt::Debug,
+                V: $crate::Diffable + ::core::fmt::Debug,
+                V::Diff<'daft>: $crate::VisitDiff,
+            {
+                fn visit_at(
+                    &self,
+                    path: &$crate::DiffPath<'_>,
+                    visitor: &mut dyn $crate::DiffVisitor,
+                ) {
+                    for (k, v) in &self.removed {
+                        visitor.removed(&path.join($crate::PathSegment::Key(k)), v);
+                    }
+                    // Diff common values to find changes within them.
+                    for (k, leaf) in &self.common {
+                        $crate::VisitDiff::visit_at(
+                            &$crate::Diffable::diff(leaf.before, leaf.after),
+                            &path.join($crate::PathSegment::Key(k)),
+                            visitor,
+                        );
+                    }
+                    $(<$crate::$order<_> as $crate::VisitDiff>::visit_at(
+                        &self.order,
+                        path,
+                        visitor,
+                    );)?
+                    for (k, v) in &self.added {
+                        visitor.added(&path.join($crate::PathSegment::Key(k)), v);
+                    }
+                }
+            }
+
+            impl<'daft, K: $key_constraint + Eq, V: Eq> $crate::DiffSummary for [<$typ Diff>]<'daft, K, V> {
+                fn is_unchanged(&self) -> bool {
+                    self.added.is_empty()
+                        && self.removed.is_empty()
+                        && self.common.values().all(|leaf| leaf.is_unchanged())
+                        $(&& $crate::$order::is_empty(&self.order))?
+                }
+            }
+
+            #[doc = "An owned version of [`" $typ "Diff`], created with"]
+            /// [`IntoOwnedDiff::into_owned`](crate::IntoOwnedDiff::into_owned).
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+            pub struct [<$typ DiffOwned>]<K: $key_constraint + Eq, V> {
+                /// Entries common to both maps.
+                pub common: $typ<K, $crate::Leaf<V>>,
+
+                /// Entries present in the `after` map, but not in `before`.
+                pub added: $typ<K, V>,
+
+                /// Entries present in the `before` map, but not in `after`.
+                pub removed: $typ<K, V>,
+
+                $(
+                /// Changes to the relative order of common entries.
+                pub order: $crate::$order<K>,
+                )?
+            }
+
+            impl<'daft, K, V> $crate::IntoOwnedDiff for [<$typ Diff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq + Clone,
+                V: Clone,
+            {
+                type Owned = [<$typ DiffOwned>]<K, V>;
+
+                fn into_owned(self) -> Self::Owned {
+                    [<$typ DiffOwned>] {
+                        common: self
+                            .common
+                            .into_iter()
+                            .map(|(k, leaf)| (k.clone(), leaf.cloned()))
+                            .collect(),
+                        added: self
+                            .added
+                            .into_iter()
+                            .map(|(k, v)| (k.clone(), v.clone()))
+                            .collect(),
+                        removed: self
+                            .removed
+                            .into_iter()
+                            .map(|(k, v)| (k.clone(), v.clone()))
+                            .collect(),
+                        $(order: <$crate::$order<_> as $crate::IntoOwnedDiff>::into_owned(
+                            self.order,
+                        ),)?
+                    }
+                }
+            }
+
+            impl<'daft, K: $key_constraint + Eq, V> $crate::Invert for [<$typ Diff>]<'daft, K, V> {
+                fn invert(self) -> Self {
+                    Self {
+                        common: self
+                            .common
+                            .into_iter()
+                            .map(|(k, leaf)| (k, $crate::Invert::invert(leaf)))
+                            .collect(),
+                        added: self.removed,
+                        removed: self.added,
+                        $(order: <$crate::$order<_> as $crate::Invert>::invert(self.order),)?
+                    }
+                }
+            }
+
+            impl<K: $key_constraint + Eq, V> $crate::Invert for [<$typ DiffOwned>]<K, V> {
+                fn invert(self) -> Self {
+                    Self {
+                        common: self
+                            .common
+                            .into_iter()
+                            .map(|(k, leaf)| (k, $crate::Invert::invert(leaf)))
+                            .collect(),
+                        added: self.removed,
+                        removed: self.added,
+                        $(order: <$crate::$order<_> as $crate::Invert>::invert(self.order),)?
+                    }
+                }
+            }
+
+            impl<'daft, K, V> $crate::Patch<$typ<K, V>> for [<$typ Diff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq + Clone,
+                V: Clone + PartialEq,
+            {
+                fn apply(&self, target: &mut $typ<K, V>) -> Result<(), $crate::PatchError> {
+                    patch_map!(
+                        target,
+                        $remove,
+                        removed = self.removed.iter().map(|(k, v)| (*k, *v)),
+                        common = self.common.iter().map(|(k, leaf)| (*k, leaf)),
+                        added = self.added.iter().map(|(k, v)| (*k, *v)),
+                        $(order = ::core::convert::identity::<&$crate::$order<_>>(&self.order),)?
+                    )
+                }
+            }
+
+            impl<K, V> $crate::Patch<$typ<K, V>> for [<$typ DiffOwned>]<K, V>
+            where
+                K: $key_constraint + Eq + Clone,
+                V: Clone + PartialEq,
+            {
+                fn apply(&self, target: &mut $typ<K, V>) -> Result<(), $crate::PatchError> {
+                    patch_map!(
+                        target,
+                        $remove,
+                        removed = &self.removed,
+                        common = &self.common,
+                        added = &self.added,
+                        $(order = ::core::convert::identity::<&$crate::$order<_>>(&self.order),)?
+                    )
+                }
+            }
+
+            impl<'daft, K, V> $crate::Merge<$typ<K, V>> for [<$typ Diff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq + Clone + ::core::fmt::Debug,
+                V: $crate::Diffable + Clone + PartialEq,
+                V::Diff<'daft>: $crate::Merge<V>,
+            {
+                fn merge_at(
+                    &self,
+                    theirs: &Self,
+                    target: &mut $typ<K, V>,
+                    path: &$crate::DiffPath<'_>,
+                    conflict: &mut dyn FnMut(&$crate::DiffPath<'_>, $crate::MergeConflictKind),
+                ) {
+                    for k in theirs.removed.keys() {
+                        // If ours removed the entry as well, there's nothing
+                        // to do.
+                        match self.common.get(k) {
+                            Some(leaf) if leaf.before == leaf.after => {
+                                target.$remove(*k);
+                            }
+                            Some(_) => conflict(
+                                &path.join($crate::PathSegment::Key(k)),
+                                $crate::MergeConflictKind::RemovedAndModified,
+                            ),
+                            None => {}
+                        }
+                    }
+                    for (k, leaf) in &theirs.common {
+                        if leaf.before == leaf.after {
+                            continue;
+                        }
+                        let path = path.join($crate::PathSegment::Key(k));
+                        match (self.common.get(k), target.get_mut(*k)) {
+                            // Diff the values on both sides to merge changes
+                            // within them.
+                            (Some(ours), Some(value)) => $crate::Merge::merge_at(
+                                &$crate::Diffable::diff(ours.before, ours.after),
+                                &$crate::Diffable::diff(leaf.before, leaf.after),
+                                value,
+                                &path,
+                                conflict,
+                            ),
+                            _ => conflict(
+                                &path,
+                                $crate::MergeConflictKind::RemovedAndModified,
+                            ),
+                        }
+                    }
+                    for (k, v) in &theirs.added {
+                        match self.added.get(k) {
+                            Some(ours) if ours == v => {}
+                            Some(_) => conflict(
+                                &path.join($crate::PathSegment::Key(k)),
+                                $crate::MergeConflictKind::BothAdded,
+                            ),
+                            None => {
+                                target.insert((*k).clone(), (*v).clone());
+                            }
+                        }
+                    }
+                    $(merge_order!(
+                        target,
+                        path,
+                        conflict,
+                        ours = ::core::convert::identity::<&$crate::$order<_>>(&self.order),
+                        theirs = &theirs.order,
+                        keys = theirs.common.keys().copied(),
+                    );)?
+                }
+            }
+
             impl<K: $key_constraint + Eq, V> $crate::Diffable for $typ<K, V>
             {
                 type Diff<'daft> = [<$typ Diff>]<'daft, K, V> where K: 'daft, V: 'daft;
@@ -192,22 +691,471 @@ macro_rules! map_diff {
                             diff.added.insert(k, v);
                         }
                     }
+                    $(
+                        diff.order = $crate::$order::compute(
+                            self.keys(),
+                            other.keys(),
+                            |k| self.get_index_of(*k),
+                            |k| other.get_index_of(*k),
+                        );
+                    )?
                     diff
                 }
             }
         }
+
+        recursive_map_diff!($typ, $key_constraint, $remove $(, $order)?);
+    }
+}
+
+/// Create a type `<MapType>RecursiveDiff`, along with its owned form, and
+/// `impl DiffableMap` for the map type.
+///
+/// This is supported for `BTreeMap`, `HashMap` and `IndexMap`. `$remove` is
+/// the name of the method used to remove entries from the map. For ordered
+/// maps, `$order` is `OrderDiff`, which adds an `order` field to the diff.
+#[cfg(feature = "alloc")]
+macro_rules! recursive_map_diff {
+    ($typ:ident, $key_constraint:ident, $remove:ident $(, $order:ident)?) => {
+        paste::paste! {
+            #[doc = "A diff of two [`" $typ "`] instances, with the values of common"]
+            /// entries diffed recursively.
+            ///
+            #[doc = "Unlike [`" $typ "Diff`], which stores common values as"]
+            /// [`Leaf`](crate::Leaf)s, this diff stores the result of diffing
+            /// them, so nested diffs are available as fields. It is returned by
+            /// [`DiffableMap::recursive_diff`](crate::DiffableMap::recursive_diff),
+            /// and used for fields annotated with
+            /// `#[daft(map_values = "recursive")]`.
+            #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+            #[cfg_attr(
+                feature = "serde",
+                serde(bound = "K: serde::Serialize, V: serde::Serialize, \
+                               V::Diff<'daft>: serde::Serialize")
+            )]
+            pub struct [<$typ RecursiveDiff>]<'daft, K: $key_constraint + Eq, V: $crate::Diffable + 'daft> {
+                /// Entries common to both maps.
+                ///
+                /// Values are stored as the diffs between them.
+                pub common: $typ<&'daft K, V::Diff<'daft>>,
+
+                /// Entries present in the `after` map, but not in `before`.
+                pub added: $typ<&'daft K, &'daft V>,
+
+                /// Entries present in the `before` map, but not in `after`.
+                pub removed: $typ<&'daft K, &'daft V>,
+
+                $(
+                /// Changes to the relative order of common entries.
+                pub order: $crate::$order<&'daft K>,
+                )?
+            }
+
+            impl<'daft, K: $key_constraint + Eq, V: $crate::Diffable> [<$typ RecursiveDiff>]<'daft, K, V> {
+                #[doc = "Create a new, empty `" $typ "RecursiveDiff` instance."]
+                pub fn new() -> Self {
+                    Self {
+                        common: $typ::new(),
+                        added: $typ::new(),
+                        removed: $typ::new(),
+                        $(order: $crate::$order::new(),)?
+                    }
+                }
+            }
+
+            impl<'daft, K, V> [<$typ RecursiveDiff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq,
+                V: $crate::Diffable,
+                V::Diff<'daft>: $crate::DiffSummary,
+            {
+                /// Return an iterator over unchanged keys.
+                pub fn unchanged_keys(&self) -> impl Iterator<Item = &'daft K> + '_ {
+                    self.common.iter().filter_map(|(k, diff)| {
+                        $crate::DiffSummary::is_unchanged(diff).then_some(*k)
+                    })
+                }
+
+                /// Return an iterator over modified keys and the diffs of
+                /// their values.
+                pub fn modified(&self) -> impl Iterator<Item = (&'daft K, &V::Diff<'daft>)> + '_ {
+                    self.common.iter().filter_map(|(k, diff)| {
+                        $crate::DiffSummary::is_modified(diff).then_some((*k, diff))
+                    })
+                }
+
+                /// Return true if the value corresponding to the key is
+                /// unchanged.
+                pub fn is_unchanged(&self, key: &K) -> bool {
+                    self.common.get(key).is_some_and(|diff| $crate::DiffSummary::is_unchanged(diff))
+                }
+
+                /// Return true if the value corresponding to the key is
+                /// modified.
+                pub fn is_modified(&self, key: &K) -> bool {
+                    self.common.get(key).is_some_and(|diff| $crate::DiffSummary::is_modified(diff))
+                }
+
+                /// Return the diff associated with the key if it is modified,
+                /// otherwise `None`.
+                pub fn get_modified(&self, key: &K) -> Option<&V::Diff<'daft>> {
+                    self.common.get(key).filter(|diff| $crate::DiffSummary::is_modified(*diff))
+                }
+
+                /// Return an iterator over modified keys.
+                pub fn modified_keys(&self) -> impl Iterator<Item = &'daft K> + '_ {
+                    self.modified().map(|(k, _)| k)
+                }
+            }
+
+            // Note: not deriving Default here because we don't want to require
+            // K or V to be Default.
+            impl<'daft, K: $key_constraint + Eq, V: $crate::Diffable> Default for [<$typ RecursiveDiff>]<'daft, K, V> {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            // The standard traits are implemented by hand, since deriving them
+            // would not require them for `V::Diff`.
+            impl<'daft, K, V> Clone for [<$typ RecursiveDiff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq,
+                V: $crate::Diffable,
+                V::Diff<'daft>: Clone,
+            {
+                fn clone(&self) -> Self {
+                    Self {
+                        common: self.common.clone(),
+                        added: self.added.clone(),
+                        removed: self.removed.clone(),
+                        $(order: <$crate::$order<_> as Clone>::clone(&self.order),)?
+                    }
+                }
+            }
+
+            impl<'daft, K, V> ::core::fmt::Debug for [<$typ RecursiveDiff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq + ::core::fmt::Debug,
+                V: $crate::Diffable + ::core::fmt::Debug,
+                V::Diff<'daft>: ::core::fmt::Debug,
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct(stringify!([<$typ RecursiveDiff>]))
+                        .field("common", &self.common)
+                        .field("added", &self.added)
+                        .field("removed", &self.removed)
+                        $(.field("order", &self.order as &$crate::$order<_>))?
+                        .finish()
+                }
+            }
+
+            impl<'daft, K, V> PartialEq for [<$typ RecursiveDiff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq,
+                V: $crate::Diffable + PartialEq,
+                V::Diff<'daft>: PartialEq,
+            {
+                fn eq(&self, other: &Self) -> bool {
+                    self.common == other.common
+                        && self.added == other.added
+                        && self.removed == other.removed
+                        $(&& <$crate::$order<_> as PartialEq>::eq(&self.order, &other.order))?
+                }
+            }
+
+            impl<'daft, K, V> Eq for [<$typ RecursiveDiff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq,
+                V: $crate::Diffable + Eq,
+                V::Diff<'daft>: Eq,
+            {
+            }
+
+            impl<'daft, K, V> $crate::VisitDiff for [<$typ RecursiveDiff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq + ::core::fmt::Debug,
+                V: $crate::Diffable + ::core::fmt::Debug,
+                V::Diff<'daft>: $crate::VisitDiff,
+            {
+                fn visit_at(
+                    &self,
+                    path: &$crate::DiffPath<'_>,
+                    visitor: &mut dyn $crate::DiffVisitor,
+                ) {
+                    for (k, v) in &self.removed {
+                        visitor.removed(&path.join($crate::PathSegment::Key(k)), v);
+                    }
+                    for (k, diff) in &self.common {
+                        $crate::VisitDiff::visit_at(
+                            diff,
+                            &path.join($crate::PathSegment::Key(k)),
+                            visitor,
+                        );
+                    }
+                    $(<$crate::$order<_> as $crate::VisitDiff>::visit_at(
+                        &self.order,
+                        path,
+                        visitor,
+                    );)?
+                    for (k, v) in &self.added {
+                        visitor.added(&path.join($crate::PathSegment::Key(k)), v);
+                    }
+                }
+            }
+
+            impl<'daft, K, V> $crate::DiffSummary for [<$typ RecursiveDiff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq,
+                V: $crate::Diffable,
+                V::Diff<'daft>: $crate::DiffSummary,
+            {
+                fn is_unchanged(&self) -> bool {
+                    self.added.is_empty()
+                        && self.removed.is_empty()
+                        && self.common.values().all(|diff| $crate::DiffSummary::is_unchanged(diff))
+                        $(&& $crate::$order::is_empty(&self.order))?
+                }
+            }
+
+            #[doc = "An owned version of [`" $typ "RecursiveDiff`], created with"]
+            /// [`IntoOwnedDiff::into_owned`](crate::IntoOwnedDiff::into_owned).
+            ///
+            /// `D` is the owned form of the diffs of common values.
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+            pub struct [<$typ RecursiveDiffOwned>]<K: $key_constraint + Eq, V, D> {
+                /// Entries common to both maps.
+                pub common: $typ<K, D>,
+
+                /// Entries present in the `after` map, but not in `before`.
+                pub added: $typ<K, V>,
+
+                /// Entries present in the `before` map, but not in `after`.
+                pub removed: $typ<K, V>,
+
+                $(
+                /// Changes to the relative order of common entries.
+                pub order: $crate::$order<K>,
+                )?
+            }
+
+            impl<'daft, K, V> $crate::IntoOwnedDiff for [<$typ RecursiveDiff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq + Clone,
+                V: $crate::Diffable + Clone,
+                V::Diff<'daft>: $crate::IntoOwnedDiff,
+            {
+                type Owned = [<$typ RecursiveDiffOwned>]<
+                    K,
+                    V,
+                    <V::Diff<'daft> as $crate::IntoOwnedDiff>::Owned,
+                >;
+
+                fn into_owned(self) -> Self::Owned {
+                    [<$typ RecursiveDiffOwned>] {
+                        common: self
+                            .common
+                            .into_iter()
+                            .map(|(k, diff)| (k.clone(), $crate::IntoOwnedDiff::into_owned(diff)))
+                            .collect(),
+                        added: self
+                            .added
+                            .into_iter()
+                            .map(|(k, v)| (k.clone(), v.clone()))
+                            .collect(),
+                        removed: self
+                            .removed
+                            .into_iter()
+                            .map(|(k, v)| (k.clone(), v.clone()))
+                            .collect(),
+                        $(order: <$crate::$order<_> as $crate::IntoOwnedDiff>::into_owned(
+                            self.order,
+                        ),)?
+                    }
+                }
+            }
+
+            impl<'daft, K, V> $crate::Invert for [<$typ RecursiveDiff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq,
+                V: $crate::Diffable,
+                V::Diff<'daft>: $crate::Invert,
+            {
+                fn invert(self) -> Self {
+                    Self {
+                        common: self
+                            .common
+                            .into_iter()
+                            .map(|(k, diff)| (k, $crate::Invert::invert(diff)))
+                            .collect(),
+                        added: self.removed,
+                        removed: self.added,
+                        $(order: <$crate::$order<_> as $crate::Invert>::invert(self.order),)?
+                    }
+                }
+            }
+
+            impl<K: $key_constraint + Eq, V, D: $crate::Invert> $crate::Invert for [<$typ RecursiveDiffOwned>]<K, V, D> {
+                fn invert(self) -> Self {
+                    Self {
+                        common: self
+                            .common
+                            .into_iter()
+                            .map(|(k, diff)| (k, $crate::Invert::invert(diff)))
+                            .collect(),
+                        added: self.removed,
+                        removed: self.added,
+                        $(order: <$crate::$order<_> as $crate::Invert>::invert(self.order),)?
+                    }
+                }
+            }
+
+            impl<'daft, K, V> $crate::Patch<$typ<K, V>> for [<$typ RecursiveDiff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq + Clone,
+                V: $crate::Diffable + Clone + PartialEq,
+                V::Diff<'daft>: $crate::Patch<V>,
+            {
+                fn apply(&self, target: &mut $typ<K, V>) -> Result<(), $crate::PatchError> {
+                    patch_map!(
+                        target,
+                        $remove,
+                        removed = self.removed.iter().map(|(k, v)| (*k, *v)),
+                        common = self.common.iter().map(|(k, diff)| (*k, diff)),
+                        added = self.added.iter().map(|(k, v)| (*k, *v)),
+                        $(order = ::core::convert::identity::<&$crate::$order<_>>(&self.order),)?
+                    )
+                }
+            }
+
+            impl<K, V, D> $crate::Patch<$typ<K, V>> for [<$typ RecursiveDiffOwned>]<K, V, D>
+            where
+                K: $key_constraint + Eq + Clone,
+                V: Clone + PartialEq,
+                D: $crate::Patch<V>,
+            {
+                fn apply(&self, target: &mut $typ<K, V>) -> Result<(), $crate::PatchError> {
+                    patch_map!(
+                        target,
+                        $remove,
+                        removed = &self.removed,
+                        common = &self.common,
+                        added = &self.added,
+                        $(order = ::core::convert::identity::<&$crate::$order<_>>(&self.order),)?
+                    )
+                }
+            }
+
+            impl<'daft, K, V> $crate::Merge<$typ<K, V>> for [<$typ RecursiveDiff>]<'daft, K, V>
+            where
+                K: $key_constraint + Eq + Clone + ::core::fmt::Debug,
+                V: $crate::Diffable + Clone + PartialEq,
+                V::Diff<'daft>: $crate::Merge<V> + $crate::DiffSummary,
+            {
+                fn merge_at(
+                    &self,
+                    theirs: &Self,
+                    target: &mut $typ<K, V>,
+                    path: &$crate::DiffPath<'_>,
+                    conflict: &mut dyn FnMut(&$crate::DiffPath<'_>, $crate::MergeConflictKind),
+                ) {
+                    for k in theirs.removed.keys() {
+                        // If ours removed the entry as well, there's nothing
+                        // to do.
+                        match self.common.get(k) {
+                            Some(diff) if $crate::DiffSummary::is_unchanged(diff) => {
+                                target.$remove(*k);
+                            }
+                            Some(_) => conflict(
+                                &path.join($crate::PathSegment::Key(k)),
+                                $crate::MergeConflictKind::RemovedAndModified,
+                            ),
+                            None => {}
+                        }
+                    }
+                    for (k, diff) in &theirs.common {
+                        if $crate::DiffSummary::is_unchanged(diff) {
+                            continue;
+                        }
+                        let path = path.join($crate::PathSegment::Key(k));
+                        match (self.common.get(k), target.get_mut(*k)) {
+                            (Some(ours), Some(value)) => $crate::Merge::merge_at(
+                                ours,
+                                diff,
+                                value,
+                                &path,
+                                conflict,
+                            ),
+                            _ => conflict(
+                                &path,
+                                $crate::MergeConflictKind::RemovedAndModified,
+                            ),
+                        }
+                    }
+                    for (k, v) in &theirs.added {
+                        match self.added.get(k) {
+                            Some(ours) if ours == v => {}
+                            Some(_) => conflict(
+                                &path.join($crate::PathSegment::Key(k)),
+                                $crate::MergeConflictKind::BothAdded,
+                            ),
+                            None => {
+                                target.insert((*k).clone(), (*v).clone());
+                            }
+                        }
+                    }
+                    $(merge_order!(
+                        target,
+                        path,
+                        conflict,
+                        ours = ::core::convert::identity::<&$crate::$order<_>>(&self.order),
+                        theirs = &theirs.order,
+                        keys = theirs.common.keys().copied(),
+                    );)?
+                }
+            }
+
+            impl<'daft, K: $key_constraint + Eq, V: $crate::Diffable> [<$typ Diff>]<'daft, K, V> {
+                /// Diff the values of common entries, converting this into a
+                #[doc = "[`" $typ "RecursiveDiff`]."]
+                pub fn into_recursive(self) -> [<$typ RecursiveDiff>]<'daft, K, V> {
+                    [<$typ RecursiveDiff>] {
+                        common: self
+                            .common
+                            .into_iter()
+                            .map(|(k, leaf)| (k, $crate::Diffable::diff(leaf.before, leaf.after)))
+                            .collect(),
+                        added: self.added,
+                        removed: self.removed,
+                        $(order: ::core::convert::identity::<$crate::$order<_>>(self.order),)?
+                    }
+                }
+            }
+
+            impl<K: $key_constraint + Eq, V: $crate::Diffable> $crate::DiffableMap for $typ<K, V> {
+                type RecursiveDiff<'daft> = [<$typ RecursiveDiff>]<'daft, K, V> where K: 'daft, V: 'daft;
+
+                fn recursive_diff<'daft>(&'daft self, other: &'daft Self) -> Self::RecursiveDiff<'daft> {
+                    $crate::Diffable::diff(self, other).into_recursive()
+                }
+            }
+        }
     }
 }
 
 /// Create a type `<SetType>Diff` and `impl Diffable` on it.
 ///
-/// This is supported for `BTreeSet` and `HashSet`.
+/// This is supported for `BTreeSet`, `HashSet` and `IndexSet`. `$remove` is
+/// the name of the method used to remove entries from the set. For ordered
+/// sets, `$order` is `OrderDiff`, which adds an `order` field to the diff.
 #[cfg(feature = "alloc")]
 macro_rules! set_diff {
-    ($(#[$doc:meta])* $typ:ident, $key_constraint:ident) => {
+    ($(#[$doc:meta])* $typ:ident, $key_constraint:ident, $remove:ident $(, $order:ident)?) => {
         paste::paste! {
             $(#[$doc])*
             #[derive(Debug, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize))]
             pub struct [<$typ Diff>]<'daft, K: $key_constraint + Eq> {
                 /// Entries common to both sets.
                 pub common: $typ<&'daft K>,
@@ -217,6 +1165,11 @@ macro_rules! set_diff {
 
                 /// Entries present in the `before` set, but not in `after`.
                 pub removed: $typ<&'daft K>,
+
+                $(
+                /// Changes to the relative order of common entries.
+                pub order: $crate::$order<&'daft K>,
+                )?
             }
 
             impl<'daft, K: $key_constraint + Eq> [<$typ Diff>]<'daft, K> {
@@ -226,6 +1179,7 @@ macro_rules! set_diff {
                         common: $typ::new(),
                         added: $typ::new(),
                         removed: $typ::new(),
+                        $(order: $crate::$order::new(),)?
                     }
                 }
             }
@@ -238,6 +1192,159 @@ macro_rules! set_diff {
                 }
             }
 
+            impl<'daft, K> $crate::VisitDiff for [<$typ Diff>]<'daft, K>
+            where
+                K: $key_constraint + Eq + ::core::fmt::Debug,
+            {
+                fn visit_at(
+                    &self,
+                    path: &$crate::DiffPath<'_>,
+                    visitor: &mut dyn $crate::DiffVisitor,
+                ) {
+                    for k in &self.removed {
+                        visitor.removed(path, k);
+                    }
+                    $(<$crate::$order<_> as $crate::VisitDiff>::visit_at(
+                        &self.order,
+                        path,
+                        visitor,
+                    );)?
+                    for k in &self.added {
+                        visitor.added(path, k);
+                    }
+                }
+            }
+
+            impl<'daft, K: $key_constraint + Eq> $crate::DiffSummary for [<$typ Diff>]<'daft, K> {
+                fn is_unchanged(&self) -> bool {
+                    self.added.is_empty()
+                        && self.removed.is_empty()
+                        $(&& $crate::$order::is_empty(&self.order))?
+                }
+            }
+
+            #[doc = "An owned version of [`" $typ "Diff`], created with"]
+            /// [`IntoOwnedDiff::into_owned`](crate::IntoOwnedDiff::into_owned).
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+            pub struct [<$typ DiffOwned>]<K: $key_constraint + Eq> {
+                /// Entries common to both sets.
+                pub common: $typ<K>,
+
+                /// Entries present in the `after` set, but not in `before`.
+                pub added: $typ<K>,
+
+                /// Entries present in the `before` set, but not in `after`.
+                pub removed: $typ<K>,
+
+                $(
+                /// Changes to the relative order of common entries.
+                pub order: $crate::$order<K>,
+                )?
+            }
+
+            impl<'daft, K> $crate::IntoOwnedDiff for [<$typ Diff>]<'daft, K>
+            where
+                K: $key_constraint + Eq + Clone,
+            {
+                type Owned = [<$typ DiffOwned>]<K>;
+
+                fn into_owned(self) -> Self::Owned {
+                    [<$typ DiffOwned>] {
+                        common: self.common.into_iter().cloned().collect(),
+                        added: self.added.into_iter().cloned().collect(),
+                        removed: self.removed.into_iter().cloned().collect(),
+                        $(order: <$crate::$order<_> as $crate::IntoOwnedDiff>::into_owned(
+                            self.order,
+                        ),)?
+                    }
+                }
+            }
+
+            impl<'daft, K: $key_constraint + Eq> $crate::Invert for [<$typ Diff>]<'daft, K> {
+                fn invert(self) -> Self {
+                    Self {
+                        common: self.common,
+                        added: self.removed,
+                        removed: self.added,
+                        $(order: <$crate::$order<_> as $crate::Invert>::invert(self.order),)?
+                    }
+                }
+            }
+
+            impl<K: $key_constraint + Eq> $crate::Invert for [<$typ DiffOwned>]<K> {
+                fn invert(self) -> Self {
+                    Self {
+                        common: self.common,
+                        added: self.removed,
+                        removed: self.added,
+                        $(order: <$crate::$order<_> as $crate::Invert>::invert(self.order),)?
+                    }
+                }
+            }
+
+            impl<'daft, K> $crate::Patch<$typ<K>> for [<$typ Diff>]<'daft, K>
+            where
+                K: $key_constraint + Eq + Clone,
+            {
+                fn apply(&self, target: &mut $typ<K>) -> Result<(), $crate::PatchError> {
+                    patch_set!(
+                        target,
+                        $remove,
+                        removed = self.removed.iter().copied(),
+                        common = self.common.iter().copied(),
+                        added = self.added.iter().copied(),
+                        $(order = ::core::convert::identity::<&$crate::$order<_>>(&self.order),)?
+                    )
+                }
+            }
+
+            impl<K> $crate::Patch<$typ<K>> for [<$typ DiffOwned>]<K>
+            where
+                K: $key_constraint + Eq + Clone,
+            {
+                fn apply(&self, target: &mut $typ<K>) -> Result<(), $crate::PatchError> {
+                    patch_set!(
+                        target,
+                        $remove,
+                        removed = &self.removed,
+                        common = &self.common,
+                        added = &self.added,
+                        $(order = ::core::convert::identity::<&$crate::$order<_>>(&self.order),)?
+                    )
+                }
+            }
+
+            impl<'daft, K> $crate::Merge<$typ<K>> for [<$typ Diff>]<'daft, K>
+            where
+                K: $key_constraint + Eq + Clone,
+            {
+                fn merge_at(
+                    &self,
+                    theirs: &Self,
+                    target: &mut $typ<K>,
+                    _path: &$crate::DiffPath<'_>,
+                    _conflict: &mut dyn FnMut(&$crate::DiffPath<'_>, $crate::MergeConflictKind),
+                ) {
+                    for k in &theirs.removed {
+                        target.$remove(*k);
+                    }
+                    for k in &theirs.added {
+                        if !target.contains(*k) {
+                            target.insert((*k).clone());
+                        }
+                    }
+                    $(merge_order!(
+                        target,
+                        _path,
+                        _conflict,
+                        ours = ::core::convert::identity::<&$crate::$order<_>>(&self.order),
+                        theirs = &theirs.order,
+                        keys = theirs.common.iter().copied(),
+                    );)?
+                }
+            }
+
             impl<K: $key_constraint + Eq>
                 $crate::Diffable for $typ<K>
             {
@@ -248,6 +1355,14 @@ macro_rules! set_diff {
                     diff.removed = self.difference(other).collect();
                     diff.added = other.difference(self).collect();
                     diff.common = self.intersection(other).collect();
+                    $(
+                        diff.order = $crate::$order::compute(
+                            self.iter(),
+                            other.iter(),
+                            |k| self.get_index_of(*k),
+                            |k| other.get_index_of(*k),
+                        );
+                    )?
                     diff
                 }
             }
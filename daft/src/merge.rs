@@ -0,0 +1,356 @@
+//! Three-way merges.
+
+use crate::{DiffPath, Leaf};
+#[cfg(feature = "alloc")]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+/// A diff that can be merged with another diff from the same base value.
+///
+/// `T` is the type of the value being merged. For example, a
+/// [`BTreeMapDiff<'_, K, V>`](crate::BTreeMapDiff) implements
+/// `Merge<BTreeMap<K, V>>`.
+///
+/// A three-way merge starts with a `base` value and two values derived from
+/// it, `ours` and `theirs`. The diff `base.diff(&ours)` is merged with
+/// `base.diff(&theirs)`, applying the changes from `theirs` to a copy of
+/// `ours`.
+///
+/// Changes made on only one side are kept, as are identical changes made on
+/// both sides. If both sides make different changes to the same value, that
+/// value is left as it is in `ours`, and a conflict is reported along with the
+/// [`DiffPath`] to it. In particular:
+///
+/// * For [`Leaf`]s, both sides conflict if they changed the value to different
+///   values.
+/// * For map diffs, entries are merged key by key. Values modified on both
+///   sides are diffed and merged recursively. Both sides conflict if one side
+///   removed an entry that the other side modified, or if both sides added
+///   different values for the same key.
+/// * For set diffs, the entries added and removed on both sides are combined.
+///   Set merges never conflict, except as described below.
+/// * For `IndexMap` and `IndexSet` diffs, if only theirs changed the relative
+///   order of common entries, the entries of the target are moved into their
+///   order. Both sides conflict if both changed the order differently.
+/// * For multiset diffs, changes to the number of occurrences of each element
+///   are combined. If both sides added (or both removed) occurrences of the
+///   same element, the larger change is kept. Multiset merges never conflict.
+///
+/// This trait is implemented for [`Leaf`]s, for all of the map and set diff
+/// types in this crate, and for tuples and arrays of diffs. The
+/// [`Diffable`](macro@crate::Diffable) derive macro implements it for
+/// generated diff structs, by merging each field that isn't ignored. Ignored
+/// fields are left as they are in `ours`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use daft::{Diffable, Merge, MergeConflictKind};
+/// use std::collections::BTreeMap;
+///
+/// let base: BTreeMap<&str, u32> = [("a", 1), ("b", 2)].into();
+/// let ours: BTreeMap<&str, u32> = [("a", 10), ("b", 2), ("c", 3)].into();
+/// let theirs: BTreeMap<&str, u32> = [("a", 1), ("b", 20)].into();
+///
+/// // Non-overlapping changes are combined.
+/// let mut merged = ours.clone();
+/// base.diff(&ours).merge(&base.diff(&theirs), &mut merged).unwrap();
+/// assert_eq!(merged, [("a", 10), ("b", 20), ("c", 3)].into());
+///
+/// // Overlapping changes are reported as conflicts.
+/// let theirs: BTreeMap<&str, u32> = [("a", 100), ("b", 2)].into();
+/// let mut merged = ours.clone();
+/// let error =
+///     base.diff(&ours).merge(&base.diff(&theirs), &mut merged).unwrap_err();
+/// assert_eq!(error.conflicts().len(), 1);
+/// assert_eq!(error.conflicts()[0].path, r#"["a"]"#);
+/// assert_eq!(error.conflicts()[0].kind, MergeConflictKind::BothModified);
+///
+/// // The conflicting value is left as it is in `ours`.
+/// assert_eq!(merged, ours);
+/// # }
+/// ```
+pub trait Merge<T: ?Sized> {
+    /// Merge `theirs` into `target`, with paths relative to `path`.
+    ///
+    /// `self` is the diff from the base value to ours, and `theirs` is the
+    /// diff from the same base value to theirs. `target` must be equal to
+    /// ours. `conflict` is called with the path and kind of each conflict.
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut T,
+        path: &DiffPath<'_>,
+        conflict: &mut dyn FnMut(&DiffPath<'_>, MergeConflictKind),
+    );
+
+    /// Merge `theirs` into `target`, which must be equal to ours.
+    ///
+    /// Returns a [`MergeError`] listing all of the conflicts found, if any.
+    /// Non-conflicting changes are merged into `target` either way.
+    #[cfg(feature = "alloc")]
+    fn merge(&self, theirs: &Self, target: &mut T) -> Result<(), MergeError> {
+        let mut conflicts = Vec::new();
+        self.merge_at(theirs, target, &DiffPath::root(), &mut |path, kind| {
+            conflicts.push(MergeConflict { path: path.to_string(), kind });
+        });
+        if conflicts.is_empty() {
+            Ok(())
+        } else {
+            Err(MergeError { conflicts })
+        }
+    }
+}
+
+/// Check whether the value in `theirs` should replace the value in ours,
+/// reporting a conflict if both sides changed it differently.
+pub(crate) fn merge_leaf<T: PartialEq>(
+    ours: &Leaf<T>,
+    theirs: &Leaf<T>,
+    path: &DiffPath<'_>,
+    conflict: &mut dyn FnMut(&DiffPath<'_>, MergeConflictKind),
+) -> bool {
+    if theirs.before == theirs.after || ours.after == theirs.after {
+        false
+    } else if ours.before == ours.after {
+        true
+    } else {
+        conflict(path, MergeConflictKind::BothModified);
+        false
+    }
+}
+
+impl<T: Clone + PartialEq> Merge<T> for Leaf<&T> {
+    #[inline]
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut T,
+        path: &DiffPath<'_>,
+        conflict: &mut dyn FnMut(&DiffPath<'_>, MergeConflictKind),
+    ) {
+        if merge_leaf(self, theirs, path, conflict) {
+            target.clone_from(theirs.after);
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> Merge<T> for Leaf<T> {
+    #[inline]
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut T,
+        path: &DiffPath<'_>,
+        conflict: &mut dyn FnMut(&DiffPath<'_>, MergeConflictKind),
+    ) {
+        self.as_ref().merge_at(&theirs.as_ref(), target, path, conflict)
+    }
+}
+
+impl<T: Clone + PartialEq> Merge<Option<T>> for Leaf<Option<&T>> {
+    #[inline]
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Option<T>,
+        path: &DiffPath<'_>,
+        conflict: &mut dyn FnMut(&DiffPath<'_>, MergeConflictKind),
+    ) {
+        if merge_leaf(self, theirs, path, conflict) {
+            *target = theirs.after.cloned();
+        }
+    }
+}
+
+impl<T: Clone + PartialEq, U: Clone + PartialEq> Merge<Result<T, U>>
+    for Leaf<Result<&T, &U>>
+{
+    #[inline]
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut Result<T, U>,
+        path: &DiffPath<'_>,
+        conflict: &mut dyn FnMut(&DiffPath<'_>, MergeConflictKind),
+    ) {
+        if merge_leaf(self, theirs, path, conflict) {
+            *target = theirs.after.cloned().map_err(Clone::clone);
+        }
+    }
+}
+
+/// The kind of a conflict found by [`Merge`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MergeConflictKind {
+    /// Both sides changed a value to different values.
+    BothModified,
+
+    /// One side removed a map entry that the other side modified.
+    RemovedAndModified,
+
+    /// Both sides added a map entry with the same key, but different values.
+    BothAdded,
+
+    /// Both sides changed the relative order of the entries of an ordered map
+    /// or set differently.
+    BothReordered,
+}
+
+impl fmt::Display for MergeConflictKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BothModified => {
+                write!(f, "both sides modified the value differently")
+            }
+            Self::RemovedAndModified => {
+                write!(f, "one side removed the entry, the other modified it")
+            }
+            Self::BothAdded => {
+                write!(f, "both sides added the entry with different values")
+            }
+            Self::BothReordered => {
+                write!(f, "both sides reordered the entries differently")
+            }
+        }
+    }
+}
+
+/// A conflict found by [`Merge::merge`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MergeConflict {
+    /// The path to the conflicting value, rendered using the
+    /// [`Display`](fmt::Display) implementation of [`DiffPath`].
+    ///
+    /// This is empty if the value at the root of the merge conflicted.
+    pub path: String,
+
+    /// The kind of conflict.
+    pub kind: MergeConflictKind,
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{}: {}", self.path, self.kind)
+        }
+    }
+}
+
+/// An error returned by [`Merge::merge`], listing the conflicts found.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MergeError {
+    conflicts: Vec<MergeConflict>,
+}
+
+#[cfg(feature = "alloc")]
+impl MergeError {
+    /// Return the conflicts found, in the order they were found.
+    pub fn conflicts(&self) -> &[MergeConflict] {
+        &self.conflicts
+    }
+
+    /// Consume this error, returning the conflicts found.
+    pub fn into_conflicts(self) -> Vec<MergeConflict> {
+        self.conflicts
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} merge conflict(s)", self.conflicts.len())?;
+        for (ix, conflict) in self.conflicts.iter().enumerate() {
+            let separator = if ix == 0 { ": " } else { "; " };
+            write!(f, "{separator}{conflict}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for MergeError {}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::Diffable;
+
+    #[test]
+    fn leaf_and_tuple_merge() {
+        let base = (1_u32, Some('a'), 5_u8);
+        let ours = (2, Some('a'), 6);
+        let theirs = (1, None, 6);
+
+        let mut merged = ours;
+        base.diff(&ours).merge(&base.diff(&theirs), &mut merged).unwrap();
+        assert_eq!(merged, (2, None, 6));
+
+        let theirs = (3, Some('a'), 7);
+        let mut merged = ours;
+        let error = base
+            .diff(&ours)
+            .merge(&base.diff(&theirs), &mut merged)
+            .unwrap_err();
+        assert_eq!(merged, ours);
+        assert_eq!(
+            error.to_string(),
+            "2 merge conflict(s): \
+             0: both sides modified the value differently; \
+             2: both sides modified the value differently"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn collections_merge() {
+        use std::collections::{BTreeMap, HashSet};
+
+        let base: BTreeMap<u32, &str> = [(1, "a"), (2, "b"), (3, "c")].into();
+        let ours: BTreeMap<u32, &str> = [(2, "x"), (3, "c"), (4, "d")].into();
+        let theirs: BTreeMap<u32, &str> =
+            [(1, "a"), (2, "x"), (4, "d"), (5, "e")].into();
+        let mut merged = ours.clone();
+        base.diff(&ours).merge(&base.diff(&theirs), &mut merged).unwrap();
+        assert_eq!(merged, [(2, "x"), (4, "d"), (5, "e")].into());
+
+        let theirs: BTreeMap<u32, &str> =
+            [(1, "y"), (2, "b"), (3, "c"), (4, "z")].into();
+        let mut merged = ours.clone();
+        let error = base
+            .diff(&ours)
+            .merge(&base.diff(&theirs), &mut merged)
+            .unwrap_err();
+        assert_eq!(merged, ours);
+        let conflicts: Vec<_> = error
+            .conflicts()
+            .iter()
+            .map(|c| (c.path.as_str(), c.kind))
+            .collect();
+        assert_eq!(
+            conflicts,
+            [
+                ("[1]", MergeConflictKind::RemovedAndModified),
+                ("[4]", MergeConflictKind::BothAdded),
+            ]
+        );
+
+        let base: HashSet<u32> = [1, 2, 3].into();
+        let ours: HashSet<u32> = [2, 3, 4].into();
+        let theirs: HashSet<u32> = [1, 2, 5].into();
+        let mut merged = ours.clone();
+        base.diff(&ours).merge(&base.diff(&theirs), &mut merged).unwrap();
+        assert_eq!(merged, [2, 4, 5].into());
+    }
+}
@@ -0,0 +1,344 @@
+//! Multiset diffs.
+
+use crate::{
+    DiffPath, DiffSummary, DiffVisitor, Diffable, IntoOwnedDiff, Invert, Merge,
+    MergeConflictKind, Patch, PatchError, VisitDiff,
+};
+use alloc::collections::{BTreeMap, BinaryHeap};
+use core::{fmt, iter, mem};
+
+/// A diff of two multisets: unordered collections that can contain the same
+/// element more than once.
+///
+/// Each map records elements along with a number of occurrences:
+///
+/// - `common`: Elements present on both sides, with the number of occurrences
+///   shared by both.
+/// - `added`: Elements with more occurrences in `after` than in `before`, with
+///   the number of extra occurrences.
+/// - `removed`: Elements with more occurrences in `before` than in `after`,
+///   with the number of missing occurrences.
+///
+/// The [`Diffable`] implementation for [`BinaryHeap`] returns a
+/// `MultisetDiff`, since the iteration order of a heap is arbitrary. A
+/// `MultisetDiff` can also be created from any two iterators over references
+/// with [`MultisetDiff::new`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use daft::{Diffable, MultisetDiff};
+/// use std::collections::BinaryHeap;
+///
+/// let before: BinaryHeap<u32> = [1, 1, 2, 3].into();
+/// let after: BinaryHeap<u32> = [1, 2, 2, 2].into();
+///
+/// let diff: MultisetDiff<'_, u32> = before.diff(&after);
+/// assert_eq!(diff.common, [(&1, 1), (&2, 1)].into());
+/// assert_eq!(diff.added, [(&2, 2)].into());
+/// assert_eq!(diff.removed, [(&1, 1), (&3, 1)].into());
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MultisetDiff<'daft, T: Ord> {
+    /// Elements common to both sides, with the number of shared occurrences.
+    pub common: BTreeMap<&'daft T, usize>,
+
+    /// Elements with more occurrences in `after`, with the number of extra
+    /// occurrences.
+    pub added: BTreeMap<&'daft T, usize>,
+
+    /// Elements with more occurrences in `before`, with the number of missing
+    /// occurrences.
+    pub removed: BTreeMap<&'daft T, usize>,
+}
+
+impl<'daft, T: Ord> MultisetDiff<'daft, T> {
+    /// Compute a multiset diff between two collections.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use daft::MultisetDiff;
+    ///
+    /// // Any iterator over references can be diffed.
+    /// let diff = MultisetDiff::new(&["a", "b", "b"], &["b", "c"]);
+    /// assert_eq!(diff.common, [(&"b", 1)].into());
+    /// assert_eq!(diff.added, [(&"c", 1)].into());
+    /// assert_eq!(diff.removed, [(&"a", 1), (&"b", 1)].into());
+    /// ```
+    pub fn new<B, A>(before: B, after: A) -> Self
+    where
+        B: IntoIterator<Item = &'daft T>,
+        A: IntoIterator<Item = &'daft T>,
+    {
+        let mut counts: BTreeMap<&'daft T, (usize, usize)> = BTreeMap::new();
+        for value in before {
+            counts.entry(value).or_default().0 += 1;
+        }
+        for value in after {
+            counts.entry(value).or_default().1 += 1;
+        }
+
+        let mut diff = Self {
+            common: BTreeMap::new(),
+            added: BTreeMap::new(),
+            removed: BTreeMap::new(),
+        };
+        for (value, (before, after)) in counts {
+            let common = before.min(after);
+            if common > 0 {
+                diff.common.insert(value, common);
+            }
+            if after > common {
+                diff.added.insert(value, after - common);
+            }
+            if before > common {
+                diff.removed.insert(value, before - common);
+            }
+        }
+        diff
+    }
+}
+
+impl<T: Ord> Diffable for BinaryHeap<T> {
+    type Diff<'daft>
+        = MultisetDiff<'daft, T>
+    where
+        T: 'daft;
+
+    fn diff<'daft>(&'daft self, other: &'daft Self) -> Self::Diff<'daft> {
+        MultisetDiff::new(self, other)
+    }
+}
+
+impl<T: Ord> DiffSummary for MultisetDiff<'_, T> {
+    fn is_unchanged(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Added and removed elements are reported once per occurrence, at the path to
+/// the multiset itself.
+impl<T: Ord + fmt::Debug> VisitDiff for MultisetDiff<'_, T> {
+    fn visit_at(&self, path: &DiffPath<'_>, visitor: &mut dyn DiffVisitor) {
+        for (value, count) in &self.removed {
+            for _ in 0..*count {
+                visitor.removed(path, value);
+            }
+        }
+        for (value, count) in &self.added {
+            for _ in 0..*count {
+                visitor.added(path, value);
+            }
+        }
+    }
+}
+
+impl<'daft, T: Ord> Invert for MultisetDiff<'daft, T> {
+    fn invert(self) -> Self {
+        Self { common: self.common, added: self.removed, removed: self.added }
+    }
+}
+
+/// An owned version of [`MultisetDiff`], created with
+/// [`IntoOwnedDiff::into_owned`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MultisetDiffOwned<T: Ord> {
+    /// Elements common to both sides, with the number of shared occurrences.
+    pub common: BTreeMap<T, usize>,
+
+    /// Elements with more occurrences in `after`, with the number of extra
+    /// occurrences.
+    pub added: BTreeMap<T, usize>,
+
+    /// Elements with more occurrences in `before`, with the number of missing
+    /// occurrences.
+    pub removed: BTreeMap<T, usize>,
+}
+
+impl<T: Ord + Clone> IntoOwnedDiff for MultisetDiff<'_, T> {
+    type Owned = MultisetDiffOwned<T>;
+
+    fn into_owned(self) -> Self::Owned {
+        let owned = |counts: BTreeMap<&T, usize>| {
+            counts.into_iter().map(|(k, n)| (k.clone(), n)).collect()
+        };
+        MultisetDiffOwned {
+            common: owned(self.common),
+            added: owned(self.added),
+            removed: owned(self.removed),
+        }
+    }
+}
+
+impl<T: Ord> Invert for MultisetDiffOwned<T> {
+    fn invert(self) -> Self {
+        Self { common: self.common, added: self.removed, removed: self.added }
+    }
+}
+
+/// Apply the removed and added occurrences of a multiset diff to `target`.
+fn patch_heap<'a, T: Ord + Clone + 'a>(
+    target: &mut BinaryHeap<T>,
+    removed: impl IntoIterator<Item = (&'a T, usize)>,
+    added: impl IntoIterator<Item = (&'a T, usize)>,
+) -> Result<(), PatchError> {
+    let mut to_remove: BTreeMap<&T, usize> = removed.into_iter().collect();
+    let mut counts: BTreeMap<&T, usize> = BTreeMap::new();
+    for value in target.iter() {
+        *counts.entry(value).or_default() += 1;
+    }
+    for (value, count) in &to_remove {
+        if counts.get(*value).copied().unwrap_or_default() < *count {
+            return Err(PatchError::MissingEntry);
+        }
+    }
+
+    remove_occurrences(target, &mut to_remove);
+    target.extend(
+        added
+            .into_iter()
+            .flat_map(|(value, count)| iter::repeat_n(value, count))
+            .cloned(),
+    );
+    Ok(())
+}
+
+/// Remove up to the given number of occurrences of each value from `target`.
+fn remove_occurrences<T: Ord>(
+    target: &mut BinaryHeap<T>,
+    to_remove: &mut BTreeMap<&T, usize>,
+) {
+    let mut items = mem::take(target).into_vec();
+    items.retain(|value| match to_remove.get_mut(value) {
+        Some(count) if *count > 0 => {
+            *count -= 1;
+            false
+        }
+        _ => true,
+    });
+    *target = items.into();
+}
+
+impl<T: Ord + Clone> Patch<BinaryHeap<T>> for MultisetDiff<'_, T> {
+    fn apply(&self, target: &mut BinaryHeap<T>) -> Result<(), PatchError> {
+        patch_heap(
+            target,
+            self.removed.iter().map(|(k, n)| (*k, *n)),
+            self.added.iter().map(|(k, n)| (*k, *n)),
+        )
+    }
+}
+
+impl<T: Ord + Clone> Patch<BinaryHeap<T>> for MultisetDiffOwned<T> {
+    fn apply(&self, target: &mut BinaryHeap<T>) -> Result<(), PatchError> {
+        patch_heap(
+            target,
+            self.removed.iter().map(|(k, n)| (k, *n)),
+            self.added.iter().map(|(k, n)| (k, *n)),
+        )
+    }
+}
+
+/// Changes to the number of occurrences of each element are reconciled: if
+/// both sides added (or both removed) occurrences of an element, only the part
+/// of their change that ours hasn't already made is applied, so the larger of
+/// the two changes is kept. Otherwise, their change is applied as is (as long
+/// as there are enough occurrences left to remove). Multiset merges never
+/// conflict.
+impl<T: Ord + Clone> Merge<BinaryHeap<T>> for MultisetDiff<'_, T> {
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut BinaryHeap<T>,
+        _path: &DiffPath<'_>,
+        _conflict: &mut dyn FnMut(&DiffPath<'_>, MergeConflictKind),
+    ) {
+        // The number of occurrences of `value` that theirs changed beyond
+        // what ours already did.
+        let remaining =
+            |ours: &BTreeMap<&T, usize>, value: &T, count: usize| {
+                count.saturating_sub(ours.get(value).copied().unwrap_or(0))
+            };
+
+        let mut to_remove: BTreeMap<&T, usize> = theirs
+            .removed
+            .iter()
+            .map(|(k, n)| (*k, remaining(&self.removed, k, *n)))
+            .filter(|(_, n)| *n > 0)
+            .collect();
+        remove_occurrences(target, &mut to_remove);
+        target.extend(
+            theirs
+                .added
+                .iter()
+                .flat_map(|(value, count)| {
+                    iter::repeat_n(
+                        *value,
+                        remaining(&self.added, value, *count),
+                    )
+                })
+                .cloned(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn binary_heap_diff() {
+        let before: BinaryHeap<u32> = [3, 1, 2, 1].into();
+        let after: BinaryHeap<u32> = [2, 1, 2, 2].into();
+        let diff = before.diff(&after);
+        assert!(diff.is_modified());
+        assert!(before.diff(&before).is_unchanged());
+        assert_eq!(diff.invert(), after.diff(&before));
+
+        let sorted = |heap: BinaryHeap<u32>| heap.into_sorted_vec();
+        let diff = before.diff(&after);
+        let mut target = before.clone();
+        diff.apply(&mut target).unwrap();
+        assert_eq!(sorted(target), [1, 2, 2, 2]);
+
+        // Occurrences that were removed must be present.
+        let mut target: BinaryHeap<u32> = [1, 2].into();
+        assert_eq!(diff.apply(&mut target), Err(PatchError::MissingEntry));
+        assert_eq!(sorted(target), [1, 2]);
+
+        let mut target = before.clone();
+        diff.into_owned().apply(&mut target).unwrap();
+        assert_eq!(sorted(target), [1, 2, 2, 2]);
+
+        // Where both sides changed the count of an element in the same
+        // direction, the larger change is kept.
+        let ours: BinaryHeap<u32> = [1, 2, 3, 4].into();
+        let theirs: BinaryHeap<u32> = [2, 3, 4, 4].into();
+        let mut merged = ours.clone();
+        before.diff(&ours).merge(&before.diff(&theirs), &mut merged).unwrap();
+        assert_eq!(sorted(merged), [2, 3, 4, 4]);
+
+        // Identical changes are kept once.
+        let base: BinaryHeap<u32> = BinaryHeap::new();
+        let ours: BinaryHeap<u32> = [5].into();
+        let mut merged = ours.clone();
+        base.diff(&ours).merge(&base.diff(&ours), &mut merged).unwrap();
+        assert_eq!(sorted(merged), [5]);
+
+        // Changes in opposite directions are both applied.
+        let ours: BinaryHeap<u32> = [3, 1, 2, 1, 1].into();
+        let theirs: BinaryHeap<u32> = [3, 2, 1].into();
+        let mut merged = ours.clone();
+        before.diff(&ours).merge(&before.diff(&theirs), &mut merged).unwrap();
+        assert_eq!(sorted(merged), [1, 1, 2, 3]);
+
+        let before: Vec<u32> = [1, 1].into();
+        assert!(MultisetDiff::new(&before, before.iter().rev()).is_unchanged());
+    }
+}
@@ -0,0 +1,567 @@
+//! Changes to the order of keys in ordered collections.
+
+use crate::{
+    DiffPath, DiffSummary, DiffVisitor, IntoOwnedDiff, Invert, PathSegment,
+    VisitDiff,
+};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Changes to the relative order of the keys common to two ordered
+/// collections.
+///
+/// `IndexMapDiff` and `IndexSetDiff` store an `OrderDiff` in their
+/// `order` field, since the order of entries is meaningful for these types.
+/// Only the relative order of *common* keys is considered: keys that were
+/// added or removed shift the positions of the keys after them, but don't by
+/// themselves cause any keys to be reported as moved.
+///
+/// An `OrderDiff` consists of:
+///
+/// - `moved`: the common keys whose relative order changed, in the order they
+///   appear in the after collection, along with their positions in the
+///   before and after collections. This is a minimal set: all other common
+///   keys stay in the same relative order.
+/// - `moves`: a minimal sequence of moves that turns the order of the common
+///   keys in the before collection into their order in the after collection.
+/// - `added` and `removed`: the positions of the keys that were added to the
+///   after collection or removed from the before collection.
+///
+/// Applying a diff with [`Patch`](crate::Patch) moves common entries into
+/// their after order, and inserts added entries at their after positions, so
+/// that patching the before collection rebuilds the after collection.
+/// [`Merge`](crate::Merge) applies their order to the common entries if only
+/// their side reordered entries, and reports a conflict if both sides did so
+/// differently.
+///
+/// `K` is typically `&'daft Key`, or `Key` for owned diffs.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "indexmap")] {
+/// use daft::{Diffable, KeyMove, MovedKey};
+/// use indexmap::IndexMap;
+///
+/// let before: IndexMap<&str, u32> =
+///     [("allow-ssh", 1), ("allow-http", 2), ("deny-all", 3)].into();
+/// let after: IndexMap<&str, u32> =
+///     [("deny-all", 3), ("allow-ssh", 1), ("allow-http", 2)].into();
+///
+/// let diff = before.diff(&after);
+/// assert!(diff.common.values().all(|leaf| leaf.is_unchanged()));
+///
+/// // "deny-all" moved from the end to the start.
+/// assert_eq!(
+///     diff.order.moved,
+///     [MovedKey { key: &"deny-all", before: 2, after: 0 }],
+/// );
+/// assert_eq!(diff.order.moves, [KeyMove { key: &"deny-all", from: 2, to: 0 }]);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OrderDiff<K> {
+    /// The common keys whose relative order changed, in after order.
+    pub moved: Vec<MovedKey<K>>,
+
+    /// A minimal sequence of moves that turns the before order of the common
+    /// keys into their after order.
+    pub moves: Vec<KeyMove<K>>,
+
+    /// The keys only present in the after collection, with their positions
+    /// there, in after order.
+    pub added: Vec<KeyPosition<K>>,
+
+    /// The keys only present in the before collection, with their positions
+    /// there, in before order.
+    pub removed: Vec<KeyPosition<K>>,
+}
+
+impl<K> OrderDiff<K> {
+    /// Create a new, empty `OrderDiff` instance.
+    pub fn new() -> Self {
+        Self {
+            moved: Vec::new(),
+            moves: Vec::new(),
+            added: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+
+    /// Compute the order changes between two ordered collections.
+    ///
+    /// `before` and `after` iterate over the keys of the before and after
+    /// collections in order. `before_index_of` and `after_index_of` return the
+    /// position of a key in the before and after collections respectively, or
+    /// `None` if it isn't present there.
+    pub fn compute<I, J, F, G>(
+        before: I,
+        after: J,
+        mut before_index_of: F,
+        mut after_index_of: G,
+    ) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        J: IntoIterator<Item = K>,
+        F: FnMut(&K) -> Option<usize>,
+        G: FnMut(&K) -> Option<usize>,
+        K: Clone,
+    {
+        let mut diff = Self::new();
+
+        // The common keys, in before order, along with their positions.
+        let mut common: Vec<(K, usize, usize)> = Vec::new();
+        for (index, key) in before.into_iter().enumerate() {
+            match after_index_of(&key) {
+                Some(after) => common.push((key, index, after)),
+                None => diff.removed.push(KeyPosition { key, index }),
+            }
+        }
+        for (index, key) in after.into_iter().enumerate() {
+            if before_index_of(&key).is_none() {
+                diff.added.push(KeyPosition { key, index });
+            }
+        }
+
+        // The keys in the longest subsequence that is in order in both
+        // collections stay where they are, and all others are moved.
+        let mut is_moved = alloc::vec![true; common.len()];
+        for i in longest_increasing_subsequence(&common) {
+            is_moved[i] = false;
+        }
+
+        // `ranks[i]` is the rank in after order of the i-th common key in
+        // before order.
+        let mut by_after: Vec<usize> = (0..common.len()).collect();
+        by_after.sort_unstable_by_key(|&i| common[i].2);
+        let mut ranks = alloc::vec![0; common.len()];
+        for (rank, &i) in by_after.iter().enumerate() {
+            ranks[i] = rank;
+        }
+
+        // Each key in after order is moved to just after its predecessor in
+        // after order. Each predecessor is either not moved, or has already
+        // been moved into place, so this results in the after order.
+        //
+        // Rather than moving keys around in a vector, give each key a sort
+        // key such that the sequence is always in sort key order. A key
+        // starts out at `(i + 1, rank)`, where `i` is its position in before
+        // order. A moved key ends up at `(anchor + 1, rank)`, where `anchor`
+        // is the position of the first key before it in after order that
+        // isn't moved, or -1 if there is none: moved keys are placed after
+        // their anchor in rank order. A Fenwick tree over the sort keys that
+        // are in use then gives the position of a key in O(log n) time.
+        let mut anchors = alloc::vec![0; common.len()];
+        let mut anchor = 0;
+        for &i in &by_after {
+            if !is_moved[i] {
+                anchor = i + 1;
+            }
+            anchors[i] = anchor;
+        }
+        let mut sort_keys: Vec<(usize, usize)> = (0..common.len())
+            .map(|i| (i + 1, ranks[i]))
+            .chain(
+                (0..common.len())
+                    .filter(|&i| is_moved[i])
+                    .map(|i| (anchors[i], ranks[i])),
+            )
+            .collect();
+        sort_keys.sort_unstable();
+        let slot = |sort_key: (usize, usize)| {
+            sort_keys
+                .binary_search(&sort_key)
+                .expect("every sort key is present")
+        };
+
+        let mut present = Fenwick::new(sort_keys.len());
+        for (i, &rank) in ranks.iter().enumerate() {
+            present.add(slot((i + 1, rank)), 1);
+        }
+        for &i in &by_after {
+            if !is_moved[i] {
+                continue;
+            }
+            let (key, before, after) = &common[i];
+            diff.moved.push(MovedKey {
+                key: key.clone(),
+                before: *before,
+                after: *after,
+            });
+
+            let original = slot((i + 1, ranks[i]));
+            present.sub(original, 1);
+            let from = present.prefix_sum(original);
+            let moved = slot((anchors[i], ranks[i]));
+            present.add(moved, 1);
+            let to = present.prefix_sum(moved);
+            diff.moves.push(KeyMove { key: key.clone(), from, to });
+        }
+        diff
+    }
+
+    /// Rearrange `keys`, the common keys in before order, into after order.
+    #[cfg(feature = "indexmap")]
+    pub(crate) fn after_order<T>(
+        &self,
+        keys: impl IntoIterator<Item = T>,
+    ) -> Result<Vec<T>, crate::PatchError> {
+        let mut keys: Vec<Option<T>> = keys.into_iter().map(Some).collect();
+        let mut order: Vec<Option<T>> = keys.iter().map(|_| None).collect();
+
+        // The ranks of moved keys among the common keys are their positions,
+        // less the number of added or removed keys before them.
+        for moved in &self.moved {
+            let before = moved.before
+                - self.removed.partition_point(|p| p.index < moved.before);
+            let after = moved.after
+                - self.added.partition_point(|p| p.index < moved.after);
+            let key = keys.get_mut(before).and_then(Option::take);
+            match (key, order.get_mut(after)) {
+                (Some(key), Some(slot @ None)) => *slot = Some(key),
+                _ => return Err(crate::PatchError::Mismatch),
+            }
+        }
+
+        // The keys that didn't move fill the remaining slots, in order.
+        let mut unmoved = keys.into_iter().flatten();
+        order
+            .into_iter()
+            .map(|slot| slot.or_else(|| unmoved.next()))
+            .collect::<Option<Vec<T>>>()
+            .ok_or(crate::PatchError::Mismatch)
+    }
+
+    /// Return true if no common keys moved.
+    ///
+    /// Added and removed keys aren't considered here: they're also recorded
+    /// in the diff of the collection this order belongs to.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.moved.is_empty()
+    }
+}
+
+// Note: not deriving Default here because we don't want to require K to be
+// Default.
+impl<K> Default for OrderDiff<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K> DiffSummary for OrderDiff<K> {
+    #[inline]
+    fn is_unchanged(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Each moved key is reported with a [`PathSegment::Key`] path, along with its
+/// before and after positions.
+impl<K: fmt::Debug> VisitDiff for OrderDiff<K> {
+    fn visit_at(&self, path: &DiffPath<'_>, visitor: &mut dyn DiffVisitor) {
+        for moved in &self.moved {
+            visitor.moved(
+                &path.join(PathSegment::Key(&moved.key)),
+                moved.before,
+                moved.after,
+            );
+        }
+    }
+}
+
+impl<K: Clone> IntoOwnedDiff for OrderDiff<&K> {
+    type Owned = OrderDiff<K>;
+
+    fn into_owned(self) -> Self::Owned {
+        OrderDiff {
+            moved: self
+                .moved
+                .into_iter()
+                .map(|moved| MovedKey {
+                    key: moved.key.clone(),
+                    before: moved.before,
+                    after: moved.after,
+                })
+                .collect(),
+            moves: self
+                .moves
+                .into_iter()
+                .map(|KeyMove { key, from, to }| KeyMove {
+                    key: key.clone(),
+                    from,
+                    to,
+                })
+                .collect(),
+            added: self.added.into_iter().map(KeyPosition::cloned).collect(),
+            removed: self
+                .removed
+                .into_iter()
+                .map(KeyPosition::cloned)
+                .collect(),
+        }
+    }
+}
+
+impl<K> Invert for OrderDiff<K> {
+    fn invert(self) -> Self {
+        let mut moved: Vec<_> = self
+            .moved
+            .into_iter()
+            .map(|MovedKey { key, before, after }| MovedKey {
+                key,
+                before: after,
+                after: before,
+            })
+            .collect();
+        moved.sort_by_key(|moved| moved.after);
+        // Undo the moves in reverse order.
+        let moves = self
+            .moves
+            .into_iter()
+            .rev()
+            .map(|KeyMove { key, from, to }| KeyMove {
+                key,
+                from: to,
+                to: from,
+            })
+            .collect();
+        Self { moved, moves, added: self.removed, removed: self.added }
+    }
+}
+
+/// A common key whose relative order changed, stored in [`OrderDiff::moved`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MovedKey<K> {
+    /// The key.
+    pub key: K,
+
+    /// The position of the key in the before collection.
+    pub before: usize,
+
+    /// The position of the key in the after collection.
+    pub after: usize,
+}
+
+/// A single move in [`OrderDiff::moves`].
+///
+/// The positions are indexes into the sequence of common keys, as it is
+/// being reordered: a move is applied by removing the key at `from`, then
+/// inserting it at `to`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct KeyMove<K> {
+    /// The key being moved.
+    pub key: K,
+
+    /// The position of the key before this move.
+    pub from: usize,
+
+    /// The position of the key after this move.
+    pub to: usize,
+}
+
+/// A key that was added or removed, stored in [`OrderDiff::added`] and
+/// [`OrderDiff::removed`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct KeyPosition<K> {
+    /// The key.
+    pub key: K,
+
+    /// The position of the key in the collection it is present in.
+    pub index: usize,
+}
+
+impl<K: Clone> KeyPosition<&K> {
+    fn cloned(self) -> KeyPosition<K> {
+        KeyPosition { key: self.key.clone(), index: self.index }
+    }
+}
+
+/// A Fenwick tree (binary indexed tree) of counts, supporting updates and
+/// prefix sums in O(log n) time.
+struct Fenwick {
+    tree: Vec<usize>,
+}
+
+impl Fenwick {
+    fn new(len: usize) -> Self {
+        Self { tree: alloc::vec![0; len + 1] }
+    }
+
+    fn add(&mut self, index: usize, count: usize) {
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            self.tree[i] += count;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    fn sub(&mut self, index: usize, count: usize) {
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            self.tree[i] -= count;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Returns the sum of the counts at indexes less than `index`.
+    fn prefix_sum(&self, index: usize) -> usize {
+        let mut sum = 0;
+        let mut i = index;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+}
+
+/// Returns the indexes of a longest subsequence of `common` whose after
+/// positions are increasing, in O(n log n) time.
+fn longest_increasing_subsequence<K>(
+    common: &[(K, usize, usize)],
+) -> Vec<usize> {
+    // `tails[l]` is the index of the smallest tail of an increasing
+    // subsequence of length `l + 1`, and `prev[i]` is the index of the element
+    // before `i` in the subsequence ending at `i`.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut prev = alloc::vec![None; common.len()];
+    for (i, (_, _, after)) in common.iter().enumerate() {
+        let len = tails.partition_point(|&t| common[t].2 < *after);
+        if len > 0 {
+            prev[i] = Some(tails[len - 1]);
+        }
+        if len == tails.len() {
+            tails.push(i);
+        } else {
+            tails[len] = i;
+        }
+    }
+
+    let mut lis = Vec::with_capacity(tails.len());
+    let mut next = tails.last().copied();
+    while let Some(i) = next {
+        lis.push(i);
+        next = prev[i];
+    }
+    lis.reverse();
+    lis
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Apply the moves to the before order of the common keys.
+    fn apply_moves(mut keys: Vec<char>, moves: &[KeyMove<char>]) -> Vec<char> {
+        for m in moves {
+            assert_eq!(keys[m.from], m.key);
+            let key = keys.remove(m.from);
+            keys.insert(m.to, key);
+        }
+        keys
+    }
+
+    fn order_diff(before: &str, after: &str) -> OrderDiff<char> {
+        OrderDiff::compute(
+            before.chars(),
+            after.chars(),
+            |c| before.find(*c),
+            |c| after.find(*c),
+        )
+    }
+
+    #[test]
+    fn order_diff_moves() {
+        let cases = [
+            ("abcd", "abcd", 0),
+            ("abcd", "dabc", 1),
+            ("abcd", "bcda", 1),
+            ("abcd", "dcba", 3),
+            ("abcdef", "badcfe", 3),
+            // Added and removed keys don't count as moves.
+            ("xabcy", "abzc", 0),
+            ("xabcy", "zcab", 1),
+        ];
+        for (before, after, num_moves) in cases {
+            let diff = order_diff(before, after);
+            assert_eq!(diff.moves.len(), num_moves, "{before} -> {after}");
+            assert_eq!(diff.moved.len(), num_moves, "{before} -> {after}");
+            for moved in &diff.moved {
+                assert_eq!(before.find(moved.key), Some(moved.before));
+                assert_eq!(after.find(moved.key), Some(moved.after));
+            }
+            for added in &diff.added {
+                assert_eq!(after.find(added.key), Some(added.index));
+                assert!(!before.contains(added.key));
+            }
+            for removed in &diff.removed {
+                assert_eq!(before.find(removed.key), Some(removed.index));
+                assert!(!after.contains(removed.key));
+            }
+
+            let common = |s: &str, other: &str| -> Vec<char> {
+                s.chars().filter(|c| other.contains(*c)).collect()
+            };
+            assert_eq!(
+                apply_moves(common(before, after), &diff.moves),
+                common(after, before),
+            );
+            let inverted = diff.invert();
+            assert_eq!(
+                apply_moves(common(after, before), &inverted.moves),
+                common(before, after),
+            );
+            assert!(inverted.moved.is_sorted_by_key(|moved| moved.after));
+            let reverse = order_diff(after, before);
+            assert_eq!(inverted.added, reverse.added);
+            assert_eq!(inverted.removed, reverse.removed);
+        }
+    }
+
+    #[test]
+    fn order_diff_permutations() {
+        // Visit every permutation of `keys` using Heap's algorithm.
+        fn permutations(keys: &mut [char], k: usize, f: &mut impl FnMut(&str)) {
+            if k <= 1 {
+                f(&keys.iter().collect::<alloc::string::String>());
+                return;
+            }
+            for i in 0..k - 1 {
+                permutations(keys, k - 1, f);
+                let j = if k % 2 == 0 { i } else { 0 };
+                keys.swap(j, k - 1);
+            }
+            permutations(keys, k - 1, f);
+        }
+
+        for before in ["abcdef", "xabcdyef"] {
+            permutations(
+                &mut ['a', 'b', 'c', 'd', 'e', 'f', 'z'],
+                7,
+                &mut |after| {
+                    let diff = order_diff(before, after);
+                    let common = |s: &str, other: &str| -> Vec<char> {
+                        s.chars().filter(|c| other.contains(*c)).collect()
+                    };
+                    assert_eq!(
+                        apply_moves(common(before, after), &diff.moves),
+                        common(after, before),
+                        "{before} -> {after}",
+                    );
+                    #[cfg(feature = "indexmap")]
+                    assert_eq!(
+                        diff.after_order(common(before, after)),
+                        Ok(common(after, before)),
+                        "{before} -> {after}",
+                    );
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Converting diffs into owned values.
+
+use crate::Leaf;
+
+/// A diff that can be converted into an owned form, which doesn't borrow from
+/// the values being diffed.
+///
+/// Diffs borrow from the before and after values with the `'daft` lifetime,
+/// so they can't outlive them. Converting a diff into its owned form clones
+/// the values it refers to, which makes it possible to store diffs, send them
+/// to other threads, or keep a history of them.
+///
+/// This trait is implemented for [`Leaf`]s of references, for all of the map
+/// and set diff types in this crate, for [`KeyedDiff`](crate::KeyedDiff), and
+/// for tuples and arrays of diffs. The [`Diffable`](macro@crate::Diffable)
+/// derive macro can generate owned diff types for structs annotated with
+/// `#[daft(owned)]`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use daft::{BTreeMapDiffOwned, Diffable, IntoOwnedDiff, Leaf};
+/// use std::collections::BTreeMap;
+///
+/// let owned: BTreeMapDiffOwned<String, u32> = {
+///     let before: BTreeMap<String, u32> = [("a".to_owned(), 1)].into();
+///     let after: BTreeMap<String, u32> = [("a".to_owned(), 2)].into();
+///     before.diff(&after).into_owned()
+/// };
+///
+/// // The owned diff outlives `before` and `after`.
+/// assert_eq!(owned.common["a"], Leaf { before: 1, after: 2 });
+/// # }
+/// ```
+pub trait IntoOwnedDiff {
+    /// The owned form of this diff.
+    type Owned;
+
+    /// Convert this diff into its owned form.
+    fn into_owned(self) -> Self::Owned;
+}
+
+/// With the `alloc` feature, this is implemented for all types that implement
+/// [`ToOwned`](alloc::borrow::ToOwned), including unsized types like `str`.
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + alloc::borrow::ToOwned> IntoOwnedDiff for Leaf<&T> {
+    type Owned = Leaf<T::Owned>;
+
+    #[inline]
+    fn into_owned(self) -> Self::Owned {
+        Leaf { before: self.before.to_owned(), after: self.after.to_owned() }
+    }
+}
+
+/// Without the `alloc` feature, this is implemented for all types that
+/// implement [`Clone`].
+#[cfg(not(feature = "alloc"))]
+impl<T: Clone> IntoOwnedDiff for Leaf<&T> {
+    type Owned = Leaf<T>;
+
+    #[inline]
+    fn into_owned(self) -> Self::Owned {
+        self.cloned()
+    }
+}
+
+impl<T: Clone> IntoOwnedDiff for Leaf<Option<&T>> {
+    type Owned = Leaf<Option<T>>;
+
+    #[inline]
+    fn into_owned(self) -> Self::Owned {
+        self.map(|value| value.cloned())
+    }
+}
+
+impl<T: Clone, U: Clone> IntoOwnedDiff for Leaf<Result<&T, &U>> {
+    type Owned = Leaf<Result<T, U>>;
+
+    #[inline]
+    fn into_owned(self) -> Self::Owned {
+        self.map(|value| value.cloned().map_err(Clone::clone))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Diffable;
+
+    #[test]
+    fn leaf_and_tuple_into_owned() {
+        let diff = (1_u32, Some('a'), Ok::<_, ()>(2_u8))
+            .diff(&(2, None, Ok(2)))
+            .into_owned();
+        assert_eq!(
+            diff,
+            (
+                Leaf { before: 1, after: 2 },
+                Leaf { before: Some('a'), after: None },
+                Leaf { before: Ok(2), after: Ok(2) },
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn collections_into_owned() {
+        use crate::{BTreeSetDiffOwned, HashMapDiffOwned, KeyedDiff};
+        use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+        let diff = "hello".diff("world").into_owned();
+        assert_eq!(
+            diff,
+            Leaf { before: "hello".to_owned(), after: "world".to_owned() }
+        );
+
+        let before: HashMap<u32, String> = [(1, "a".to_owned())].into();
+        let after: HashMap<u32, String> = [(2, "b".to_owned())].into();
+        let diff: HashMapDiffOwned<u32, String> =
+            before.diff(&after).into_owned();
+        assert_eq!(diff.added, [(2, "b".to_owned())].into());
+        assert_eq!(diff.removed, [(1, "a".to_owned())].into());
+        assert!(diff.common.is_empty());
+
+        let before: BTreeSet<u32> = [1, 2].into();
+        let after: BTreeSet<u32> = [2, 3].into();
+        let diff = before.diff(&after).into_owned();
+        assert_eq!(
+            diff,
+            BTreeSetDiffOwned {
+                common: [2].into(),
+                added: [3].into(),
+                removed: [1].into(),
+            }
+        );
+
+        let before = [(1, 'a'), (2, 'b')];
+        let after = [(2, 'b'), (1, 'c')];
+        let diff = KeyedDiff::new(&before, &after, |(k, _)| k).into_owned();
+        assert_eq!(
+            diff.entries.common,
+            BTreeMap::from([
+                (1, Leaf { before: (1, 'a'), after: (1, 'c') }),
+                (2, Leaf { before: (2, 'b'), after: (2, 'b') }),
+            ])
+        );
+        assert_eq!(diff.positions[&1], Leaf { before: 0, after: 1 });
+        assert_eq!(diff.moved, [1].into());
+    }
+}
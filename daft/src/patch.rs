@@ -0,0 +1,201 @@
+//! Applying diffs to values.
+
+use crate::Leaf;
+use core::fmt;
+
+/// A diff that can be applied to a copy of its before value to reconstruct the
+/// after value.
+///
+/// `T` is the type of the value being patched. For example, a
+/// [`BTreeMapDiff<'_, K, V>`](crate::BTreeMapDiff) implements
+/// `Patch<BTreeMap<K, V>>`.
+///
+/// Applying a diff checks that the target matches the before side of the diff,
+/// and returns a [`PatchError`] if it doesn't. Values are compared with
+/// `PartialEq`, and copied out of the diff with `Clone`.
+///
+/// This trait is implemented for [`Leaf`]s, for all of the map and set diff
+/// types in this crate, for tuples and arrays of diffs, and for the owned forms
+/// of all of these. The [`Diffable`](macro@crate::Diffable) derive macro implements it
+/// for generated diff structs, by applying the diff of each field that isn't
+/// ignored. Ignored fields are left unchanged.
+///
+/// For `IndexMap` and `IndexSet`, the common entries of the target are moved
+/// into their order in the after collection, and added entries are inserted
+/// at their positions there, as recorded in the diff's
+/// [`OrderDiff`](crate::OrderDiff). Patching the before collection therefore
+/// results in the same order as the after collection.
+///
+/// If an error is returned, the target may have been partially patched.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use daft::{Diffable, IntoOwnedDiff, Patch, PatchError};
+/// use std::collections::BTreeMap;
+///
+/// let before: BTreeMap<&str, u32> = [("a", 1), ("b", 2)].into();
+/// let after: BTreeMap<&str, u32> = [("a", 1), ("b", 3), ("c", 4)].into();
+/// let diff = before.diff(&after).into_owned();
+///
+/// // Applying the diff to a copy of `before` produces `after`.
+/// let mut target = before.clone();
+/// diff.apply(&mut target).unwrap();
+/// assert_eq!(target, after);
+///
+/// // The diff can't be applied again, since `target` no longer matches the
+/// // before side of the diff.
+/// assert_eq!(diff.apply(&mut target), Err(PatchError::Mismatch));
+/// # }
+/// ```
+pub trait Patch<T: ?Sized> {
+    /// Apply this diff to `target`, which must be equal to the before side of
+    /// the diff.
+    fn apply(&self, target: &mut T) -> Result<(), PatchError>;
+}
+
+impl<T: Clone + PartialEq> Patch<T> for Leaf<&T> {
+    #[inline]
+    fn apply(&self, target: &mut T) -> Result<(), PatchError> {
+        if target != self.before {
+            return Err(PatchError::Mismatch);
+        }
+        target.clone_from(self.after);
+        Ok(())
+    }
+}
+
+impl<T: Clone + PartialEq> Patch<T> for Leaf<T> {
+    #[inline]
+    fn apply(&self, target: &mut T) -> Result<(), PatchError> {
+        self.as_ref().apply(target)
+    }
+}
+
+impl<T: Clone + PartialEq> Patch<Option<T>> for Leaf<Option<&T>> {
+    #[inline]
+    fn apply(&self, target: &mut Option<T>) -> Result<(), PatchError> {
+        if target.as_ref() != self.before {
+            return Err(PatchError::Mismatch);
+        }
+        *target = self.after.cloned();
+        Ok(())
+    }
+}
+
+impl<T: Clone + PartialEq, U: Clone + PartialEq> Patch<Result<T, U>>
+    for Leaf<Result<&T, &U>>
+{
+    #[inline]
+    fn apply(&self, target: &mut Result<T, U>) -> Result<(), PatchError> {
+        if target.as_ref() != self.before {
+            return Err(PatchError::Mismatch);
+        }
+        *target = self.after.cloned().map_err(Clone::clone);
+        Ok(())
+    }
+}
+
+/// An error returned by [`Patch::apply`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PatchError {
+    /// A value in the target didn't match the before side of the diff.
+    Mismatch,
+
+    /// An entry that was removed or modified in the diff wasn't present in
+    /// the target.
+    MissingEntry,
+
+    /// An entry that was added in the diff was already present in the target.
+    ExistingEntry,
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mismatch => {
+                write!(f, "target value doesn't match the before value")
+            }
+            Self::MissingEntry => {
+                write!(f, "entry not present in the target")
+            }
+            Self::ExistingEntry => {
+                write!(f, "added entry already present in the target")
+            }
+        }
+    }
+}
+
+impl core::error::Error for PatchError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Diffable, IntoOwnedDiff};
+
+    #[test]
+    fn leaf_and_tuple_patch() {
+        let before = (1_u32, Some('a'), Ok::<_, ()>(2_u8));
+        let after = (2, None, Ok(3));
+        let diff = before.diff(&after);
+
+        let mut target = before;
+        diff.apply(&mut target).unwrap();
+        assert_eq!(target, after);
+
+        // The target now matches the after side, not the before side.
+        assert_eq!(diff.apply(&mut target), Err(PatchError::Mismatch));
+
+        let mut target = before;
+        diff.into_owned().apply(&mut target).unwrap();
+        assert_eq!(target, after);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn collections_patch() {
+        use std::collections::{BTreeSet, HashMap};
+
+        let before = "hello".to_owned();
+        let after = "world".to_owned();
+        let mut target = before.clone();
+        before.diff(&after).apply(&mut target).unwrap();
+        assert_eq!(target, after);
+
+        let before = vec![1, 2];
+        let after = vec![3];
+        let mut target = before.clone();
+        before.diff(&after).into_owned().apply(&mut target).unwrap();
+        assert_eq!(target, after);
+
+        let before: HashMap<u32, &str> = [(1, "a"), (2, "b")].into();
+        let after: HashMap<u32, &str> = [(2, "c"), (3, "d")].into();
+        let diff = before.diff(&after);
+        let mut target = before.clone();
+        diff.apply(&mut target).unwrap();
+        assert_eq!(target, after);
+
+        // Entries that aren't in the diff are left alone.
+        let mut target = before.clone();
+        target.insert(4, "e");
+        diff.apply(&mut target).unwrap();
+        assert_eq!(target, [(2, "c"), (3, "d"), (4, "e")].into());
+
+        let mut target = before.clone();
+        target.remove(&1);
+        assert_eq!(diff.apply(&mut target), Err(PatchError::MissingEntry));
+        let mut target = before.clone();
+        target.insert(2, "x");
+        assert_eq!(diff.apply(&mut target), Err(PatchError::Mismatch));
+
+        let before: BTreeSet<u32> = [1, 2].into();
+        let after: BTreeSet<u32> = [2, 3].into();
+        let diff = before.diff(&after).into_owned();
+        let mut target = before.clone();
+        diff.apply(&mut target).unwrap();
+        assert_eq!(target, after);
+        assert_eq!(diff.apply(&mut target), Err(PatchError::MissingEntry));
+    }
+}
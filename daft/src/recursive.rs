@@ -0,0 +1,62 @@
+//! Map diffs with values diffed recursively.
+
+use crate::Diffable;
+
+/// A map whose common values can be diffed recursively, with the diffs stored
+/// eagerly.
+///
+/// The [`Diffable`] implementations for maps store common values as
+/// [`Leaf`](crate::Leaf)s, which is cheap, but means that nested diffs have to
+/// be recomputed each time they're needed, e.g. with
+/// [`BTreeMapDiff::modified_diff`](crate::BTreeMapDiff::modified_diff).
+/// `recursive_diff` instead returns a diff, such as a
+/// [`BTreeMapRecursiveDiff`](crate::BTreeMapRecursiveDiff), whose `common`
+/// field stores `V::Diff` for each entry present in both maps.
+///
+/// This trait is implemented for [`BTreeMap`](alloc::collections::BTreeMap),
+/// as well as `HashMap` and `IndexMap` with the corresponding features. The
+/// [`Diffable`](macro@crate::Diffable) derive macro uses it for fields
+/// annotated with `#[daft(map_values = "recursive")]`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "std", feature = "derive"))] {
+/// use daft::{Diffable, DiffableMap, Leaf};
+/// use std::collections::BTreeMap;
+///
+/// #[derive(Diffable)]
+/// struct Sled {
+///     serial: String,
+///     generation: u64,
+/// }
+///
+/// let before: BTreeMap<u32, Sled> = [
+///     (1, Sled { serial: "a".to_owned(), generation: 1 }),
+///     (2, Sled { serial: "b".to_owned(), generation: 1 }),
+/// ]
+/// .into();
+/// let after: BTreeMap<u32, Sled> = [
+///     (1, Sled { serial: "a".to_owned(), generation: 2 }),
+///     (2, Sled { serial: "b".to_owned(), generation: 1 }),
+/// ]
+/// .into();
+///
+/// let diff = before.recursive_diff(&after);
+/// assert_eq!(diff.common[&1].generation, Leaf { before: &1, after: &2 });
+/// assert_eq!(diff.modified_keys().collect::<Vec<_>>(), [&1]);
+/// # }
+/// ```
+pub trait DiffableMap: Diffable {
+    /// The type of the recursive diff.
+    type RecursiveDiff<'daft>
+    where
+        Self: 'daft;
+
+    /// Compute the diff between two maps, diffing the values of entries
+    /// present in both.
+    fn recursive_diff<'daft>(
+        &'daft self,
+        other: &'daft Self,
+    ) -> Self::RecursiveDiff<'daft>;
+}
@@ -0,0 +1,554 @@
+//! Human-readable rendering of diffs.
+
+use crate::{DiffPath, DiffVisitor, PathSegment, VisitDiff};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+/// Renders a diff as an indented tree of the parts that changed.
+///
+/// `DiffRenderer` works with any diff that implements [`VisitDiff`]. Unlike
+/// the `Debug` output for a diff, the rendered output only includes changes:
+///
+/// - Modified values are shown as `before -> after`.
+/// - Entries removed from maps and sets are shown with a `-` marker.
+/// - Entries added to maps and sets are shown with a `+` marker.
+/// - Entries that moved within ordered maps and sets are shown with a `~`
+///   marker, along with their before and after positions.
+///
+/// Values are formatted with their `Debug` implementations.
+///
+/// With the `color` feature, output can be styled with ANSI colors: see
+/// [`DiffRenderer::color`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "std", feature = "derive"))] {
+/// use daft::{DiffRenderer, Diffable};
+/// use std::collections::{BTreeMap, BTreeSet};
+///
+/// #[derive(Debug, Eq, PartialEq, Diffable)]
+/// struct Zpool {
+///     size: u64,
+/// }
+///
+/// #[derive(Diffable)]
+/// struct Sled {
+///     name: &'static str,
+///     zpools: BTreeMap<&'static str, Zpool>,
+///     tags: BTreeSet<&'static str>,
+/// }
+///
+/// let before = Sled {
+///     name: "sled0",
+///     zpools: [("abc", Zpool { size: 10 }), ("def", Zpool { size: 30 })]
+///         .into_iter()
+///         .collect(),
+///     tags: ["a"].into_iter().collect(),
+/// };
+/// let after = Sled {
+///     name: "sled0",
+///     zpools: [("abc", Zpool { size: 20 }), ("ghi", Zpool { size: 40 })]
+///         .into_iter()
+///         .collect(),
+///     tags: ["b"].into_iter().collect(),
+/// };
+///
+/// let diff = before.diff(&after);
+/// let rendered = DiffRenderer::new().render(&diff).to_string();
+/// assert_eq!(
+///     rendered,
+///     r#"zpools:
+///   - "def": Zpool { size: 30 }
+///   "abc":
+///     size: 10 -> 20
+///   + "ghi": Zpool { size: 40 }
+/// tags:
+///   - "a"
+///   + "b"
+/// "#,
+/// );
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DiffRenderer {
+    indent: usize,
+    #[cfg(feature = "color")]
+    color: ColorChoice,
+}
+
+impl DiffRenderer {
+    /// The default number of spaces per level of indentation.
+    pub const DEFAULT_INDENT: usize = 2;
+
+    /// Create a new renderer with the default options.
+    pub fn new() -> Self {
+        Self {
+            indent: Self::DEFAULT_INDENT,
+            #[cfg(feature = "color")]
+            color: ColorChoice::Auto,
+        }
+    }
+
+    /// Set the number of spaces used for each level of indentation.
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Set whether to style output with ANSI colors.
+    ///
+    /// Removed entries are shown in red, added entries in green, modified
+    /// values in yellow, and moved entries in cyan. The default is
+    /// [`ColorChoice::Auto`], which uses colors if standard output is a
+    /// terminal.
+    ///
+    /// This requires the `color` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use daft::{ColorChoice, DiffRenderer, Diffable};
+    ///
+    /// let diff = 1_u32.diff(&2);
+    /// let rendered = DiffRenderer::new()
+    ///     .color(ColorChoice::Always)
+    ///     .render(&diff)
+    ///     .to_string();
+    /// assert_eq!(rendered, "\x1b[33m1 -> 2\x1b[0m\n");
+    /// ```
+    #[cfg(feature = "color")]
+    pub fn color(mut self, color: ColorChoice) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Return a value that renders `diff` when displayed.
+    ///
+    /// Each change is written on its own line, and every line ends with a
+    /// newline. If nothing changed, nothing is written.
+    ///
+    /// With the `color` feature and [`ColorChoice::Auto`], whether to use
+    /// colors is determined when this method is called.
+    pub fn render<'a, D: VisitDiff + ?Sized>(
+        &'a self,
+        diff: &'a D,
+    ) -> RenderedDiff<'a, D> {
+        #[cfg(feature = "color")]
+        let color = self.color.should_colorize();
+        #[cfg(not(feature = "color"))]
+        let color = false;
+
+        RenderedDiff { renderer: self, diff, color }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+impl DiffRenderer {
+    /// Create a renderer that never uses colors, so test output doesn't
+    /// depend on whether standard output is a terminal.
+    pub(crate) fn plain() -> Self {
+        let renderer = Self::new();
+        #[cfg(feature = "color")]
+        let renderer = renderer.color(ColorChoice::Never);
+        renderer
+    }
+}
+
+impl Default for DiffRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A diff rendered by a [`DiffRenderer`].
+///
+/// This is returned by [`DiffRenderer::render`], and implements
+/// [`Display`](fmt::Display).
+pub struct RenderedDiff<'a, D: ?Sized> {
+    renderer: &'a DiffRenderer,
+    diff: &'a D,
+    color: bool,
+}
+
+impl<D: VisitDiff + ?Sized> fmt::Display for RenderedDiff<'_, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut visitor = RenderVisitor {
+            f,
+            indent: self.renderer.indent,
+            color: self.color,
+            headers: Vec::new(),
+            result: Ok(()),
+        };
+        self.diff.visit(&mut visitor);
+        visitor.result
+    }
+}
+
+impl<D: ?Sized> fmt::Debug for RenderedDiff<'_, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RenderedDiff")
+            .field("renderer", &self.renderer)
+            .field("color", &self.color)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The kind of change on a line, which determines its marker and style.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Change {
+    Removed,
+    Added,
+    Modified,
+    Moved,
+}
+
+impl Change {
+    fn marker(self) -> Option<&'static str> {
+        match self {
+            Self::Removed => Some("-"),
+            Self::Added => Some("+"),
+            Self::Modified => None,
+            Self::Moved => Some("~"),
+        }
+    }
+
+    /// The ANSI escape sequence used to style this kind of change.
+    fn ansi_style(self) -> &'static str {
+        match self {
+            // Red
+            Self::Removed => "\x1b[31m",
+            // Green
+            Self::Added => "\x1b[32m",
+            // Yellow
+            Self::Modified => "\x1b[33m",
+            // Cyan
+            Self::Moved => "\x1b[36m",
+        }
+    }
+}
+
+const ANSI_RESET: &str = "\x1b[0m";
+
+struct RenderVisitor<'a, 'f> {
+    f: &'a mut fmt::Formatter<'f>,
+    indent: usize,
+    // Whether to style changes with ANSI colors.
+    color: bool,
+    // The labels of the headers written so far, from the root to the current
+    // position in the tree.
+    headers: Vec<String>,
+    // The first error encountered while writing, if any. Visitors can't
+    // return errors, so further output is skipped after an error.
+    result: fmt::Result,
+}
+
+impl RenderVisitor<'_, '_> {
+    /// Write the headers for `labels`, skipping ones already written.
+    fn write_headers(&mut self, labels: Vec<String>) -> fmt::Result {
+        let common = self
+            .headers
+            .iter()
+            .zip(&labels)
+            .take_while(|(a, b)| a == b)
+            .count();
+        self.headers.truncate(common);
+
+        for label in labels.into_iter().skip(common) {
+            self.write_indent(self.headers.len())?;
+            writeln!(self.f, "{label}:")?;
+            self.headers.push(label);
+        }
+        Ok(())
+    }
+
+    fn write_indent(&mut self, depth: usize) -> fmt::Result {
+        write!(self.f, "{:width$}", "", width = depth * self.indent)
+    }
+
+    /// Write a line for a change at `depth`, styled if colors are enabled.
+    fn write_line(
+        &mut self,
+        depth: usize,
+        change: Change,
+        args: fmt::Arguments<'_>,
+    ) -> fmt::Result {
+        self.write_indent(depth)?;
+        if self.color {
+            writeln!(self.f, "{}{args}{ANSI_RESET}", change.ansi_style())
+        } else {
+            writeln!(self.f, "{args}")
+        }
+    }
+
+    fn write_entry(
+        &mut self,
+        path: &DiffPath<'_>,
+        change: Change,
+        value: &dyn fmt::Debug,
+    ) -> fmt::Result {
+        let mut labels = labels(path);
+        // Map entries and sequence elements are shown with their key or index
+        // on the marker line. Other values, such as set entries, are shown
+        // below the path they were found at.
+        let label = match path.last() {
+            Some(PathSegment::Key(_) | PathSegment::Index(_)) => labels.pop(),
+            _ => None,
+        };
+
+        self.write_headers(labels)?;
+        let depth = self.headers.len();
+        let marker = change.marker().unwrap_or_default();
+        match label {
+            Some(label) => self.write_line(
+                depth,
+                change,
+                format_args!("{marker} {label}: {value:?}"),
+            ),
+            None => self.write_line(
+                depth,
+                change,
+                format_args!("{marker} {value:?}"),
+            ),
+        }
+    }
+
+    fn write_modified(
+        &mut self,
+        path: &DiffPath<'_>,
+        before: &dyn fmt::Debug,
+        after: &dyn fmt::Debug,
+    ) -> fmt::Result {
+        let mut labels = labels(path);
+        let label = labels.pop();
+
+        self.write_headers(labels)?;
+        let depth = self.headers.len();
+        match label {
+            Some(label) => self.write_line(
+                depth,
+                Change::Modified,
+                format_args!("{label}: {before:?} -> {after:?}"),
+            ),
+            None => self.write_line(
+                depth,
+                Change::Modified,
+                format_args!("{before:?} -> {after:?}"),
+            ),
+        }
+    }
+
+    fn write_moved(
+        &mut self,
+        path: &DiffPath<'_>,
+        before: usize,
+        after: usize,
+    ) -> fmt::Result {
+        let mut labels = labels(path);
+        let label = labels.pop().unwrap_or_default();
+
+        self.write_headers(labels)?;
+        let depth = self.headers.len();
+        self.write_line(
+            depth,
+            Change::Moved,
+            format_args!("~ {label}: position {before} -> {after}"),
+        )
+    }
+}
+
+impl DiffVisitor for RenderVisitor<'_, '_> {
+    fn added(&mut self, path: &DiffPath<'_>, value: &dyn fmt::Debug) {
+        if self.result.is_ok() {
+            self.result = self.write_entry(path, Change::Added, value);
+        }
+    }
+
+    fn removed(&mut self, path: &DiffPath<'_>, value: &dyn fmt::Debug) {
+        if self.result.is_ok() {
+            self.result = self.write_entry(path, Change::Removed, value);
+        }
+    }
+
+    fn modified(
+        &mut self,
+        path: &DiffPath<'_>,
+        before: &dyn fmt::Debug,
+        after: &dyn fmt::Debug,
+    ) {
+        if self.result.is_ok() {
+            self.result = self.write_modified(path, before, after);
+        }
+    }
+
+    fn moved(&mut self, path: &DiffPath<'_>, before: usize, after: usize) {
+        if self.result.is_ok() {
+            self.result = self.write_moved(path, before, after);
+        }
+    }
+}
+
+/// Whether to style rendered output with ANSI colors.
+///
+/// This requires the `color` feature. See [`DiffRenderer::color`].
+#[cfg(feature = "color")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Use colors if standard output is a terminal, and the `NO_COLOR`
+    /// environment variable is not set to a non-empty value.
+    #[default]
+    Auto,
+
+    /// Always use colors.
+    Always,
+
+    /// Never use colors.
+    Never,
+}
+
+#[cfg(feature = "color")]
+impl ColorChoice {
+    /// Return true if output should be colorized.
+    ///
+    /// For [`ColorChoice::Auto`], this checks the environment as described
+    /// above.
+    pub fn should_colorize(self) -> bool {
+        use std::io::IsTerminal;
+
+        match self {
+            Self::Auto => auto_colorize(
+                std::env::var_os("NO_COLOR"),
+                std::io::stdout().is_terminal(),
+            ),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+/// Return whether [`ColorChoice::Auto`] uses colors, given the value of the
+/// `NO_COLOR` environment variable and whether standard output is a terminal.
+#[cfg(feature = "color")]
+fn auto_colorize(
+    no_color: Option<std::ffi::OsString>,
+    is_terminal: bool,
+) -> bool {
+    // See https://no-color.org/.
+    let no_color = no_color.is_some_and(|value| !value.is_empty());
+    !no_color && is_terminal
+}
+
+/// Return the label for each segment of `path`, as shown in the tree.
+fn labels(path: &DiffPath<'_>) -> Vec<String> {
+    path.segments()
+        .map(|segment| match segment {
+            PathSegment::Field(name) | PathSegment::Variant(name) => {
+                String::from(name)
+            }
+            PathSegment::TupleIndex(ix) => format!("{ix}"),
+            PathSegment::Index(ix) => format!("[{ix}]"),
+            PathSegment::Key(key) => format!("{key:?}"),
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Diffable, SeqDiff};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn render_nested() {
+        let before: BTreeMap<&str, BTreeMap<u32, (u32, char)>> = [
+            ("a", [(1, (1, 'x')), (2, (2, 'y'))].into_iter().collect()),
+            ("b", [(1, (1, 'x'))].into_iter().collect()),
+        ]
+        .into_iter()
+        .collect();
+        let after: BTreeMap<&str, BTreeMap<u32, (u32, char)>> = [
+            ("a", [(1, (1, 'z')), (2, (3, 'w'))].into_iter().collect()),
+            ("b", [(1, (1, 'x'))].into_iter().collect()),
+        ]
+        .into_iter()
+        .collect();
+
+        let diff = before.diff(&after);
+        assert_eq!(
+            DiffRenderer::plain().indent(4).render(&diff).to_string(),
+            r#""a":
+    1:
+        1: 'x' -> 'z'
+    2:
+        0: 2 -> 3
+        1: 'y' -> 'w'
+"#,
+        );
+
+        // Nothing is rendered for an unchanged diff.
+        let diff = before.diff(&before);
+        assert_eq!(DiffRenderer::plain().render(&diff).to_string(), "");
+    }
+
+    #[test]
+    fn render_root() {
+        let diff = 1_u32.diff(&2);
+        assert_eq!(DiffRenderer::plain().render(&diff).to_string(), "1 -> 2\n");
+
+        let diff = SeqDiff::new(&['a', 'b'], &['b', 'c']);
+        assert_eq!(
+            DiffRenderer::plain().render(&diff).to_string(),
+            "- [0]: 'a'\n+ [1]: 'c'\n",
+        );
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn render_color() {
+        let before: BTreeMap<&str, u32> = [("a", 1), ("b", 2)].into();
+        let after: BTreeMap<&str, u32> = [("a", 3), ("c", 4)].into();
+        let diff = before.diff(&after);
+
+        let renderer = DiffRenderer::plain().color(ColorChoice::Always);
+        assert_eq!(
+            renderer.render(&diff).to_string(),
+            "\x1b[31m- \"b\": 2\x1b[0m\n\
+             \x1b[33m\"a\": 1 -> 3\x1b[0m\n\
+             \x1b[32m+ \"c\": 4\x1b[0m\n",
+        );
+
+        let renderer = renderer.color(ColorChoice::Never);
+        assert_eq!(
+            renderer.render(&diff).to_string(),
+            "- \"b\": 2\n\"a\": 1 -> 3\n+ \"c\": 4\n",
+        );
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn render_color_auto() {
+        use std::io::IsTerminal;
+
+        assert_eq!(ColorChoice::default(), ColorChoice::Auto);
+        assert!(auto_colorize(None, true));
+        assert!(auto_colorize(Some("".into()), true));
+        assert!(!auto_colorize(Some("1".into()), true));
+        assert!(!auto_colorize(None, false));
+
+        // SAFETY: no other test reads or writes NO_COLOR, and tests that
+        // render output use `ColorChoice::Never`.
+        unsafe { std::env::set_var("NO_COLOR", "1") };
+        assert!(!ColorChoice::Auto.should_colorize());
+        unsafe { std::env::remove_var("NO_COLOR") };
+
+        // Output isn't styled if standard output isn't a terminal, as is
+        // typically the case in CI.
+        if !std::io::stdout().is_terminal() {
+            let diff = 1_u32.diff(&2);
+            assert_eq!(
+                DiffRenderer::new().render(&diff).to_string(),
+                "1 -> 2\n"
+            );
+        }
+    }
+}
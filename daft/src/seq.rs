@@ -0,0 +1,731 @@
+//! Ordered sequence diffs.
+
+use crate::{
+    DiffPath, DiffSummary, DiffVisitor, Invert, Leaf, PathSegment, VisitDiff,
+};
+use alloc::{
+    collections::{LinkedList, VecDeque},
+    vec,
+    vec::Vec,
+};
+use core::{fmt, ops::Range};
+
+/// An ordered diff of two sequences, expressed as an edit script.
+///
+/// Vectors and slices are diffed as [`Leaf`] nodes by default, since there
+/// are several reasonable ways to diff them. `SeqDiff` is the opt-in ordered
+/// diff: it finds a longest common subsequence of the two sides (using
+/// [Myers' algorithm]), and describes the differences as a list of
+/// [`SeqEdit`] runs.
+///
+/// Each edit carries index ranges into both the `before` and `after`
+/// sequences. Taken together, the `before` ranges of all edits cover the
+/// entire `before` sequence in order, and likewise for `after`.
+///
+/// A `SeqDiff` can be created with [`SeqDiff::new`], or from the [`Leaf`]
+/// produced by diffing a `Vec` or slice with [`Leaf::seq_diff`].
+///
+/// [Myers' algorithm]: http://www.xmailserver.org/diff2.pdf
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use daft::{Diffable, SeqEdit, SeqEditKind};
+///
+/// let before = vec!["a", "b", "c", "d"];
+/// let after = vec!["a", "x", "c", "d", "e"];
+///
+/// let diff = before.diff(&after).seq_diff();
+/// assert_eq!(
+///     diff.edits,
+///     [
+///         SeqEdit { kind: SeqEditKind::Equal, before: 0..1, after: 0..1 },
+///         SeqEdit { kind: SeqEditKind::Replace, before: 1..2, after: 1..2 },
+///         SeqEdit { kind: SeqEditKind::Equal, before: 2..4, after: 2..4 },
+///         SeqEdit { kind: SeqEditKind::Insert, before: 4..4, after: 4..5 },
+///     ],
+/// );
+///
+/// // The elements covered by an edit can be retrieved from the diff.
+/// let replaced = &diff.edits[1];
+/// assert_eq!(diff.before_items(replaced), [&"b"]);
+/// assert_eq!(diff.after_items(replaced), [&"x"]);
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SeqDiff<'daft, T: ?Sized> {
+    /// The elements of the before sequence.
+    pub before: Vec<&'daft T>,
+
+    /// The elements of the after sequence.
+    pub after: Vec<&'daft T>,
+
+    /// The edit script, in order.
+    pub edits: Vec<SeqEdit>,
+}
+
+// Implemented by hand, since the elements are references and don't need to be
+// `Clone` themselves.
+impl<T: ?Sized> Clone for SeqDiff<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            before: self.before.clone(),
+            after: self.after.clone(),
+            edits: self.edits.clone(),
+        }
+    }
+}
+
+impl<'daft, T: ?Sized + Eq> SeqDiff<'daft, T> {
+    /// Compute an ordered diff between two sequences.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use daft::{SeqDiff, SeqEditKind};
+    /// use std::collections::VecDeque;
+    ///
+    /// // Any iterator over references can be diffed.
+    /// let before: VecDeque<u32> = [1, 2, 3].into_iter().collect();
+    /// let after: VecDeque<u32> = [1, 3].into_iter().collect();
+    ///
+    /// let diff = SeqDiff::new(&before, &after);
+    /// let kinds = diff.edits.iter().map(|edit| edit.kind).collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     kinds,
+    ///     [SeqEditKind::Equal, SeqEditKind::Delete, SeqEditKind::Equal],
+    /// );
+    /// # }
+    /// ```
+    pub fn new<B, A>(before: B, after: A) -> Self
+    where
+        B: IntoIterator<Item = &'daft T>,
+        A: IntoIterator<Item = &'daft T>,
+    {
+        let before: Vec<_> = before.into_iter().collect();
+        let after: Vec<_> = after.into_iter().collect();
+        let edits = compute_edits(before.len(), after.len(), |i, j| {
+            before[i] == after[j]
+        });
+        Self { before, after, edits }
+    }
+}
+
+impl<'daft, T: ?Sized> SeqDiff<'daft, T> {
+    /// Return the elements on the before side covered by `edit`.
+    ///
+    /// For [`SeqEditKind::Insert`] edits, this is always empty.
+    pub fn before_items(&self, edit: &SeqEdit) -> &[&'daft T] {
+        &self.before[edit.before.clone()]
+    }
+
+    /// Return the elements on the after side covered by `edit`.
+    ///
+    /// For [`SeqEditKind::Delete`] edits, this is always empty.
+    pub fn after_items(&self, edit: &SeqEdit) -> &[&'daft T] {
+        &self.after[edit.after.clone()]
+    }
+
+    /// Return an iterator over edits that aren't [`SeqEditKind::Equal`].
+    pub fn changes(&self) -> impl Iterator<Item = &SeqEdit> + '_ {
+        self.edits.iter().filter(|edit| edit.kind != SeqEditKind::Equal)
+    }
+}
+
+impl<T: ?Sized> DiffSummary for SeqDiff<'_, T> {
+    fn is_unchanged(&self) -> bool {
+        self.changes().next().is_none()
+    }
+}
+
+impl<T: ?Sized> Invert for SeqDiff<'_, T> {
+    fn invert(self) -> Self {
+        Self {
+            before: self.after,
+            after: self.before,
+            edits: self.edits.into_iter().map(SeqEdit::invert).collect(),
+        }
+    }
+}
+
+/// Removed and added elements are reported with a [`PathSegment::Index`] into
+/// the before and after sequences respectively. Replaced elements are reported
+/// as removals followed by additions.
+impl<T: fmt::Debug + ?Sized> VisitDiff for SeqDiff<'_, T> {
+    fn visit_at(&self, path: &DiffPath<'_>, visitor: &mut dyn DiffVisitor) {
+        for edit in self.changes() {
+            for ix in edit.before.clone() {
+                visitor.removed(
+                    &path.join(PathSegment::Index(ix)),
+                    &self.before[ix],
+                );
+            }
+            for ix in edit.after.clone() {
+                visitor
+                    .added(&path.join(PathSegment::Index(ix)), &self.after[ix]);
+            }
+        }
+    }
+}
+
+impl<'daft, T: Eq> Leaf<&'daft [T]> {
+    /// Perform an ordered diff of [`before`][Self::before] and
+    /// [`after`][Self::after], returning a [`SeqDiff`].
+    ///
+    /// The [`Diffable`](crate::Diffable) implementations for `Vec<T>` and
+    /// `[T]` return a `Leaf<&[T]>`; this method is the opt-in way to get an
+    /// edit script out of that leaf.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use daft::{Diffable, SeqEditKind};
+    ///
+    /// let before = vec![1, 2, 3];
+    /// let after = vec![1, 2, 3, 4];
+    ///
+    /// let diff = before.diff(&after).seq_diff();
+    /// let insert = diff.changes().next().unwrap();
+    /// assert_eq!(insert.kind, SeqEditKind::Insert);
+    /// assert_eq!(diff.after_items(insert), [&4]);
+    /// # }
+    /// ```
+    pub fn seq_diff(self) -> SeqDiff<'daft, T> {
+        SeqDiff::new(self.before, self.after)
+    }
+}
+
+impl<'daft, T: Eq> Leaf<&'daft VecDeque<T>> {
+    /// Perform an ordered diff of [`before`][Self::before] and
+    /// [`after`][Self::after], returning a [`SeqDiff`].
+    ///
+    /// This is the same as [`Leaf::<&[T]>::seq_diff`](Leaf::seq_diff), for
+    /// the `Leaf` returned by the [`Diffable`](crate::Diffable)
+    /// implementation for `VecDeque<T>`.
+    pub fn seq_diff(self) -> SeqDiff<'daft, T> {
+        SeqDiff::new(self.before, self.after)
+    }
+}
+
+impl<'daft, T: Eq> Leaf<&'daft LinkedList<T>> {
+    /// Perform an ordered diff of [`before`][Self::before] and
+    /// [`after`][Self::after], returning a [`SeqDiff`].
+    ///
+    /// This is the same as [`Leaf::<&[T]>::seq_diff`](Leaf::seq_diff), for
+    /// the `Leaf` returned by the [`Diffable`](crate::Diffable)
+    /// implementation for `LinkedList<T>`.
+    pub fn seq_diff(self) -> SeqDiff<'daft, T> {
+        SeqDiff::new(self.before, self.after)
+    }
+}
+
+/// A run of elements within a [`SeqDiff`].
+///
+/// The `before` and `after` fields are index ranges into the before and after
+/// sequences respectively. For insertions, `before` is the empty range at
+/// which elements were inserted; for deletions, `after` is the empty range at
+/// which elements were removed.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SeqEdit {
+    /// The kind of edit.
+    pub kind: SeqEditKind,
+
+    /// The range of indexes on the before side.
+    pub before: Range<usize>,
+
+    /// The range of indexes on the after side.
+    pub after: Range<usize>,
+}
+
+impl SeqEdit {
+    /// Invert this edit, swapping its before and after sides.
+    pub(crate) fn invert(self) -> Self {
+        let kind = match self.kind {
+            SeqEditKind::Insert => SeqEditKind::Delete,
+            SeqEditKind::Delete => SeqEditKind::Insert,
+            kind @ (SeqEditKind::Equal | SeqEditKind::Replace) => kind,
+        };
+        Self { kind, before: self.after, after: self.before }
+    }
+}
+
+/// The kind of a [`SeqEdit`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SeqEditKind {
+    /// The elements are the same on both sides.
+    Equal,
+
+    /// The elements are present on the after side, but not before.
+    Insert,
+
+    /// The elements are present on the before side, but not after.
+    Delete,
+
+    /// Elements on the before side were replaced with elements on the after
+    /// side.
+    Replace,
+}
+
+/// Compute a list of edits between two sequences of length `n` and `m`.
+///
+/// `eq(i, j)` returns true if element `i` of the before sequence is equal to
+/// element `j` of the after sequence.
+pub(crate) fn compute_edits<F>(n: usize, m: usize, eq: F) -> Vec<SeqEdit>
+where
+    F: Fn(usize, usize) -> bool,
+{
+    let mut matches = Vec::new();
+    Myers::new(n, m).conquer(&eq, 0..n, 0..m, &mut matches);
+    edits_from_matches(n, m, &matches)
+}
+
+/// State for the linear-space variant of Myers' O(ND) algorithm.
+///
+/// Rather than keeping the furthest-reaching paths for every edit distance
+/// (which takes O(D²) memory), this searches forwards from the start and
+/// backwards from the end at the same time until the two searches overlap.
+/// That finds the "middle snake" of an optimal path, and the algorithm then
+/// recurses on either side of it (section 4b of the paper).
+struct Myers {
+    /// The furthest x reached on each diagonal by the forward search.
+    forward: Diagonals,
+    /// The furthest x reached on each diagonal by the backward search,
+    /// counting from the ends of both sequences.
+    backward: Diagonals,
+}
+
+impl Myers {
+    fn new(n: usize, m: usize) -> Self {
+        // The searches overlap after at most ceil((n + m) / 2) steps, and
+        // step d reads diagonals -(d + 1)..=(d + 1).
+        let max = (n + m).div_ceil(2) + 1;
+        Self { forward: Diagonals::new(max), backward: Diagonals::new(max) }
+    }
+
+    /// Find the matching pairs of a longest common subsequence of `before`
+    /// and `after`, pushing them onto `matches` in increasing order.
+    fn conquer<F>(
+        &mut self,
+        eq: &F,
+        mut before: Range<usize>,
+        mut after: Range<usize>,
+        matches: &mut Vec<(usize, usize)>,
+    ) where
+        F: Fn(usize, usize) -> bool,
+    {
+        // The common prefix and suffix are part of every longest common
+        // subsequence, and trimming them is cheap.
+        while !before.is_empty()
+            && !after.is_empty()
+            && eq(before.start, after.start)
+        {
+            matches.push((before.start, after.start));
+            before.start += 1;
+            after.start += 1;
+        }
+        let mut suffix = 0;
+        while !before.is_empty()
+            && !after.is_empty()
+            && eq(before.end - 1, after.end - 1)
+        {
+            before.end -= 1;
+            after.end -= 1;
+            suffix += 1;
+        }
+
+        if !before.is_empty() && !after.is_empty() {
+            let (x, y) = self.middle_snake(eq, before.clone(), after.clone());
+            self.conquer(eq, before.start..x, after.start..y, matches);
+            self.conquer(eq, x..before.end, y..after.end, matches);
+        }
+
+        matches.extend((0..suffix).map(|i| (before.end + i, after.end + i)));
+    }
+
+    /// Find the start of the middle snake of an optimal path through
+    /// `before` and `after`, which must both be non-empty and differ in
+    /// their first and last elements.
+    fn middle_snake<F>(
+        &mut self,
+        eq: &F,
+        before: Range<usize>,
+        after: Range<usize>,
+    ) -> (usize, usize)
+    where
+        F: Fn(usize, usize) -> bool,
+    {
+        let (n, m) = (before.len() as isize, after.len() as isize);
+        // Forward diagonal k corresponds to backward diagonal delta - k.
+        let delta = n - m;
+        let odd = delta % 2 != 0;
+        let forward_eq = |x: isize, y: isize| {
+            eq(before.start + x as usize, after.start + y as usize)
+        };
+        let backward_eq = |x: isize, y: isize| {
+            eq(before.end - 1 - x as usize, after.end - 1 - y as usize)
+        };
+
+        self.forward[1] = 0;
+        self.backward[1] = 0;
+        for d in 0..=(n + m + 1) / 2 {
+            for k in (-d..=d).step_by(2) {
+                let (x0, y0) = self.forward.step(d, k);
+                let (mut x, mut y) = (x0, y0);
+                while x < n && y < m && forward_eq(x, y) {
+                    x += 1;
+                    y += 1;
+                }
+                self.forward[k] = x;
+                if odd
+                    && (k - delta).abs() < d
+                    && x + self.backward[delta - k] >= n
+                {
+                    return (
+                        before.start + x0 as usize,
+                        after.start + y0 as usize,
+                    );
+                }
+            }
+
+            for k in (-d..=d).step_by(2) {
+                let (mut x, mut y) = self.backward.step(d, k);
+                while x < n && y < m && backward_eq(x, y) {
+                    x += 1;
+                    y += 1;
+                }
+                self.backward[k] = x;
+                if !odd
+                    && (k - delta).abs() <= d
+                    && x + self.forward[delta - k] >= n
+                {
+                    return (before.end - x as usize, after.end - y as usize);
+                }
+            }
+        }
+
+        unreachable!("the forward and backward searches always overlap")
+    }
+}
+
+/// Furthest-reaching x values, indexed by diagonal k = x - y.
+struct Diagonals {
+    values: Vec<isize>,
+    offset: isize,
+}
+
+impl Diagonals {
+    fn new(max: usize) -> Self {
+        Self { values: vec![0; 2 * max + 1], offset: max as isize }
+    }
+
+    /// Return the starting point on diagonal k for step d, extending the
+    /// furthest-reaching path from step d - 1 by one insertion or deletion.
+    fn step(&self, d: isize, k: isize) -> (isize, isize) {
+        let x = if k == -d || (k != d && self[k - 1] < self[k + 1]) {
+            self[k + 1]
+        } else {
+            self[k - 1] + 1
+        };
+        (x, x - k)
+    }
+}
+
+impl core::ops::Index<isize> for Diagonals {
+    type Output = isize;
+
+    fn index(&self, k: isize) -> &isize {
+        &self.values[(self.offset + k) as usize]
+    }
+}
+
+impl core::ops::IndexMut<isize> for Diagonals {
+    fn index_mut(&mut self, k: isize) -> &mut isize {
+        &mut self.values[(self.offset + k) as usize]
+    }
+}
+
+/// Turn an increasing list of matching pairs into runs of edits.
+fn edits_from_matches(
+    n: usize,
+    m: usize,
+    matches: &[(usize, usize)],
+) -> Vec<SeqEdit> {
+    let mut edits: Vec<SeqEdit> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    let push_gap = |edits: &mut Vec<SeqEdit>, i, i_end, j, j_end| {
+        let kind = match (i == i_end, j == j_end) {
+            (true, true) => return,
+            (true, false) => SeqEditKind::Insert,
+            (false, true) => SeqEditKind::Delete,
+            (false, false) => SeqEditKind::Replace,
+        };
+        edits.push(SeqEdit { kind, before: i..i_end, after: j..j_end });
+    };
+
+    for &(mi, mj) in matches {
+        push_gap(&mut edits, i, mi, j, mj);
+        match edits.last_mut() {
+            Some(edit)
+                if edit.kind == SeqEditKind::Equal
+                    && edit.before.end == mi
+                    && edit.after.end == mj =>
+            {
+                edit.before.end += 1;
+                edit.after.end += 1;
+            }
+            _ => edits.push(SeqEdit {
+                kind: SeqEditKind::Equal,
+                before: mi..mi + 1,
+                after: mj..mj + 1,
+            }),
+        }
+        i = mi + 1;
+        j = mj + 1;
+    }
+    push_gap(&mut edits, i, n, j, m);
+
+    edits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Diffable;
+    use alloc::vec::Vec;
+
+    fn kinds(edits: &[SeqEdit]) -> Vec<SeqEditKind> {
+        edits.iter().map(|edit| edit.kind).collect()
+    }
+
+    // Reconstruct the after sequence from the before sequence and the edits.
+    fn apply(before: &[char], after: &[char], edits: &[SeqEdit]) -> Vec<char> {
+        let mut out = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        for edit in edits {
+            assert_eq!(edit.before.start, i, "before ranges are contiguous");
+            assert_eq!(edit.after.start, j, "after ranges are contiguous");
+            match edit.kind {
+                SeqEditKind::Equal => {
+                    assert_eq!(
+                        before[edit.before.clone()],
+                        after[edit.after.clone()]
+                    );
+                    out.extend_from_slice(&before[edit.before.clone()]);
+                }
+                SeqEditKind::Insert | SeqEditKind::Replace => {
+                    out.extend_from_slice(&after[edit.after.clone()]);
+                }
+                SeqEditKind::Delete => {}
+            }
+            i = edit.before.end;
+            j = edit.after.end;
+        }
+        assert_eq!((i, j), (before.len(), after.len()));
+        out
+    }
+
+    fn lcs_len(a: &[char], b: &[char]) -> usize {
+        let mut dp = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for i in 0..a.len() {
+            for j in 0..b.len() {
+                dp[i + 1][j + 1] = if a[i] == b[j] {
+                    dp[i][j] + 1
+                } else {
+                    dp[i][j + 1].max(dp[i + 1][j])
+                };
+            }
+        }
+        dp[a.len()][b.len()]
+    }
+
+    #[test]
+    fn seq_diff_basic() {
+        // Arrays are diffed element by element, so use slices.
+        let before: &[i32] = &[1, 2, 3];
+        let diff = before.diff(before).seq_diff();
+        assert_eq!(
+            diff.edits,
+            [SeqEdit { kind: SeqEditKind::Equal, before: 0..3, after: 0..3 }]
+        );
+        assert_eq!(diff.changes().count(), 0);
+
+        let empty: &[i32] = &[];
+        let diff = empty.diff(before).seq_diff();
+        assert_eq!(kinds(&diff.edits), [SeqEditKind::Insert]);
+        let diff = before.diff(empty).seq_diff();
+        assert_eq!(kinds(&diff.edits), [SeqEditKind::Delete]);
+        let diff = empty.diff(empty).seq_diff();
+        assert!(diff.edits.is_empty());
+
+        let diff = [1, 2, 3][..].diff(&[4, 5][..]).seq_diff();
+        assert_eq!(
+            diff.edits,
+            [SeqEdit { kind: SeqEditKind::Replace, before: 0..3, after: 0..2 }]
+        );
+    }
+
+    #[test]
+    fn seq_diff_deque_and_list() {
+        use alloc::collections::{LinkedList, VecDeque};
+
+        let before: VecDeque<i32> = [1, 2, 3].into();
+        let after: VecDeque<i32> = [0, 1, 3].into();
+        let diff = before.diff(&after).seq_diff();
+        assert_eq!(
+            kinds(&diff.edits),
+            [
+                SeqEditKind::Insert,
+                SeqEditKind::Equal,
+                SeqEditKind::Delete,
+                SeqEditKind::Equal
+            ]
+        );
+
+        let before: LinkedList<i32> = [1, 2, 3].into();
+        let after: LinkedList<i32> = [1, 2, 3, 4].into();
+        let diff = before.diff(&after).seq_diff();
+        assert_eq!(
+            kinds(&diff.edits),
+            [SeqEditKind::Equal, SeqEditKind::Insert]
+        );
+        assert_eq!(diff.after_items(&diff.edits[1]), [&4]);
+    }
+
+    #[test]
+    fn seq_diff_matches_lcs() {
+        let cases = [
+            ("abcabba", "cbabac"),
+            ("", "abc"),
+            ("abc", ""),
+            ("abcdef", "abcdef"),
+            ("kitten", "sitting"),
+            ("xaxbxcx", "abc"),
+            ("the quick brown fox", "a quick brown dog jumped"),
+            ("aaaaaaaa", "aaa"),
+            ("abab", "baba"),
+        ];
+
+        for (before, after) in cases {
+            let before: Vec<char> = before.chars().collect();
+            let after: Vec<char> = after.chars().collect();
+            let diff = SeqDiff::new(&before, &after);
+
+            assert_eq!(
+                apply(&before, &after, &diff.edits),
+                after,
+                "edits reconstruct after for {before:?} -> {after:?}"
+            );
+            let equal: usize = diff
+                .edits
+                .iter()
+                .filter(|edit| edit.kind == SeqEditKind::Equal)
+                .map(|edit| edit.before.len())
+                .sum();
+            assert_eq!(
+                equal,
+                lcs_len(&before, &after),
+                "edit script is minimal for {before:?} -> {after:?}"
+            );
+
+            // Runs of the same kind are always coalesced.
+            for pair in diff.edits.windows(2) {
+                assert!(
+                    pair[0].kind != pair[1].kind,
+                    "adjacent edits have different kinds: {pair:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn seq_diff_random() {
+        // A small xorshift generator, so that the cases are deterministic.
+        let mut state = 0x2545_f491_u32;
+        let mut next = move |bound: u32| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state % bound
+        };
+
+        for _ in 0..500 {
+            // A small alphabet makes for lots of partial matches.
+            let mut gen_seq = || -> Vec<char> {
+                let len = next(16);
+                (0..len).map(|_| (b'a' + next(3) as u8) as char).collect()
+            };
+            let before = gen_seq();
+            let after = gen_seq();
+            let diff = SeqDiff::new(&before, &after);
+
+            assert_eq!(apply(&before, &after, &diff.edits), after);
+            let equal: usize = diff
+                .edits
+                .iter()
+                .filter(|edit| edit.kind == SeqEditKind::Equal)
+                .map(|edit| edit.before.len())
+                .sum();
+            assert_eq!(
+                equal,
+                lcs_len(&before, &after),
+                "edit script is minimal for {before:?} -> {after:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn seq_diff_large() {
+        // Long sequences with scattered changes: memory use must stay linear
+        // in the input size rather than growing with the edit distance.
+        let before: Vec<u32> = (0..20_000).collect();
+        let after: Vec<u32> = before
+            .iter()
+            .map(|&x| if x % 100 == 0 { x + 1_000_000 } else { x })
+            .filter(|&x| x % 150 != 0)
+            .collect();
+
+        let diff = SeqDiff::new(&before, &after);
+        let equal: usize = diff
+            .edits
+            .iter()
+            .filter(|edit| edit.kind == SeqEditKind::Equal)
+            .map(|edit| edit.before.len())
+            .sum();
+        // Elements divisible by 100 are replaced, and those divisible by 150
+        // (but not 300) are removed.
+        let unchanged =
+            before.iter().filter(|&&x| x % 100 != 0 && x % 150 != 0).count();
+        assert_eq!(equal, unchanged);
+    }
+
+    #[test]
+    fn seq_diff_visit() {
+        use alloc::{format, string::String};
+
+        #[derive(Default)]
+        struct Recorder(Vec<String>);
+
+        impl DiffVisitor for Recorder {
+            fn added(&mut self, path: &DiffPath<'_>, value: &dyn fmt::Debug) {
+                self.0.push(format!("{path}: + {value:?}"));
+            }
+
+            fn removed(&mut self, path: &DiffPath<'_>, value: &dyn fmt::Debug) {
+                self.0.push(format!("{path}: - {value:?}"));
+            }
+        }
+
+        let before = ['a', 'b', 'c'];
+        let after = ['a', 'x', 'c', 'd'];
+        let mut recorder = Recorder::default();
+        SeqDiff::new(&before, &after).visit(&mut recorder);
+        assert_eq!(recorder.0, ["[1]: - 'b'", "[1]: + 'x'", "[3]: + 'd'"]);
+    }
+}
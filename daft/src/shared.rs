@@ -0,0 +1,409 @@
+//! Diffs of shared pointers that check for pointer equality.
+
+use crate::{
+    DiffPath, DiffSummary, DiffVisitor, Diffable, IntoOwnedDiff, Invert, Merge,
+    MergeConflictKind, Patch, PatchError, VisitDiff,
+};
+use alloc::{rc::Rc, sync::Arc};
+use core::{fmt, ops::Deref};
+
+/// A wrapper around a shared pointer whose diff skips the pointee if both
+/// sides point to the same allocation.
+///
+/// The [`Diffable`] implementations for [`Arc<T>`] and [`Rc<T>`] always
+/// recurse into `T`. That's wasteful for structurally shared data, where
+/// before and after are snapshots that mostly consist of the same pointers.
+/// Wrapping the pointer in `Shared` makes its diff a [`SharedDiff`], which is
+/// [`SharedDiff::Identical`] without diffing `T` if the pointers are equal
+/// (as determined by [`Arc::ptr_eq`] or [`Rc::ptr_eq`]).
+///
+/// `Shared` can be used anywhere a `Diffable` type can, such as in map values
+/// or struct fields.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "std", feature = "derive"))] {
+/// use daft::{DiffSummary, Diffable, Shared, SharedDiff};
+/// use std::{collections::BTreeMap, sync::Arc};
+///
+/// #[derive(Clone, Diffable)]
+/// struct Snapshot {
+///     config: Shared<Arc<BTreeMap<String, String>>>,
+///     generation: u64,
+/// }
+///
+/// let before = Snapshot {
+///     config: Shared(Arc::new([("a".to_owned(), "b".to_owned())].into())),
+///     generation: 1,
+/// };
+/// let after = Snapshot { generation: 2, ..before.clone() };
+///
+/// // The config map is shared, so it isn't diffed.
+/// let diff = before.diff(&after);
+/// assert!(matches!(diff.config, SharedDiff::Identical(_)));
+/// assert!(diff.is_modified());
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Shared<P>(pub P);
+
+impl<P> Shared<P> {
+    /// Return the wrapped pointer.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.0
+    }
+}
+
+impl<P: Deref> Deref for Shared<P> {
+    type Target = P::Target;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> From<Arc<T>> for Shared<Arc<T>> {
+    #[inline]
+    fn from(ptr: Arc<T>) -> Self {
+        Self(ptr)
+    }
+}
+
+impl<T: ?Sized> From<Rc<T>> for Shared<Rc<T>> {
+    #[inline]
+    fn from(ptr: Rc<T>) -> Self {
+        Self(ptr)
+    }
+}
+
+impl<T: Diffable + ?Sized> Diffable for Shared<Arc<T>> {
+    type Diff<'daft>
+        = SharedDiff<'daft, T>
+    where
+        T: 'daft;
+
+    fn diff<'daft>(&'daft self, other: &'daft Self) -> Self::Diff<'daft> {
+        if Arc::ptr_eq(&self.0, &other.0) {
+            SharedDiff::Identical(&*self.0)
+        } else {
+            SharedDiff::Diffed(self.0.diff(&other.0))
+        }
+    }
+}
+
+impl<T: Diffable + ?Sized> Diffable for Shared<Rc<T>> {
+    type Diff<'daft>
+        = SharedDiff<'daft, T>
+    where
+        T: 'daft;
+
+    fn diff<'daft>(&'daft self, other: &'daft Self) -> Self::Diff<'daft> {
+        if Rc::ptr_eq(&self.0, &other.0) {
+            SharedDiff::Identical(&*self.0)
+        } else {
+            SharedDiff::Diffed(self.0.diff(&other.0))
+        }
+    }
+}
+
+/// The diff of a [`Shared`] pointer.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound = "T: serde::Serialize, T::Diff<'daft>: serde::Serialize")
+)]
+pub enum SharedDiff<'daft, T: Diffable + ?Sized + 'daft> {
+    /// Both sides point to the same value, which wasn't diffed.
+    Identical(&'daft T),
+
+    /// The sides point to different allocations, and were diffed. The values
+    /// may still be equal.
+    Diffed(T::Diff<'daft>),
+}
+
+impl<'daft, T: Diffable + ?Sized> SharedDiff<'daft, T> {
+    /// Return true if both sides point to the same value.
+    #[inline]
+    pub fn is_identical(&self) -> bool {
+        matches!(self, Self::Identical(_))
+    }
+
+    /// Return the diff of the values, computing it if both sides point to
+    /// the same value.
+    pub fn into_diff(self) -> T::Diff<'daft> {
+        match self {
+            Self::Identical(value) => value.diff(value),
+            Self::Diffed(diff) => diff,
+        }
+    }
+}
+
+impl<'daft, T> Clone for SharedDiff<'daft, T>
+where
+    T: Diffable + ?Sized,
+    T::Diff<'daft>: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Identical(value) => Self::Identical(value),
+            Self::Diffed(diff) => Self::Diffed(diff.clone()),
+        }
+    }
+}
+
+impl<'daft, T> fmt::Debug for SharedDiff<'daft, T>
+where
+    T: Diffable + fmt::Debug + ?Sized,
+    T::Diff<'daft>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identical(value) => {
+                f.debug_tuple("Identical").field(value).finish()
+            }
+            Self::Diffed(diff) => f.debug_tuple("Diffed").field(diff).finish(),
+        }
+    }
+}
+
+impl<'daft, T> PartialEq for SharedDiff<'daft, T>
+where
+    T: Diffable + PartialEq + ?Sized,
+    T::Diff<'daft>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Identical(a), Self::Identical(b)) => a == b,
+            (Self::Diffed(a), Self::Diffed(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<'daft, T> Eq for SharedDiff<'daft, T>
+where
+    T: Diffable + Eq + ?Sized,
+    T::Diff<'daft>: Eq,
+{
+}
+
+impl<'daft, T> DiffSummary for SharedDiff<'daft, T>
+where
+    T: Diffable + ?Sized,
+    T::Diff<'daft>: DiffSummary,
+{
+    fn is_unchanged(&self) -> bool {
+        match self {
+            Self::Identical(_) => true,
+            Self::Diffed(diff) => diff.is_unchanged(),
+        }
+    }
+}
+
+impl<'daft, T> VisitDiff for SharedDiff<'daft, T>
+where
+    T: Diffable + ?Sized,
+    T::Diff<'daft>: VisitDiff,
+{
+    fn visit_at(&self, path: &DiffPath<'_>, visitor: &mut dyn DiffVisitor) {
+        if let Self::Diffed(diff) = self {
+            diff.visit_at(path, visitor);
+        }
+    }
+}
+
+impl<'daft, T> Invert for SharedDiff<'daft, T>
+where
+    T: Diffable + ?Sized,
+    T::Diff<'daft>: Invert,
+{
+    fn invert(self) -> Self {
+        match self {
+            Self::Identical(value) => Self::Identical(value),
+            Self::Diffed(diff) => Self::Diffed(diff.invert()),
+        }
+    }
+}
+
+/// The owned form is `None` if both sides pointed to the same value.
+impl<'daft, T> IntoOwnedDiff for SharedDiff<'daft, T>
+where
+    T: Diffable + ?Sized,
+    T::Diff<'daft>: IntoOwnedDiff,
+{
+    type Owned = Option<<T::Diff<'daft> as IntoOwnedDiff>::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        match self {
+            Self::Identical(_) => None,
+            Self::Diffed(diff) => Some(diff.into_owned()),
+        }
+    }
+}
+
+macro_rules! shared_patch_merge {
+    ($($ptr:ident),*) => {
+        $(
+            /// If both sides pointed to the same value, the target is left
+            /// alone, but must point to or be equal to that value. Otherwise,
+            /// the diff is applied to a unique copy of the pointee, cloning it
+            /// if it's shared.
+            impl<'daft, T> Patch<Shared<$ptr<T>>> for SharedDiff<'daft, T>
+            where
+                T: Diffable + Clone + PartialEq,
+                T::Diff<'daft>: Patch<T>,
+            {
+                fn apply(
+                    &self,
+                    target: &mut Shared<$ptr<T>>,
+                ) -> Result<(), PatchError> {
+                    match self {
+                        Self::Identical(value) => {
+                            if core::ptr::eq(&*target.0, *value)
+                                || &*target.0 == *value
+                            {
+                                Ok(())
+                            } else {
+                                Err(PatchError::Mismatch)
+                            }
+                        }
+                        Self::Diffed(diff) => {
+                            diff.apply($ptr::make_mut(&mut target.0))
+                        }
+                    }
+                }
+            }
+
+            impl<'daft, T> Merge<Shared<$ptr<T>>> for SharedDiff<'daft, T>
+            where
+                T: Diffable + Clone,
+                T::Diff<'daft>: Merge<T>,
+            {
+                fn merge_at(
+                    &self,
+                    theirs: &Self,
+                    target: &mut Shared<$ptr<T>>,
+                    path: &DiffPath<'_>,
+                    conflict: &mut dyn FnMut(
+                        &DiffPath<'_>,
+                        MergeConflictKind,
+                    ),
+                ) {
+                    let Self::Diffed(theirs) = theirs else {
+                        // Their side is unchanged.
+                        return;
+                    };
+                    let target = $ptr::make_mut(&mut target.0);
+                    match *self {
+                        // Our side is unchanged, so diff the value with
+                        // itself to merge their changes.
+                        Self::Identical(value) => {
+                            value.diff(value).merge_at(
+                                theirs, target, path, conflict,
+                            );
+                        }
+                        Self::Diffed(ref ours) => {
+                            ours.merge_at(theirs, target, path, conflict);
+                        }
+                    }
+                }
+            }
+        )*
+    }
+}
+
+shared_patch_merge! { Arc, Rc }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Leaf;
+    use alloc::{string::String, vec, vec::Vec};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    // A type that counts how many times it's been diffed.
+    struct Counted<'a>(u32, &'a AtomicUsize);
+
+    impl Diffable for Counted<'_> {
+        type Diff<'daft>
+            = Leaf<&'daft u32>
+        where
+            Self: 'daft;
+
+        fn diff<'daft>(&'daft self, other: &'daft Self) -> Self::Diff<'daft> {
+            self.1.fetch_add(1, Ordering::Relaxed);
+            Leaf { before: &self.0, after: &other.0 }
+        }
+    }
+
+    #[test]
+    fn shared_ptr_eq() {
+        let count = AtomicUsize::new(0);
+        let a = Shared(Arc::new(Counted(1, &count)));
+        let b = Shared(Arc::new(Counted(1, &count)));
+
+        let a2 = a.clone();
+        let diff = a.diff(&a2);
+        assert!(diff.is_identical());
+        assert!(diff.is_unchanged());
+        assert_eq!(count.load(Ordering::Relaxed), 0);
+
+        let diff = a.diff(&b);
+        assert!(!diff.is_identical());
+        assert!(diff.is_unchanged());
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+
+        let a = Shared(Rc::new(Counted(1, &count)));
+        let b = Shared(Rc::new(Counted(2, &count)));
+        let a2 = a.clone();
+        assert!(a.diff(&a2).is_identical());
+        assert!(a.diff(&b).is_modified());
+        assert_eq!(a.diff(&b).into_owned(), Some(Leaf { before: 1, after: 2 }));
+        assert_eq!(a.diff(&a2).into_owned(), None);
+    }
+
+    #[test]
+    fn shared_patch_merge() {
+        let base: Shared<Arc<Vec<String>>> =
+            Arc::new(vec!["a".into(), "b".into()]).into();
+        let ours = Shared(Arc::new(vec!["a".into(), "c".into()]));
+
+        // Patching a shared target clones it, leaving the base untouched.
+        let mut target = base.clone();
+        base.diff(&ours).apply(&mut target).unwrap();
+        assert_eq!(target, ours);
+        assert_eq!(*base, ["a", "b"]);
+
+        let mut target = base.clone();
+        base.diff(&base).apply(&mut target).unwrap();
+        assert!(Arc::ptr_eq(&target.0, &base.0));
+
+        // An identical diff still checks that the target matches.
+        let mut target = Shared(Arc::new(vec!["a".into(), "b".into()]));
+        base.diff(&base).apply(&mut target).unwrap();
+        let mut target = ours.clone();
+        assert_eq!(
+            base.diff(&base).apply(&mut target),
+            Err(PatchError::Mismatch)
+        );
+
+        // Our side is identical, so their changes are applied.
+        let mut merged = base.clone();
+        base.diff(&base).merge(&base.diff(&ours), &mut merged).unwrap();
+        assert_eq!(merged, ours);
+
+        let theirs = Shared(Arc::new(vec!["a".into(), "d".into()]));
+        let mut merged = ours.clone();
+        let error = base
+            .diff(&ours)
+            .merge(&base.diff(&theirs), &mut merged)
+            .unwrap_err();
+        assert_eq!(error.conflicts().len(), 1);
+        assert_eq!(merged, ours);
+    }
+}
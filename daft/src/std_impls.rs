@@ -6,9 +6,11 @@ use std::{
     ffi::{OsStr, OsString},
     hash::Hash,
     path::{Path, PathBuf},
+    time::{Instant, SystemTime},
 };
 
 leaf! { Path, OsStr }
+leaf! { Instant, SystemTime }
 leaf_deref! { PathBuf => Path, OsString => OsStr }
 
 map_diff!(
@@ -65,7 +67,7 @@ map_diff!(
     /// assert_eq!(modified, [(&2, Leaf { before: &"dolor", after: &"sit" })]);
     /// # }
     /// ```
-    HashMap, Hash
+    HashMap, Hash, remove
 );
 set_diff!(
     /// A diff of two [`HashSet`] instances.
@@ -97,7 +99,7 @@ set_diff!(
     /// assert_eq!(changes, expected);
     /// # }
     /// ```
-    HashSet, Hash
+    HashSet, Hash, remove
 );
 
 #[cfg(test)]
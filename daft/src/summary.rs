@@ -0,0 +1,116 @@
+//! Whole-diff change detection.
+
+use crate::Leaf;
+
+/// A diff that can report whether anything changed between the before and
+/// after values.
+///
+/// This trait is implemented for [`Leaf`], for all of the map and set diff
+/// types in this crate, for tuples and arrays of diffs, and for diff types
+/// generated by the [`Diffable`](macro@crate::Diffable) derive macro. For generated diff
+/// types, the summary is computed recursively over each field that isn't
+/// ignored.
+///
+/// Some diff types have inherent methods with the same names, e.g.
+/// [`Leaf::is_unchanged`], or per-key methods like
+/// [`BTreeMapDiff::is_unchanged`](crate::BTreeMapDiff::is_unchanged). To call
+/// the trait methods on those types, use `DiffSummary::is_unchanged(&diff)`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "std", feature = "derive"))] {
+/// use daft::{DiffSummary, Diffable};
+/// use std::collections::BTreeMap;
+///
+/// #[derive(Diffable)]
+/// struct Config {
+///     name: String,
+///     settings: BTreeMap<String, u32>,
+/// }
+///
+/// let before = Config {
+///     name: "a".to_owned(),
+///     settings: [("x".to_owned(), 1)].into_iter().collect(),
+/// };
+/// let mut after = Config {
+///     name: "a".to_owned(),
+///     settings: [("x".to_owned(), 1)].into_iter().collect(),
+/// };
+/// assert!(before.diff(&after).is_unchanged());
+///
+/// after.settings.insert("y".to_owned(), 2);
+/// assert!(before.diff(&after).is_modified());
+/// # }
+/// ```
+pub trait DiffSummary {
+    /// Returns true if nothing changed between the before and after values.
+    fn is_unchanged(&self) -> bool;
+
+    /// Returns true if anything changed between the before and after values.
+    ///
+    /// This is the negation of [`is_unchanged`](Self::is_unchanged).
+    #[inline]
+    fn is_modified(&self) -> bool {
+        !self.is_unchanged()
+    }
+}
+
+impl<T: PartialEq> DiffSummary for Leaf<T> {
+    #[inline]
+    fn is_unchanged(&self) -> bool {
+        self.before == self.after
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Diffable;
+
+    #[test]
+    fn leaf_and_tuple_summary() {
+        let diff = 1_u32.diff(&1);
+        assert!(DiffSummary::is_unchanged(&diff));
+
+        let before = (1_u32, 'a', Some(2_u64));
+        let mut after = before;
+        assert!(before.diff(&after).is_unchanged());
+
+        after.2 = None;
+        let diff = before.diff(&after);
+        assert!(diff.is_modified());
+        assert!(DiffSummary::is_unchanged(&diff.0));
+        assert!(DiffSummary::is_modified(&diff.2));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn map_and_set_summary() {
+        use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+        let before: BTreeMap<u32, &str> = [(1, "a"), (2, "b")].into();
+        let mut after = before.clone();
+        assert!(DiffSummary::is_unchanged(&before.diff(&after)));
+        after.insert(2, "c");
+        assert!(DiffSummary::is_modified(&before.diff(&after)));
+
+        let before: HashMap<u32, &str> = [(1, "a")].into();
+        let mut after = before.clone();
+        assert!(DiffSummary::is_unchanged(&before.diff(&after)));
+        after.remove(&1);
+        assert!(DiffSummary::is_modified(&before.diff(&after)));
+
+        let before: BTreeSet<u32> = [1, 2].into();
+        let mut after = before.clone();
+        assert!(before.diff(&after).is_unchanged());
+        after.insert(3);
+        assert!(before.diff(&after).is_modified());
+
+        let before: HashSet<u32> = [1, 2].into();
+        let mut after = before.clone();
+        assert!(before.diff(&after).is_unchanged());
+        after.remove(&1);
+        assert!(before.diff(&after).is_modified());
+    }
+}
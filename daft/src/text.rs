@@ -0,0 +1,575 @@
+//! Text diffs.
+
+use crate::{
+    DiffPath, DiffSummary, DiffVisitor, IntoOwnedDiff, Invert, Leaf, Merge,
+    MergeConflictKind, Patch, PatchError, SeqDiff, SeqEdit, SeqEditKind,
+    VisitDiff, merge::merge_leaf,
+};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, ops::Range};
+
+/// The unit that text is split into by a [`TextDiff`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TextUnit {
+    /// Lines, including their trailing newlines.
+    Line,
+
+    /// Runs of whitespace and runs of non-whitespace characters.
+    Word,
+
+    /// Individual characters.
+    Char,
+}
+
+impl TextUnit {
+    /// Split `text` into tokens. Concatenating the tokens produces `text`.
+    fn tokenize(self, text: &str) -> Vec<&str> {
+        match self {
+            Self::Line => text.split_inclusive('\n').collect(),
+            Self::Word => {
+                let mut tokens = Vec::new();
+                let mut start = 0;
+                let mut prev_space = None;
+                for (ix, c) in text.char_indices() {
+                    let space = c.is_whitespace();
+                    if prev_space.is_some_and(|prev| prev != space) {
+                        tokens.push(&text[start..ix]);
+                        start = ix;
+                    }
+                    prev_space = Some(space);
+                }
+                if start < text.len() {
+                    tokens.push(&text[start..]);
+                }
+                tokens
+            }
+            Self::Char => text
+                .char_indices()
+                .map(|(ix, c)| &text[ix..ix + c.len_utf8()])
+                .collect(),
+        }
+    }
+}
+
+/// An ordered diff of two strings, split into lines, words or characters.
+///
+/// Strings are diffed as [`Leaf`] nodes by default. For long, multi-line
+/// strings such as templates or certificates, a `TextDiff` is more useful: it
+/// splits both sides into [`TextUnit`]s and computes an edit script over them
+/// as a [`SeqDiff`].
+///
+/// A line-level `TextDiff` can be created from a `Leaf<&str>` with
+/// [`Leaf::text_diff`], or for a struct field with the `#[daft(text)]`
+/// attribute on the [`Diffable`](macro@crate::Diffable) derive macro. Word
+/// and character-level diffs can be created with [`TextDiff::new`].
+///
+/// The changes can be grouped into [`TextHunk`]s with [`TextDiff::hunks`],
+/// and rendered in the unified diff format with [`TextDiff::unified`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use daft::Diffable;
+///
+/// let before = "a\nb\nc\nd\n".to_owned();
+/// let after = "a\nx\nc\nd\n".to_owned();
+///
+/// let diff = before.diff(&after).text_diff();
+/// assert_eq!(
+///     diff.unified().context(1).to_string(),
+///     "@@ -1,3 +1,3 @@\n a\n-b\n+x\n c\n",
+/// );
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TextDiff<'daft> {
+    /// The unit the text was split into.
+    pub unit: TextUnit,
+
+    /// The full text on both sides.
+    pub text: Leaf<&'daft str>,
+
+    /// The diff of the two sides, split into tokens.
+    pub tokens: SeqDiff<'daft, str>,
+}
+
+impl<'daft> TextDiff<'daft> {
+    /// Compute a diff of two strings, split into `unit`s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use daft::{TextDiff, TextUnit};
+    ///
+    /// let diff = TextDiff::new("one two three", "one 2 three", TextUnit::Word);
+    /// let change = diff.tokens.changes().next().unwrap();
+    /// assert_eq!(diff.tokens.before_items(change), ["two"]);
+    /// assert_eq!(diff.tokens.after_items(change), ["2"]);
+    /// ```
+    pub fn new(before: &'daft str, after: &'daft str, unit: TextUnit) -> Self {
+        let tokens = SeqDiff::new(unit.tokenize(before), unit.tokenize(after));
+        Self { unit, text: Leaf { before, after }, tokens }
+    }
+
+    /// Group the changes into hunks, each surrounded by up to `context`
+    /// unchanged tokens.
+    ///
+    /// Changes separated by at most `2 * context` unchanged tokens are
+    /// grouped into the same hunk.
+    pub fn hunks(&self, context: usize) -> Vec<TextHunk> {
+        // Changed runs in an edit script are always separated by unchanged
+        // runs, so a hunk alternates between the two.
+        let edits = &self.tokens.edits;
+        let mut hunks = Vec::new();
+        let mut ix = 0;
+        while ix < edits.len() {
+            if edits[ix].kind == SeqEditKind::Equal {
+                ix += 1;
+                continue;
+            }
+
+            let mut hunk_edits = Vec::new();
+            if let Some(prev) = ix.checked_sub(1) {
+                hunk_edits.push(edits[prev].trim_start(context));
+            }
+            hunk_edits.push(edits[ix].clone());
+            while let Some(gap) = edits.get(ix + 1) {
+                match edits.get(ix + 2) {
+                    Some(next) if gap.before.len() <= 2 * context => {
+                        hunk_edits.push(gap.clone());
+                        hunk_edits.push(next.clone());
+                        ix += 2;
+                    }
+                    _ => {
+                        hunk_edits.push(gap.trim_end(context));
+                        break;
+                    }
+                }
+            }
+            hunk_edits.retain(|edit| {
+                !(edit.before.is_empty() && edit.after.is_empty())
+            });
+
+            let (first, last) =
+                (&hunk_edits[0], &hunk_edits[hunk_edits.len() - 1]);
+            hunks.push(TextHunk {
+                before: first.before.start..last.before.end,
+                after: first.after.start..last.after.end,
+                edits: hunk_edits,
+            });
+            ix += 1;
+        }
+        hunks
+    }
+
+    /// Return a [`Display`](fmt::Display) implementation that renders this
+    /// diff in the unified diff format.
+    ///
+    /// By default, hunks have three tokens of context: this can be changed
+    /// with [`UnifiedDiff::context`].
+    pub fn unified(&self) -> UnifiedDiff<'_, 'daft> {
+        UnifiedDiff { diff: self, context: UnifiedDiff::DEFAULT_CONTEXT }
+    }
+}
+
+impl<'daft> Leaf<&'daft str> {
+    /// Perform a line-level diff of [`before`][Self::before] and
+    /// [`after`][Self::after], returning a [`TextDiff`].
+    ///
+    /// The [`Diffable`](crate::Diffable) implementations for `String` and
+    /// `str` return a `Leaf<&str>`; this method is the opt-in way to get a
+    /// text diff out of that leaf. For word or character-level diffs, use
+    /// [`TextDiff::new`].
+    pub fn text_diff(self) -> TextDiff<'daft> {
+        TextDiff::new(self.before, self.after, TextUnit::Line)
+    }
+}
+
+impl DiffSummary for TextDiff<'_> {
+    fn is_unchanged(&self) -> bool {
+        self.tokens.is_unchanged()
+    }
+}
+
+/// Removed and added tokens are reported with a
+/// [`PathSegment::Index`](crate::PathSegment::Index) into the before and after
+/// tokens respectively, as with [`SeqDiff`].
+impl VisitDiff for TextDiff<'_> {
+    fn visit_at(&self, path: &DiffPath<'_>, visitor: &mut dyn DiffVisitor) {
+        self.tokens.visit_at(path, visitor);
+    }
+}
+
+impl Invert for TextDiff<'_> {
+    fn invert(self) -> Self {
+        Self {
+            unit: self.unit,
+            text: self.text.invert(),
+            tokens: self.tokens.invert(),
+        }
+    }
+}
+
+impl Patch<String> for TextDiff<'_> {
+    fn apply(&self, target: &mut String) -> Result<(), PatchError> {
+        if target != self.text.before {
+            return Err(PatchError::Mismatch);
+        }
+        self.text.after.clone_into(target);
+        Ok(())
+    }
+}
+
+/// Text diffs are merged as a whole, in the same way as [`Leaf`]s: both sides
+/// conflict if they changed the text differently.
+impl Merge<String> for TextDiff<'_> {
+    fn merge_at(
+        &self,
+        theirs: &Self,
+        target: &mut String,
+        path: &DiffPath<'_>,
+        conflict: &mut dyn FnMut(&DiffPath<'_>, MergeConflictKind),
+    ) {
+        if merge_leaf(&self.text, &theirs.text, path, conflict) {
+            theirs.text.after.clone_into(target);
+        }
+    }
+}
+
+/// An owned version of [`TextDiff`], created with
+/// [`IntoOwnedDiff::into_owned`].
+///
+/// The tokens are not stored: use [`TextDiffOwned::as_diff`] to get them back.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TextDiffOwned {
+    /// The unit the text was split into.
+    pub unit: TextUnit,
+
+    /// The full text on both sides.
+    pub text: Leaf<String>,
+
+    /// The edit script over the tokens, in order.
+    pub edits: Vec<SeqEdit>,
+}
+
+impl TextDiffOwned {
+    /// Borrow this diff as a [`TextDiff`], splitting the text into tokens
+    /// again.
+    pub fn as_diff(&self) -> TextDiff<'_> {
+        let tokens = SeqDiff {
+            before: self.unit.tokenize(&self.text.before),
+            after: self.unit.tokenize(&self.text.after),
+            edits: self.edits.clone(),
+        };
+        TextDiff { unit: self.unit, text: self.text.as_deref(), tokens }
+    }
+}
+
+impl IntoOwnedDiff for TextDiff<'_> {
+    type Owned = TextDiffOwned;
+
+    fn into_owned(self) -> Self::Owned {
+        TextDiffOwned {
+            unit: self.unit,
+            text: self.text.map(ToString::to_string),
+            edits: self.tokens.edits,
+        }
+    }
+}
+
+impl DiffSummary for TextDiffOwned {
+    fn is_unchanged(&self) -> bool {
+        self.edits.iter().all(|edit| edit.kind == SeqEditKind::Equal)
+    }
+}
+
+impl Invert for TextDiffOwned {
+    fn invert(self) -> Self {
+        Self {
+            unit: self.unit,
+            text: self.text.invert(),
+            edits: self.edits.into_iter().map(SeqEdit::invert).collect(),
+        }
+    }
+}
+
+impl Patch<String> for TextDiffOwned {
+    fn apply(&self, target: &mut String) -> Result<(), PatchError> {
+        self.as_diff().apply(target)
+    }
+}
+
+/// A group of nearby changes within a [`TextDiff`], along with the unchanged
+/// tokens around them.
+///
+/// Created by [`TextDiff::hunks`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TextHunk {
+    /// The range of tokens covered by this hunk on the before side.
+    pub before: Range<usize>,
+
+    /// The range of tokens covered by this hunk on the after side.
+    pub after: Range<usize>,
+
+    /// The edits within this hunk, in order. Unchanged runs at the start and
+    /// end are trimmed to the requested amount of context.
+    pub edits: Vec<SeqEdit>,
+}
+
+/// Renders a [`TextDiff`] in the unified diff format.
+///
+/// Created by [`TextDiff::unified`].
+///
+/// Each hunk starts with a header of the form `@@ -1,3 +1,4 @@`, listing the
+/// 1-based start and length of the hunk on each side.
+///
+/// - For line diffs, unchanged lines are prefixed with a space, removed lines
+///   with `-`, and added lines with `+`. A line without a trailing newline is
+///   followed by `\ No newline at end of file`.
+/// - For word and character diffs, hunk ranges count words or characters.
+///   Each hunk is rendered on a single line, with removed text shown as
+///   `[-text-]` and added text as `{+text+}`.
+///
+/// # Example
+///
+/// ```
+/// use daft::{TextDiff, TextUnit};
+///
+/// let diff =
+///     TextDiff::new("the quick fox", "the slow fox", TextUnit::Word);
+/// assert_eq!(
+///     diff.unified().to_string(),
+///     "@@ -1,5 +1,5 @@\nthe [-quick-]{+slow+} fox\n",
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct UnifiedDiff<'a, 'daft> {
+    diff: &'a TextDiff<'daft>,
+    context: usize,
+}
+
+impl UnifiedDiff<'_, '_> {
+    /// The default number of unchanged tokens shown around each change.
+    pub const DEFAULT_CONTEXT: usize = 3;
+
+    /// Set the number of unchanged tokens shown around each change.
+    pub fn context(mut self, context: usize) -> Self {
+        self.context = context;
+        self
+    }
+}
+
+impl fmt::Display for UnifiedDiff<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tokens = &self.diff.tokens;
+        for hunk in self.diff.hunks(self.context) {
+            writeln!(
+                f,
+                "@@ -{} +{} @@",
+                HunkRange(&hunk.before),
+                HunkRange(&hunk.after),
+            )?;
+            match self.diff.unit {
+                TextUnit::Line => {
+                    for edit in &hunk.edits {
+                        if edit.kind == SeqEditKind::Equal {
+                            write_lines(f, ' ', tokens.before_items(edit))?;
+                        } else {
+                            write_lines(f, '-', tokens.before_items(edit))?;
+                            write_lines(f, '+', tokens.after_items(edit))?;
+                        }
+                    }
+                }
+                TextUnit::Word | TextUnit::Char => {
+                    for edit in &hunk.edits {
+                        if edit.kind == SeqEditKind::Equal {
+                            write_tokens(f, tokens.before_items(edit))?;
+                            continue;
+                        }
+                        if !edit.before.is_empty() {
+                            f.write_str("[-")?;
+                            write_tokens(f, tokens.before_items(edit))?;
+                            f.write_str("-]")?;
+                        }
+                        if !edit.after.is_empty() {
+                            f.write_str("{+")?;
+                            write_tokens(f, tokens.after_items(edit))?;
+                            f.write_str("+}")?;
+                        }
+                    }
+                    writeln!(f)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SeqEdit {
+    /// Keep up to the last `len` elements of an unchanged run.
+    fn trim_start(&self, len: usize) -> Self {
+        let len = self.before.len().min(len);
+        Self {
+            kind: self.kind,
+            before: self.before.end - len..self.before.end,
+            after: self.after.end - len..self.after.end,
+        }
+    }
+
+    /// Keep up to the first `len` elements of an unchanged run.
+    fn trim_end(&self, len: usize) -> Self {
+        let len = self.before.len().min(len);
+        Self {
+            kind: self.kind,
+            before: self.before.start..self.before.start + len,
+            after: self.after.start..self.after.start + len,
+        }
+    }
+}
+
+/// Formats a hunk range as `start,len`, following the conventions of GNU diff.
+struct HunkRange<'a>(&'a Range<usize>);
+
+impl fmt::Display for HunkRange<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.len() {
+            // An empty range refers to the position just before it.
+            0 => write!(f, "{},0", self.0.start),
+            1 => write!(f, "{}", self.0.start + 1),
+            len => write!(f, "{},{}", self.0.start + 1, len),
+        }
+    }
+}
+
+fn write_lines(
+    f: &mut fmt::Formatter<'_>,
+    prefix: char,
+    lines: &[&str],
+) -> fmt::Result {
+    for line in lines {
+        write!(f, "{prefix}{line}")?;
+        if !line.ends_with('\n') {
+            f.write_str("\n\\ No newline at end of file\n")?;
+        }
+    }
+    Ok(())
+}
+
+fn write_tokens(f: &mut fmt::Formatter<'_>, tokens: &[&str]) -> fmt::Result {
+    tokens.iter().try_for_each(|token| f.write_str(token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    fn lines(n: usize) -> String {
+        (1..=n).map(|i| format!("{i}\n")).collect()
+    }
+
+    #[test]
+    fn tokenize() {
+        assert_eq!(TextUnit::Line.tokenize("a\n\nb"), ["a\n", "\n", "b"]);
+        assert_eq!(
+            TextUnit::Word.tokenize(" one  two\n"),
+            [" ", "one", "  ", "two", "\n"]
+        );
+        assert_eq!(TextUnit::Char.tokenize("añb"), ["a", "ñ", "b"]);
+        assert!(TextUnit::Word.tokenize("").is_empty());
+    }
+
+    #[test]
+    fn text_hunks() {
+        let before = lines(20);
+        let after = before.replacen("3\n", "x\n", 1).replace("18\n", "");
+        let diff = Leaf { before: &*before, after: &*after }.text_diff();
+        let hunks = diff.hunks(2);
+        assert_eq!(
+            hunks.iter().map(|h| (&h.before, &h.after)).collect::<Vec<_>>(),
+            [(&(0..5), &(0..5)), (&(15..20), &(15..19))]
+        );
+        assert_eq!(
+            diff.unified().context(2).to_string(),
+            "@@ -1,5 +1,5 @@\n 1\n 2\n-3\n+x\n 4\n 5\n\
+             @@ -16,5 +16,4 @@\n 16\n 17\n-18\n 19\n 20\n",
+        );
+
+        // Changes separated by a short run of unchanged lines share a hunk.
+        let hunks = diff.hunks(7);
+        assert_eq!(hunks.len(), 1);
+        assert_eq!((&hunks[0].before, &hunks[0].after), (&(0..20), &(0..19)));
+        assert_eq!(hunks[0].edits.len(), 5);
+
+        // Without context, hunks only contain changes.
+        let hunks = diff.hunks(0);
+        assert_eq!(hunks.len(), 2);
+        assert!(hunks.iter().all(|h| h.edits.len() == 1));
+
+        assert!(diff.hunks(3).iter().all(|h| {
+            let first = h.edits.first().unwrap();
+            let last = h.edits.last().unwrap();
+            first.before.start == h.before.start
+                && last.before.end == h.before.end
+        }));
+        let unchanged = Leaf { before: &*before, after: &*before };
+        assert!(unchanged.text_diff().hunks(3).is_empty());
+    }
+
+    #[test]
+    fn text_unified() {
+        let diff = Leaf { before: "a\nb", after: "a\nb\nc\n" }.text_diff();
+        assert_eq!(
+            diff.unified().to_string(),
+            "@@ -1,2 +1,3 @@\n a\n-b\n\\ No newline at end of file\n+b\n+c\n",
+        );
+
+        let diff = Leaf { before: "", after: "a\n" }.text_diff();
+        assert_eq!(diff.unified().to_string(), "@@ -0,0 +1 @@\n+a\n");
+
+        let diff = TextDiff::new("kitten", "sitting", TextUnit::Char);
+        assert_eq!(
+            diff.unified().context(2).to_string(),
+            "@@ -1,6 +1,7 @@\n[-k-]{+s+}itt[-e-]{+i+}n{+g+}\n",
+        );
+        assert_eq!(
+            diff.unified().context(1).to_string(),
+            "@@ -1,2 +1,2 @@\n[-k-]{+s+}i\n@@ -4,3 +4,4 @@\nt[-e-]{+i+}n{+g+}\n",
+        );
+    }
+
+    #[test]
+    fn text_patch_merge() {
+        let base = lines(3);
+        let ours = base.replace('2', "two");
+        let theirs = base.replace('3', "three");
+        let diff = Leaf { before: &*base, after: &*ours }.text_diff();
+
+        let mut target = base.clone();
+        diff.apply(&mut target).unwrap();
+        assert_eq!(target, ours);
+        assert_eq!(diff.apply(&mut target), Err(PatchError::Mismatch));
+
+        let owned = diff.clone().into_owned();
+        assert_eq!(owned.as_diff(), diff);
+        assert_eq!(owned.clone().invert().as_diff(), diff.clone().invert());
+        let mut target = base.clone();
+        owned.apply(&mut target).unwrap();
+        assert_eq!(target, ours);
+
+        let theirs_diff = Leaf { before: &*base, after: &*theirs }.text_diff();
+        let mut target = ours.clone();
+        let error = diff.merge(&theirs_diff, &mut target).unwrap_err();
+        assert_eq!(error.conflicts().len(), 1);
+        assert_eq!(target, ours);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{Diffable, Leaf};
+use chrono::{
+    DateTime, FixedOffset, Month, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeDelta, TimeZone, Utc, Weekday,
+};
+
+leaf! {
+    NaiveDate,
+    NaiveDateTime,
+    NaiveTime,
+    TimeDelta,
+    FixedOffset,
+    Utc,
+    Weekday,
+    Month
+}
+
+impl<Tz: TimeZone> Diffable for DateTime<Tz> {
+    type Diff<'daft>
+        = Leaf<&'daft DateTime<Tz>>
+    where
+        Tz: 'daft;
+
+    fn diff<'daft>(&'daft self, other: &'daft Self) -> Self::Diff<'daft> {
+        Leaf { before: self, after: other }
+    }
+}
+
+impl<Tz: TimeZone> Leaf<&DateTime<Tz>> {
+    /// Return the signed difference from before to after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "chrono04")] {
+    /// use chrono::{TimeDelta, TimeZone, Utc};
+    /// use daft::Diffable;
+    ///
+    /// let before = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+    /// let after = Utc.with_ymd_and_hms(2025, 1, 1, 11, 30, 0).unwrap();
+    /// assert_eq!(before.diff(&after).delta(), TimeDelta::minutes(-30));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn delta(&self) -> TimeDelta {
+        // Compare the UTC times to avoid cloning the time zones.
+        self.after.naive_utc().signed_duration_since(self.before.naive_utc())
+    }
+}
+
+macro_rules! naive_delta {
+    ($($ty:ty),*) => {
+        $(
+            impl Leaf<&$ty> {
+                /// Return the signed difference from before to after.
+                #[inline]
+                pub fn delta(&self) -> TimeDelta {
+                    self.after.signed_duration_since(*self.before)
+                }
+            }
+        )*
+    }
+}
+
+naive_delta! { NaiveDate, NaiveDateTime, NaiveTime }
+
+#[cfg(test)]
+mod tests {
+    use crate::Diffable;
+    use chrono::{FixedOffset, NaiveDate, NaiveTime, TimeDelta, TimeZone};
+
+    #[test]
+    fn chrono_delta() {
+        let before = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+        let after = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert_eq!(before.diff(&after).delta(), TimeDelta::days(2));
+
+        let before = before.and_hms_opt(23, 0, 0).unwrap();
+        let after = after.and_hms_opt(1, 0, 0).unwrap();
+        assert_eq!(before.diff(&after).delta(), TimeDelta::hours(26));
+
+        let before = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        let after = NaiveTime::from_hms_opt(11, 59, 30).unwrap();
+        assert_eq!(before.diff(&after).delta(), TimeDelta::seconds(-30));
+
+        // Date-times in different time zones are compared as instants.
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let cet = FixedOffset::east_opt(3600).unwrap();
+        let before = utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let after = cet.with_ymd_and_hms(2025, 1, 1, 13, 0, 0).unwrap();
+        assert_eq!(before.diff(&after).delta(), TimeDelta::zero());
+    }
+}
@@ -5,12 +5,14 @@ use indexmap::{IndexMap, IndexSet};
 map_diff!(
     /// A diff of two [`IndexMap`] instances.
     ///
-    /// The diff contains three elements:
+    /// The diff contains four elements:
     ///
     /// - `common`: Entries that are present in both maps, with their values
     ///   stored as a [`Leaf`][crate::Leaf].
     /// - `added`: Entries present in `after`, but not in `before`.
     /// - `removed`: Entries present in `before`, but not in `after`.
+    /// - `order`: Changes to the relative order of common entries, as an
+    ///   [`OrderDiff`][crate::OrderDiff].
     ///
     /// If `V` implements `Eq`, `common` can be split into
     /// [`unchanged`][Self::unchanged] and [`modified`][Self::modified] entries.
@@ -24,7 +26,7 @@ map_diff!(
     ///
     /// ```
     /// # #[cfg(feature = "indexmap")] {
-    /// use daft::{Diffable, IndexMapDiff, Leaf};
+    /// use daft::{Diffable, IndexMapDiff, KeyPosition, Leaf, OrderDiff};
     /// use indexmap::IndexMap;
     ///
     /// let a: IndexMap<usize, &str> =
@@ -41,6 +43,13 @@ map_diff!(
     ///     ].into_iter().collect(),
     ///     added: [(&3, &"amet")].into_iter().collect(),
     ///     removed: [(&0, &"lorem")].into_iter().collect(),
+    ///     // The common keys are in the same order, so only the positions of
+    ///     // the added and removed keys are recorded.
+    ///     order: OrderDiff {
+    ///         added: vec![KeyPosition { key: &3, index: 2 }],
+    ///         removed: vec![KeyPosition { key: &0, index: 0 }],
+    ///         ..OrderDiff::new()
+    ///     },
     /// };
     ///
     /// assert_eq!(changes, expected);
@@ -56,16 +65,18 @@ map_diff!(
     /// assert_eq!(modified, [(&2, Leaf { before: &"dolor", after: &"sit" })]);
     /// # }
     /// ```
-    IndexMap, Hash
+    IndexMap, Hash, shift_remove, OrderDiff
 );
 set_diff!(
     /// A diff of two [`IndexSet`] instances.
     ///
-    /// The diff contains three elements:
+    /// The diff contains four elements:
     ///
     /// - `common`: Entries that are present in both sets.
     /// - `added`: Entries present in `after`, but not in `before`.
     /// - `removed`: Entries present in `before`, but not in `after`.
+    /// - `order`: Changes to the relative order of common entries, as an
+    ///   [`OrderDiff`][crate::OrderDiff].
     ///
     /// [`IndexSet`]: indexmap::IndexSet
     ///
@@ -73,7 +84,7 @@ set_diff!(
     ///
     /// ```
     /// # #[cfg(feature = "indexmap")] {
-    /// use daft::{Diffable, IndexSetDiff};
+    /// use daft::{Diffable, IndexSetDiff, KeyPosition, OrderDiff};
     /// use indexmap::IndexSet;
     ///
     /// let a: IndexSet<usize> = [0, 1].into_iter().collect();
@@ -85,18 +96,26 @@ set_diff!(
     ///     common: [&1].into_iter().collect(),
     ///     added: [&2].into_iter().collect(),
     ///     removed: [&0].into_iter().collect(),
+    ///     order: OrderDiff {
+    ///         added: vec![KeyPosition { key: &2, index: 1 }],
+    ///         removed: vec![KeyPosition { key: &0, index: 0 }],
+    ///         ..OrderDiff::new()
+    ///     },
     /// };
     ///
     /// assert_eq!(changes, expected);
     /// # }
     /// ```
-    IndexSet, Hash
+    IndexSet, Hash, shift_remove, OrderDiff
 );
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Leaf;
+    use crate::{
+        DiffSummary, DiffableMap, IntoOwnedDiff, Invert, KeyMove, KeyPosition,
+        Leaf, Merge, MovedKey, OrderDiff, Patch,
+    };
     use alloc::vec::Vec;
 
     #[test]
@@ -108,8 +127,40 @@ mod tests {
             added: [&6, &7, &8].into_iter().collect(),
             removed: [&0, &1, &2].into_iter().collect(),
             common: [&3, &4, &5].into_iter().collect(),
+            order: OrderDiff {
+                added: [&6, &7, &8]
+                    .into_iter()
+                    .zip(3..)
+                    .map(|(key, index)| KeyPosition { key, index })
+                    .collect(),
+                removed: [&0, &1, &2]
+                    .into_iter()
+                    .zip(0..)
+                    .map(|(key, index)| KeyPosition { key, index })
+                    .collect(),
+                ..OrderDiff::new()
+            },
         };
         assert_eq!(expected, changes);
+
+        // Moving 3 to the end is a single move.
+        let c: IndexSet<_> = [4, 5, 3].into_iter().collect();
+        let changes = b.diff(&c);
+        assert_eq!(
+            changes.order.moved,
+            [MovedKey { key: &3, before: 0, after: 2 }]
+        );
+        assert_eq!(changes.order.moves, [KeyMove { key: &3, from: 0, to: 2 }]);
+        assert!(changes.is_modified());
+        #[cfg(feature = "std")]
+        assert_eq!(
+            crate::DiffRenderer::plain().render(&changes).to_string(),
+            "- 6\n- 7\n- 8\n~ 3: position 0 -> 2\n",
+        );
+        assert_eq!(
+            changes.invert().order.moves,
+            [KeyMove { key: &3, from: 2, to: 0 }]
+        );
     }
 
     #[test]
@@ -127,10 +178,54 @@ mod tests {
             .collect(),
             added: [(&3, &1)].into_iter().collect(),
             removed: [(&1, &1)].into_iter().collect(),
+            order: OrderDiff {
+                added: [KeyPosition { key: &3, index: 2 }].into(),
+                removed: [KeyPosition { key: &1, index: 1 }].into(),
+                ..OrderDiff::new()
+            },
         };
 
         assert_eq!(changes, expected);
 
+        // Reordering entries is a change, even if no values changed.
+        let c: IndexMap<_, _> = [(2, 1), (3, 1), (0, 2)].into_iter().collect();
+        let changes = b.diff(&c);
+        assert!(DiffSummary::is_modified(&changes));
+        #[cfg(feature = "std")]
+        assert_eq!(
+            crate::DiffRenderer::plain().render(&changes).to_string(),
+            "~ 0: position 0 -> 2\n",
+        );
+        assert_eq!(
+            changes.order.moved,
+            [MovedKey { key: &0, before: 0, after: 2 }]
+        );
+        let owned = changes.into_owned();
+        assert_eq!(owned.order.moves, [KeyMove { key: 0, from: 0, to: 2 }]);
+
+        // Recursive diffs keep track of reordering as well.
+        let recursive = b.recursive_diff(&c);
+        assert!(DiffSummary::is_modified(&recursive));
+        assert_eq!(
+            recursive.order.moved,
+            [MovedKey { key: &0, before: 0, after: 2 }]
+        );
+        #[cfg(feature = "std")]
+        assert_eq!(
+            crate::DiffRenderer::plain().render(&recursive).to_string(),
+            "~ 0: position 0 -> 2\n",
+        );
+        let owned = recursive.clone().into_owned();
+        assert_eq!(owned.order.moves, [KeyMove { key: 0, from: 0, to: 2 }]);
+        assert_eq!(
+            owned.invert().order.moves,
+            [KeyMove { key: 0, from: 2, to: 0 }]
+        );
+        assert_eq!(
+            recursive.invert().order.moved,
+            [MovedKey { key: &0, before: 2, after: 0 }]
+        );
+
         // Ensure that keys don't need to be Diffable, and values don't need to
         // be Eq or Diffable.
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -157,4 +252,136 @@ mod tests {
             [&K(0), &K(2)]
         );
     }
+
+    #[test]
+    fn indexmap_patch_order() {
+        // `IndexMap`'s `PartialEq` ignores order, so compare the entries in
+        // order.
+        fn entries<K, V>(map: &IndexMap<K, V>) -> Vec<(&K, &V)> {
+            map.iter().collect()
+        }
+
+        let before: IndexMap<_, _> =
+            [(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')].into_iter().collect();
+        let afters: [IndexMap<_, _>; 3] = [
+            [(3, 'd'), (2, 'x'), (0, 'a'), (4, 'e')].into_iter().collect(),
+            [(4, 'e'), (3, 'd'), (5, 'f'), (2, 'x'), (0, 'a')]
+                .into_iter()
+                .collect(),
+            [(4, 'e'), (5, 'f')].into_iter().collect(),
+        ];
+        for after in &afters {
+            let diff = before.diff(after);
+            let mut target = before.clone();
+            diff.apply(&mut target).unwrap();
+            assert_eq!(entries(&target), entries(after));
+
+            // Inverting the diff restores the before order, including the
+            // positions of removed entries.
+            let mut target = after.clone();
+            diff.into_owned().invert().apply(&mut target).unwrap();
+            assert_eq!(entries(&target), entries(&before));
+
+            let mut target = before.clone();
+            before.recursive_diff(after).apply(&mut target).unwrap();
+            assert_eq!(entries(&target), entries(after));
+        }
+
+        let before: IndexSet<_> = [0, 1, 2, 3].into_iter().collect();
+        let afters: [IndexSet<_>; 3] = [
+            [2, 3, 0, 4].into_iter().collect(),
+            [4, 3, 2, 0].into_iter().collect(),
+            [5, 3, 4, 1, 6].into_iter().collect(),
+        ];
+        for after in &afters {
+            let diff = before.diff(after);
+            let mut target = before.clone();
+            diff.apply(&mut target).unwrap();
+            assert_eq!(
+                target.iter().collect::<Vec<_>>(),
+                after.iter().collect::<Vec<_>>()
+            );
+
+            let mut target = after.clone();
+            diff.into_owned().invert().apply(&mut target).unwrap();
+            assert_eq!(
+                target.iter().collect::<Vec<_>>(),
+                before.iter().collect::<Vec<_>>()
+            );
+        }
+
+        // An added entry can't be inserted past the end of the target.
+        let after: IndexSet<_> = [0, 1, 2, 3, 4].into_iter().collect();
+        let mut target: IndexSet<_> = [0, 1].into_iter().collect();
+        let diff = IndexSetDiff {
+            common: [&0, &1].into_iter().collect(),
+            ..before.diff(&after)
+        };
+        assert_eq!(diff.apply(&mut target), Err(crate::PatchError::Mismatch));
+    }
+
+    #[test]
+    fn indexmap_merge_order() {
+        let base: IndexMap<_, _> =
+            [('a', 1), ('b', 2), ('c', 3)].into_iter().collect();
+        let ours: IndexMap<_, _> =
+            [('a', 1), ('b', 2), ('c', 3), ('d', 4)].into_iter().collect();
+        let theirs: IndexMap<_, _> =
+            [('c', 3), ('a', 1), ('b', 2)].into_iter().collect();
+
+        // Only theirs reordered entries, so their order is applied to the
+        // common entries.
+        let mut merged = ours.clone();
+        base.diff(&ours).merge(&base.diff(&theirs), &mut merged).unwrap();
+        assert_eq!(merged.keys().collect::<Vec<_>>(), [&'c', &'a', &'b', &'d']);
+
+        let mut merged = ours.clone();
+        base.recursive_diff(&ours)
+            .merge(&base.recursive_diff(&theirs), &mut merged)
+            .unwrap();
+        assert_eq!(merged.keys().collect::<Vec<_>>(), [&'c', &'a', &'b', &'d']);
+
+        let base_set: IndexSet<_> = base.keys().copied().collect();
+        let ours_set: IndexSet<_> = ours.keys().copied().collect();
+        let theirs_set: IndexSet<_> = theirs.keys().copied().collect();
+        let mut merged = ours_set.clone();
+        base_set
+            .diff(&ours_set)
+            .merge(&base_set.diff(&theirs_set), &mut merged)
+            .unwrap();
+        assert_eq!(merged.iter().collect::<Vec<_>>(), [&'c', &'a', &'b', &'d']);
+
+        // Both sides reordering entries the same way doesn't conflict.
+        let mut merged = theirs.clone();
+        base.diff(&theirs).merge(&base.diff(&theirs), &mut merged).unwrap();
+        assert_eq!(merged.keys().collect::<Vec<_>>(), [&'c', &'a', &'b']);
+
+        // Both sides reordering entries differently conflicts, and leaves the
+        // order as it is in ours.
+        let ours: IndexMap<_, _> =
+            [('b', 2), ('a', 1), ('c', 3)].into_iter().collect();
+        let mut merged = ours.clone();
+        let error = base
+            .diff(&ours)
+            .merge(&base.diff(&theirs), &mut merged)
+            .unwrap_err();
+        assert_eq!(error.conflicts().len(), 1);
+        assert_eq!(error.conflicts()[0].path, "");
+        assert_eq!(
+            error.conflicts()[0].kind,
+            crate::MergeConflictKind::BothReordered
+        );
+        assert_eq!(merged.keys().collect::<Vec<_>>(), [&'b', &'a', &'c']);
+
+        let ours_set: IndexSet<_> = ours.keys().copied().collect();
+        let mut merged = ours_set.clone();
+        let error = base_set
+            .diff(&ours_set)
+            .merge(&base_set.diff(&theirs_set), &mut merged)
+            .unwrap_err();
+        assert_eq!(
+            error.conflicts()[0].kind,
+            crate::MergeConflictKind::BothReordered
+        );
+    }
 }
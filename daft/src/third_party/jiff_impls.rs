@@ -0,0 +1,57 @@
+use crate::Leaf;
+use jiff::{
+    SignedDuration, Timestamp,
+    civil::{Date, DateTime, Time, Weekday},
+    tz::Offset,
+};
+#[cfg(feature = "alloc")]
+use jiff::{Zoned, tz::TimeZone};
+
+leaf! { Timestamp, Date, DateTime, Time, Weekday, SignedDuration, Offset }
+#[cfg(feature = "alloc")]
+leaf! { Zoned, TimeZone }
+
+macro_rules! jiff_delta {
+    ($($ty:ty),*) => {
+        $(
+            impl Leaf<&$ty> {
+                /// Return the signed difference from before to after.
+                #[inline]
+                pub fn delta(&self) -> SignedDuration {
+                    self.after.duration_since(*self.before)
+                }
+            }
+        )*
+    }
+}
+
+jiff_delta! { Timestamp, Date, DateTime, Time }
+
+#[cfg(feature = "alloc")]
+impl Leaf<&Zoned> {
+    /// Return the signed difference from before to after.
+    #[inline]
+    pub fn delta(&self) -> SignedDuration {
+        self.after.duration_since(self.before)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Diffable;
+    use jiff::{SignedDuration, Timestamp, civil::date};
+
+    #[test]
+    fn jiff_delta() {
+        let before = Timestamp::from_second(100).unwrap();
+        let after = Timestamp::from_second(40).unwrap();
+        assert_eq!(before.diff(&after).delta(), SignedDuration::from_secs(-60));
+
+        let before = date(2025, 3, 1);
+        let after = date(2025, 2, 28);
+        assert_eq!(
+            before.diff(&after).delta(),
+            SignedDuration::from_hours(-24)
+        );
+    }
+}
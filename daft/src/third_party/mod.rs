@@ -1,10 +1,16 @@
 //! Implementations for third-party libraries.
 
+#[cfg(feature = "chrono04")]
+mod chrono_impls;
 #[cfg(feature = "indexmap")]
 pub(crate) mod indexmap;
+#[cfg(feature = "jiff02")]
+mod jiff_impls;
 #[cfg(feature = "newtype-uuid1")]
 mod newtype_uuid_impls;
 #[cfg(feature = "oxnet01")]
 mod oxnet_impls;
+#[cfg(feature = "time03")]
+mod time_impls;
 #[cfg(feature = "uuid1")]
 mod uuid_impls;
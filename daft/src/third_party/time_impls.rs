@@ -0,0 +1,50 @@
+use crate::Leaf;
+use time::{
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset,
+    Weekday,
+};
+
+leaf! {
+    Date,
+    Time,
+    PrimitiveDateTime,
+    OffsetDateTime,
+    UtcOffset,
+    Duration,
+    Weekday,
+    Month
+}
+
+macro_rules! time_delta {
+    ($($ty:ty),*) => {
+        $(
+            impl Leaf<&$ty> {
+                /// Return the signed difference from before to after.
+                #[inline]
+                pub fn delta(&self) -> Duration {
+                    *self.after - *self.before
+                }
+            }
+        )*
+    }
+}
+
+time_delta! { Date, Time, PrimitiveDateTime, OffsetDateTime }
+
+#[cfg(test)]
+mod tests {
+    use crate::Diffable;
+    use time::{Date, Duration, Month, OffsetDateTime};
+
+    #[test]
+    fn time_delta() {
+        let before = OffsetDateTime::UNIX_EPOCH;
+        let after = before - Duration::seconds(90);
+        assert_eq!(before.diff(&after).delta(), Duration::seconds(-90));
+
+        let before =
+            Date::from_calendar_date(2024, Month::February, 28).unwrap();
+        let after = Date::from_calendar_date(2024, Month::March, 1).unwrap();
+        assert_eq!(before.diff(&after).delta(), Duration::days(2));
+    }
+}
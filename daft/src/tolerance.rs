@@ -0,0 +1,141 @@
+//! Approximate comparisons for floating-point leaves.
+
+use crate::Leaf;
+
+/// A tolerance for comparing floating-point values, used with
+/// [`Leaf::approx_eq`].
+///
+/// A bare `f64` converts to an [`Absolute`](Self::Absolute) tolerance.
+///
+/// # Example
+///
+/// ```
+/// use daft::{Diffable, Tolerance};
+///
+/// let before = 0.1_f64 + 0.2;
+/// let after = 0.3_f64;
+///
+/// let diff = before.diff(&after);
+/// assert!(diff.before != diff.after);
+/// assert!(diff.approx_eq(Tolerance::Absolute(1e-9)));
+/// assert!(diff.approx_eq(Tolerance::Relative(1e-9)));
+/// assert!(diff.approx_eq(Tolerance::Ulps(1)));
+///
+/// // Bare `f64`s are absolute tolerances.
+/// assert!(diff.approx_eq(1e-9.into()));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tolerance {
+    /// The values are equal if they differ by at most this amount.
+    Absolute(f64),
+
+    /// The values are equal if they differ by at most this fraction of the
+    /// larger of their magnitudes.
+    Relative(f64),
+
+    /// The values are equal if there are at most this many representable
+    /// values between them, in units in the last place (ULPs). Positive and
+    /// negative zero count as the same value.
+    Ulps(u64),
+}
+
+impl From<f64> for Tolerance {
+    #[inline]
+    fn from(epsilon: f64) -> Self {
+        Self::Absolute(epsilon)
+    }
+}
+
+macro_rules! float_approx_eq {
+    ($($ty:ty => $bits:ty),*) => {
+        $(
+            impl Leaf<&$ty> {
+                /// Return true if before and after are equal within
+                /// `tolerance`.
+                ///
+                /// Two NaN values are considered equal to each other, and
+                /// infinities are only equal to themselves.
+                pub fn approx_eq(&self, tolerance: Tolerance) -> bool {
+                    let (before, after) = (*self.before, *self.after);
+                    if before == after || (before.is_nan() && after.is_nan()) {
+                        return true;
+                    }
+                    if !before.is_finite() || !after.is_finite() {
+                        return false;
+                    }
+
+                    let delta = (f64::from(before) - f64::from(after)).abs();
+                    match tolerance {
+                        Tolerance::Absolute(epsilon) => delta <= epsilon,
+                        Tolerance::Relative(epsilon) => {
+                            let magnitude =
+                                f64::from(before.abs().max(after.abs()));
+                            delta <= epsilon * magnitude
+                        }
+                        Tolerance::Ulps(ulps) => {
+                            // Map the bit patterns to integers that sort in the
+                            // same order as the floats they represent, with
+                            // both zeros mapping to the same integer. The
+                            // magnitude is always less than `SIGN`, so this
+                            // doesn't overflow.
+                            const SIGN: $bits = 1 << (<$bits>::BITS - 1);
+                            let ordered = |x: $ty| {
+                                let bits = x.to_bits();
+                                if bits & SIGN == 0 {
+                                    SIGN + bits
+                                } else {
+                                    SIGN - (bits & !SIGN)
+                                }
+                            };
+                            let distance =
+                                ordered(before).abs_diff(ordered(after));
+                            u64::from(distance) <= ulps
+                        }
+                    }
+                }
+            }
+        )*
+    }
+}
+
+float_approx_eq! { f32 => u32, f64 => u64 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Diffable;
+
+    fn approx_eq(before: f64, after: f64, tolerance: Tolerance) -> bool {
+        before.diff(&after).approx_eq(tolerance)
+    }
+
+    #[test]
+    fn float_approx_eq() {
+        assert!(approx_eq(1.0, 1.05, Tolerance::Absolute(0.1)));
+        assert!(!approx_eq(1.0, 1.2, Tolerance::Absolute(0.1)));
+        assert!(approx_eq(1000.0, 1001.0, Tolerance::Relative(0.01)));
+        assert!(!approx_eq(1.0, 1.1, Tolerance::Relative(0.01)));
+
+        // Adjacent values, including across zero.
+        let next = f64::from_bits(1.0_f64.to_bits() + 1);
+        assert!(approx_eq(1.0, next, Tolerance::Ulps(1)));
+        assert!(!approx_eq(1.0, next, Tolerance::Ulps(0)));
+        assert!(approx_eq(0.0, -0.0, Tolerance::Ulps(0)));
+        let tiny = f64::from_bits(1);
+        assert!(approx_eq(tiny, -tiny, Tolerance::Ulps(2)));
+        assert!(!approx_eq(tiny, -tiny, Tolerance::Ulps(1)));
+        assert!(approx_eq(tiny, -0.0, Tolerance::Ulps(1)));
+
+        // NaNs and infinities.
+        assert!(approx_eq(f64::NAN, f64::NAN, Tolerance::Absolute(0.0)));
+        assert!(!approx_eq(f64::NAN, 1.0, Tolerance::Absolute(1e9)));
+        let inf = f64::INFINITY;
+        assert!(approx_eq(inf, inf, Tolerance::Relative(0.0)));
+        assert!(!approx_eq(inf, f64::MAX, Tolerance::Relative(1.0)));
+
+        let diff = 1.0_f32.diff(&1.000_001);
+        assert!(diff.approx_eq(Tolerance::Absolute(1e-5)));
+        assert!(diff.approx_eq(Tolerance::Ulps(8)));
+        assert!(!diff.approx_eq(Tolerance::Ulps(4)));
+    }
+}
@@ -0,0 +1,353 @@
+//! Visiting the changes in a diff.
+
+use crate::Leaf;
+use core::fmt;
+
+/// A visitor over the changes in a diff, used with [`VisitDiff`].
+///
+/// Each method is called with the [`DiffPath`] at which a change was found.
+/// All methods have default implementations that do nothing, so visitors only
+/// need to implement the ones they are interested in.
+pub trait DiffVisitor {
+    /// Called for a value that is present on the after side, but not the
+    /// before side.
+    ///
+    /// This is called for entries added to maps and sets. For maps, `path`
+    /// ends with the [`PathSegment::Key`] of the entry; for sets, `path` is
+    /// the path to the set itself.
+    fn added(&mut self, path: &DiffPath<'_>, value: &dyn fmt::Debug) {
+        let _ = (path, value);
+    }
+
+    /// Called for a value that is present on the before side, but not the
+    /// after side.
+    ///
+    /// This is called for entries removed from maps and sets. For maps, `path`
+    /// ends with the [`PathSegment::Key`] of the entry; for sets, `path` is
+    /// the path to the set itself.
+    fn removed(&mut self, path: &DiffPath<'_>, value: &dyn fmt::Debug) {
+        let _ = (path, value);
+    }
+
+    /// Called for a leaf value that is different on the before and after
+    /// sides.
+    fn modified(
+        &mut self,
+        path: &DiffPath<'_>,
+        before: &dyn fmt::Debug,
+        after: &dyn fmt::Debug,
+    ) {
+        let _ = (path, before, after);
+    }
+
+    /// Called for an entry of an ordered map or set that moved relative to
+    /// the other entries common to both sides.
+    ///
+    /// `path` ends with the [`PathSegment::Key`] of the entry, and `before`
+    /// and `after` are its positions in the before and after collections. See
+    /// [`OrderDiff`](crate::OrderDiff) for which entries are reported as
+    /// moved.
+    fn moved(&mut self, path: &DiffPath<'_>, before: usize, after: usize) {
+        let _ = (path, before, after);
+    }
+}
+
+/// A diff that can be walked by a [`DiffVisitor`].
+///
+/// Visiting a diff calls the visitor for each added, removed, modified, and
+/// moved value, along with a structured [`DiffPath`] to the value. Unchanged
+/// values are not reported.
+///
+/// This trait is implemented for [`Leaf`] (for types that implement `Debug`
+/// and `PartialEq`), for all of the map and set diff types in this crate, for
+/// tuples and arrays of diffs, and for diff types generated by the
+/// [`Diffable`](macro@crate::Diffable) derive macro. Elements of arrays are
+/// visited with [`PathSegment::Index`] paths.
+///
+/// Modified entries in map diffs are visited by diffing the before and after
+/// values, so a change deep within a map value is reported with a path such as
+/// `zpools["abc"].size`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "std", feature = "derive"))] {
+/// use daft::{DiffPath, DiffVisitor, Diffable, VisitDiff};
+/// use std::{collections::BTreeMap, fmt};
+///
+/// #[derive(Debug, Eq, PartialEq, Diffable)]
+/// struct Zpool {
+///     size: u64,
+/// }
+///
+/// #[derive(Diffable)]
+/// struct Sled {
+///     zpools: BTreeMap<String, Zpool>,
+/// }
+///
+/// /// Records changes as lines in an audit log.
+/// #[derive(Default)]
+/// struct AuditLog(Vec<String>);
+///
+/// impl DiffVisitor for AuditLog {
+///     fn modified(
+///         &mut self,
+///         path: &DiffPath<'_>,
+///         before: &dyn fmt::Debug,
+///         after: &dyn fmt::Debug,
+///     ) {
+///         self.0.push(format!("{path}: {before:?} -> {after:?}"));
+///     }
+/// }
+///
+/// let before = Sled {
+///     zpools: [("abc".to_owned(), Zpool { size: 10 })].into_iter().collect(),
+/// };
+/// let after = Sled {
+///     zpools: [("abc".to_owned(), Zpool { size: 20 })].into_iter().collect(),
+/// };
+///
+/// let mut log = AuditLog::default();
+/// before.diff(&after).visit(&mut log);
+/// assert_eq!(log.0, [r#"zpools["abc"].size: 10 -> 20"#]);
+/// # }
+/// ```
+pub trait VisitDiff {
+    /// Visit the changes in this diff, starting at the root path.
+    #[inline]
+    fn visit(&self, visitor: &mut dyn DiffVisitor) {
+        self.visit_at(&DiffPath::root(), visitor);
+    }
+
+    /// Visit the changes in this diff, with paths relative to `path`.
+    fn visit_at(&self, path: &DiffPath<'_>, visitor: &mut dyn DiffVisitor);
+}
+
+impl<T: fmt::Debug + PartialEq> VisitDiff for Leaf<T> {
+    fn visit_at(&self, path: &DiffPath<'_>, visitor: &mut dyn DiffVisitor) {
+        if self.before != self.after {
+            visitor.modified(path, &self.before, &self.after);
+        }
+    }
+}
+
+/// The location of a change within a diff.
+///
+/// A `DiffPath` is a sequence of [`PathSegment`]s starting from the root of
+/// the diff being visited. Paths are stored as a linked list on the stack, so
+/// creating them doesn't allocate: use [`join`](Self::join) to create a child
+/// path.
+///
+/// The [`Display`](fmt::Display) implementation renders paths in a Rust-like
+/// syntax, for example `sled.zpools["abc"].size`.
+#[derive(Clone, Copy)]
+pub struct DiffPath<'a> {
+    // The parent path and last segment, or None for the root.
+    inner: Option<(&'a DiffPath<'a>, PathSegment<'a>)>,
+}
+
+impl<'a> DiffPath<'a> {
+    /// Return the empty path, at the root of a diff.
+    #[inline]
+    pub const fn root() -> Self {
+        Self { inner: None }
+    }
+
+    /// Return a new path with `segment` appended to this one.
+    #[inline]
+    pub fn join<'b>(&'b self, segment: PathSegment<'b>) -> DiffPath<'b> {
+        DiffPath { inner: Some((self, segment)) }
+    }
+
+    /// Return true if this is the root path.
+    #[inline]
+    pub fn is_root(&self) -> bool {
+        self.inner.is_none()
+    }
+
+    /// Return the number of segments in this path.
+    pub fn depth(&self) -> usize {
+        let mut depth = 0;
+        let mut path = self;
+        while let Some((parent, _)) = path.inner {
+            depth += 1;
+            path = parent;
+        }
+        depth
+    }
+
+    /// Return the parent of this path, or `None` if this is the root path.
+    #[inline]
+    pub fn parent(&self) -> Option<&'a DiffPath<'a>> {
+        self.inner.map(|(parent, _)| parent)
+    }
+
+    /// Return the last segment of this path, or `None` if this is the root
+    /// path.
+    #[inline]
+    pub fn last(&self) -> Option<PathSegment<'a>> {
+        self.inner.map(|(_, segment)| segment)
+    }
+
+    /// Return an iterator over the segments of this path, starting from the
+    /// root.
+    pub fn segments(&self) -> impl Iterator<Item = PathSegment<'a>> + '_ {
+        let depth = self.depth();
+        (0..depth).map(move |ix| {
+            // Walk up from the end of the path. Paths are short, so this
+            // quadratic approach is fine, and it avoids allocating.
+            let mut path = self;
+            for _ in ix + 1..depth {
+                path = path.parent().expect("depth is correct");
+            }
+            path.last().expect("depth is correct")
+        })
+    }
+}
+
+impl fmt::Display for DiffPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (ix, segment) in self.segments().enumerate() {
+            segment.fmt_with_separator(f, ix > 0)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for DiffPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.segments()).finish()
+    }
+}
+
+/// A single segment of a [`DiffPath`].
+#[derive(Clone, Copy, Debug)]
+pub enum PathSegment<'a> {
+    /// A named field of a struct or enum variant, displayed as `.name`.
+    Field(&'static str),
+
+    /// A field of a tuple or tuple-like struct, displayed as `.0`.
+    TupleIndex(usize),
+
+    /// An index into a sequence, displayed as `[0]`.
+    Index(usize),
+
+    /// A key in a map, displayed using its `Debug` implementation as
+    /// `["key"]`.
+    Key(&'a dyn fmt::Debug),
+
+    /// An enum variant, displayed as `::Name`.
+    Variant(&'static str),
+}
+
+impl PathSegment<'_> {
+    fn fmt_with_separator(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        separator: bool,
+    ) -> fmt::Result {
+        match self {
+            Self::Field(name) if separator => write!(f, ".{name}"),
+            Self::Field(name) => write!(f, "{name}"),
+            Self::TupleIndex(ix) if separator => write!(f, ".{ix}"),
+            Self::TupleIndex(ix) => write!(f, "{ix}"),
+            Self::Index(ix) => write!(f, "[{ix}]"),
+            Self::Key(key) => write!(f, "[{key:?}]"),
+            Self::Variant(name) if separator => write!(f, "::{name}"),
+            Self::Variant(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl fmt::Display for PathSegment<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_separator(f, true)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::Diffable;
+    use std::collections::{BTreeMap, BTreeSet};
+
+    /// Records all changes as strings.
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl DiffVisitor for Recorder {
+        fn added(&mut self, path: &DiffPath<'_>, value: &dyn fmt::Debug) {
+            self.0.push(format!("{path}: + {value:?}"));
+        }
+
+        fn removed(&mut self, path: &DiffPath<'_>, value: &dyn fmt::Debug) {
+            self.0.push(format!("{path}: - {value:?}"));
+        }
+
+        fn modified(
+            &mut self,
+            path: &DiffPath<'_>,
+            before: &dyn fmt::Debug,
+            after: &dyn fmt::Debug,
+        ) {
+            self.0.push(format!("{path}: {before:?} -> {after:?}"));
+        }
+    }
+
+    #[test]
+    fn path_display() {
+        let root = DiffPath::root();
+        assert!(root.is_root());
+        assert_eq!(root.to_string(), "");
+
+        let key = "abc";
+        let sled = root.join(PathSegment::Field("sled"));
+        let zpools = sled.join(PathSegment::Field("zpools"));
+        let entry = zpools.join(PathSegment::Key(&key));
+        let size = entry.join(PathSegment::TupleIndex(0));
+        let variant = size.join(PathSegment::Variant("Some"));
+        let index = variant.join(PathSegment::Index(3));
+        assert_eq!(index.to_string(), r#"sled.zpools["abc"].0::Some[3]"#);
+        assert_eq!(index.depth(), 6);
+        assert_eq!(index.parent().unwrap().to_string(), variant.to_string());
+        assert!(matches!(index.last(), Some(PathSegment::Index(3))));
+
+        let tuple = root.join(PathSegment::TupleIndex(1));
+        assert_eq!(tuple.join(PathSegment::Field("a")).to_string(), "1.a");
+    }
+
+    #[test]
+    fn visit_builtin_diffs() {
+        let before: BTreeMap<&str, (u32, BTreeSet<char>)> = [
+            ("a", (1, ['x'].into_iter().collect())),
+            ("b", (2, BTreeSet::new())),
+        ]
+        .into_iter()
+        .collect();
+        let after: BTreeMap<&str, (u32, BTreeSet<char>)> = [
+            ("a", (1, ['y'].into_iter().collect())),
+            ("c", (3, BTreeSet::new())),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut recorder = Recorder::default();
+        before.diff(&after).visit(&mut recorder);
+        assert_eq!(
+            recorder.0,
+            [
+                r#"["b"]: - (2, {})"#,
+                r#"["a"].1: - 'x'"#,
+                r#"["a"].1: + 'y'"#,
+                r#"["c"]: + (3, {})"#,
+            ]
+        );
+
+        let mut recorder = Recorder::default();
+        let path = DiffPath::root();
+        let path = path.join(PathSegment::Field("count"));
+        1_u32.diff(&2).visit_at(&path, &mut recorder);
+        2_u32.diff(&2).visit_at(&path, &mut recorder);
+        assert_eq!(recorder.0, ["count: 1 -> 2"]);
+    }
+}